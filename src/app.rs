@@ -1,6 +1,21 @@
+use std::hash::Hash;
+
+#[cfg(feature = "bevy_asset")]
+use bevy::asset::Asset;
 use bevy::{
+    app::RunFixedUpdateLoop,
+    ecs::reflect::ReflectMapEntities,
     prelude::*,
-    reflect::GetTypeRegistration,
+    reflect::{
+        GetTypeRegistration,
+        TypePath,
+    },
+    time::fixed_timestep::run_fixed_update_schedule,
+    utils::{
+        Duration,
+        HashMap,
+        Instant,
+    },
 };
 
 use crate::prelude::*;
@@ -10,11 +25,772 @@ pub trait AppSaveableExt {
     /// Register a type as saveable - it will be included in rollback and affected by save/load.
     fn register_saveable<T: GetTypeRegistration>(&mut self) -> &mut Self;
 
+    /// Register a component that holds [`Entity`] references as saveable, checking that its references are mapped on load.
+    ///
+    /// Without `#[reflect(MapEntities)]`, references would silently point at the wrong entities after loading.
+    ///
+    /// # Errors
+    /// - [`SaveableError::MissingMapEntities`] if the type does not reflect `MapEntities`, in which case it is not registered as saveable
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::{entity::{EntityMapper, MapEntities}, reflect::ReflectMapEntities};
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Component, Reflect)]
+    /// #[reflect(Component, MapEntities)]
+    /// struct Target(Entity);
+    ///
+    /// impl MapEntities for Target {
+    ///     fn map_entities(&mut self, mapper: &mut EntityMapper) {
+    ///         self.0 = mapper.get_or_reserve(self.0);
+    ///     }
+    /// }
+    ///
+    /// impl FromWorld for Target {
+    ///     fn from_world(_: &mut World) -> Self {
+    ///         Self(Entity::PLACEHOLDER)
+    ///     }
+    /// }
+    ///
+    /// // Forgot `MapEntities`
+    /// #[derive(Component, Reflect)]
+    /// #[reflect(Component)]
+    /// struct Follow(Entity);
+    ///
+    /// impl FromWorld for Follow {
+    ///     fn from_world(_: &mut World) -> Self {
+    ///         Self(Entity::PLACEHOLDER)
+    ///     }
+    /// }
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// assert!(app.register_saveable_with_entities::<Target>().is_ok());
+    ///
+    /// let err = app.register_saveable_with_entities::<Follow>().unwrap_err();
+    /// assert!(matches!(err, SaveableError::MissingMapEntities { .. }));
+    ///
+    /// let saveables = app.world.resource::<SaveableRegistry>();
+    /// assert!(saveables.contains(std::any::type_name::<Target>()));
+    /// assert!(!saveables.contains(std::any::type_name::<Follow>()));
+    /// ```
+    fn register_saveable_with_entities<T: GetTypeRegistration>(
+        &mut self,
+    ) -> Result<&mut Self, SaveableError>;
+
+    /// Unregister a saveable type - it will no longer be included in rollback or affected by save/load.
+    fn unregister_saveable<T: GetTypeRegistration>(&mut self) -> &mut Self;
+
     /// Set a type to ignore rollback - it will be included in save/load but it won't change during rollback.
     fn ignore_rollback<T: GetTypeRegistration>(&mut self) -> &mut Self;
 
     /// Set a type to allow rollback - it will be included in rollback and affected by save/load.
     fn allow_rollback<T: GetTypeRegistration>(&mut self) -> &mut Self;
+
+    /// Couple two component types - snapshots will always include either both or neither of them.
+    ///
+    /// If a snapshot filter includes only one of the types, both are excluded and a warning is logged.
+    /// [`Transform`] and [`GlobalTransform`] are coupled by [`SaveablesPlugin`].
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let world = &mut app.world;
+    /// world.spawn((Transform::default(), GlobalTransform::default()));
+    ///
+    /// // The filter would only exclude `Transform`
+    /// let snapshot = Snapshot::builder(world)
+    ///     .filter(|reg| reg.type_name() != std::any::type_name::<Transform>())
+    ///     .extract_all_entities()
+    ///     .build();
+    ///
+    /// world.clear_entities();
+    /// snapshot.apply(world).unwrap();
+    ///
+    /// // Neither component was included
+    /// assert_eq!(world.query::<&Transform>().iter(world).count(), 0);
+    /// assert_eq!(world.query::<&GlobalTransform>().iter(world).count(), 0);
+    /// ```
+    fn register_coupled_types<A, B>(&mut self) -> &mut Self
+    where
+        A: GetTypeRegistration,
+        B: GetTypeRegistration;
+
+    /// Serialize a type under a stable name instead of its Rust type path.
+    ///
+    /// Saves written with the stable name keep loading after the type is renamed or moved,
+    /// as long as the new type is registered with the same stable name.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// mod before {
+    ///     # use bevy::prelude::*;
+    ///     #[derive(Resource, Reflect, Default)]
+    ///     #[reflect(Resource)]
+    ///     pub struct Score(pub u32);
+    /// }
+    ///
+    /// mod after {
+    ///     # use bevy::prelude::*;
+    ///     #[derive(Resource, Reflect, Default)]
+    ///     #[reflect(Resource)]
+    ///     pub struct Score(pub u32);
+    /// }
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// app.register_saveable::<before::Score>()
+    ///     .register_serialized_name::<before::Score>("game::Score")
+    ///     .insert_resource(before::Score(42));
+    ///
+    /// let mut save = Vec::new();
+    /// app.world.serialize(&mut serde_json::Serializer::new(&mut save)).unwrap();
+    ///
+    /// assert!(String::from_utf8_lossy(&save).contains("game::Score"));
+    ///
+    /// // A later build, where `Score` has moved
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// app.register_saveable::<after::Score>()
+    ///     .register_serialized_name::<after::Score>("game::Score");
+    ///
+    /// app.world.deserialize(&mut serde_json::Deserializer::from_slice(&save)).unwrap();
+    ///
+    /// assert_eq!(app.world.resource::<after::Score>().0, 42);
+    /// ```
+    ///
+    /// # Panics
+    /// - If `name` is the Rust type path or stable name of another registered type
+    ///
+    /// ```should_panic
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Resource, Reflect, Default)]
+    /// #[reflect(Resource)]
+    /// struct Score(u32);
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// // `Transform` would load as `Score`
+    /// app.register_saveable::<Score>()
+    ///     .register_serialized_name::<Score>(std::any::type_name::<Transform>());
+    /// ```
+    fn register_serialized_name<T: GetTypeRegistration>(&mut self, name: &str) -> &mut Self;
+
+    /// Never serialize the fields of a type at the given reflect paths, such as `translation.z`.
+    ///
+    /// Paths are dot-separated struct field names. When a save is applied, excluded fields keep their current value,
+    /// or their default value if the component or resource is inserted, which requires `#[reflect(Default)]`.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// // A 2D game, where the z coordinate only determines draw order
+    /// app.register_field_filter::<Transform>(&["translation.z"]);
+    ///
+    /// let world = &mut app.world;
+    /// let entity = world.spawn(Transform::from_xyz(1.0, 2.0, 3.0)).id();
+    ///
+    /// let mut save = Vec::new();
+    /// world.serialize(&mut serde_json::Serializer::new(&mut save)).unwrap();
+    ///
+    /// let json = String::from_utf8(save.clone()).unwrap();
+    /// assert!(json.contains(r#""x":1.0"#));
+    /// assert!(!json.contains(r#""z":3.0"#));
+    ///
+    /// world.entity_mut(entity).insert(Transform::from_xyz(5.0, 5.0, 5.0));
+    /// world.deserialize(&mut serde_json::Deserializer::from_slice(&save)).unwrap();
+    ///
+    /// // The excluded field was left untouched
+    /// let transform = world.get::<Transform>(entity).unwrap();
+    /// assert_eq!(transform.translation, Vec3::new(1.0, 2.0, 5.0));
+    /// ```
+    fn register_field_filter<T: GetTypeRegistration>(&mut self, paths: &[&str]) -> &mut Self;
+
+    /// Save the time field of a type at the given reflect path relative to the current time.
+    ///
+    /// The field may be an [`Instant`](bevy::utils::Instant), or a [`Duration`](bevy::utils::Duration)
+    /// measured like [`Time::elapsed`]. It is saved as the time remaining until it, and rebuilt from the
+    /// current time when applied, so timers stay meaningful across sessions. Times in the past are saved as now.
+    ///
+    /// The path is made of dot-separated struct field names. Call this once for each time field.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy::utils::{Duration, Instant};
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Component, Reflect)]
+    /// #[reflect(Component)]
+    /// struct Cooldown {
+    ///     ready_at: Instant,
+    /// }
+    ///
+    /// impl FromWorld for Cooldown {
+    ///     fn from_world(_: &mut World) -> Self {
+    ///         Self { ready_at: Instant::now() }
+    ///     }
+    /// }
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let mut other = App::new();
+    /// # other.add_plugins(MinimalPlugins);
+    /// # other.add_plugins(SavePlugins);
+    /// for app in [&mut app, &mut other] {
+    ///     app.register_saveable::<Cooldown>()
+    ///         .register_relative_time::<Cooldown>("ready_at");
+    /// }
+    ///
+    /// let world = &mut app.world;
+    /// world.spawn(Cooldown { ready_at: Instant::now() + Duration::from_secs(5) });
+    ///
+    /// let mut save = Vec::new();
+    /// world.serialize(&mut serde_json::Serializer::new(&mut save)).unwrap();
+    ///
+    /// // Reload in a new session
+    /// let world = &mut other.world;
+    /// world.deserialize(&mut serde_json::Deserializer::from_slice(&save)).unwrap();
+    ///
+    /// let cooldown = world.query::<&Cooldown>().single(world);
+    /// let remaining = cooldown.ready_at.saturating_duration_since(Instant::now());
+    ///
+    /// assert!(remaining <= Duration::from_secs(5));
+    /// assert!(remaining > Duration::from_secs(4));
+    /// ```
+    fn register_relative_time<T: GetTypeRegistration>(&mut self, path: &str) -> &mut Self;
+
+    /// Serialize the fields of a tuple struct as a map keyed by field index, instead of by position.
+    ///
+    /// Saves keep loading after fields are added to or removed from the end of the type. Added fields are filled
+    /// from its default value, which requires `#[reflect(Default)]`, and removed fields are ignored.
+    /// This also applies to the tuple struct when it is nested in the fields of other structs.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// mod before {
+    ///     # use bevy::prelude::*;
+    ///     #[derive(Component, Reflect, Default)]
+    ///     #[reflect(Component, Default)]
+    ///     pub struct Velocity(pub f32);
+    /// }
+    ///
+    /// mod after {
+    ///     # use bevy::prelude::*;
+    ///     #[derive(Component, Reflect)]
+    ///     #[reflect(Component, Default)]
+    ///     pub struct Velocity(pub f32, pub f32);
+    ///
+    ///     impl Default for Velocity {
+    ///         fn default() -> Self {
+    ///             Self(0.0, -9.8)
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// app.register_saveable::<before::Velocity>()
+    ///     .register_serialized_name::<before::Velocity>("game::Velocity")
+    ///     .register_indexed_fields::<before::Velocity>();
+    ///
+    /// app.world.spawn(before::Velocity(2.0));
+    ///
+    /// let mut save = Vec::new();
+    /// app.world.serialize(&mut serde_json::Serializer::new(&mut save)).unwrap();
+    ///
+    /// assert!(String::from_utf8_lossy(&save).contains(r#""game::Velocity":{"0":2.0}"#));
+    ///
+    /// // A later build, where `Velocity` gained a field
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// app.register_saveable::<after::Velocity>()
+    ///     .register_serialized_name::<after::Velocity>("game::Velocity")
+    ///     .register_indexed_fields::<after::Velocity>();
+    ///
+    /// app.world.deserialize(&mut serde_json::Deserializer::from_slice(&save)).unwrap();
+    ///
+    /// let world = &mut app.world;
+    /// let velocity = world.query::<&after::Velocity>().single(world);
+    ///
+    /// assert_eq!((velocity.0, velocity.1), (2.0, -9.8));
+    /// ```
+    fn register_indexed_fields<T: GetTypeRegistration>(&mut self) -> &mut Self;
+
+    /// Remap every [`Entity`] in a component when applying, however deeply it is nested in collections.
+    ///
+    /// [`ReflectMapEntities`] only maps types that implement [`MapEntities`](bevy::ecs::entity::MapEntities).
+    /// With this, entities in fields such as `Vec<Entity>` or `HashMap<u32, Entity>` are found by walking the
+    /// reflected value instead, and the component does not need to implement anything.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Component, Reflect, Default)]
+    /// #[reflect(Component)]
+    /// struct Neighbors(Vec<Entity>);
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let mut other = App::new();
+    /// # other.add_plugins(MinimalPlugins);
+    /// # other.add_plugins(SavePlugins);
+    /// for app in [&mut app, &mut other] {
+    ///     app.register_saveable::<Neighbors>()
+    ///         .register_type::<Vec<Entity>>()
+    ///         .register_entity_fields::<Neighbors>();
+    /// }
+    ///
+    /// let world = &mut app.world;
+    ///
+    /// let a = world.spawn(Name::new("a")).id();
+    /// let b = world.spawn(Name::new("b")).id();
+    /// world.spawn((Name::new("hub"), Neighbors(vec![a, b, a])));
+    ///
+    /// let snapshot = Snapshot::from_world(world);
+    ///
+    /// // Existing entities shift the ids of the applied ones
+    /// let world = &mut other.world;
+    /// world.spawn_batch((0..5).map(|_| Transform::default()));
+    ///
+    /// snapshot
+    ///     .applier(world)
+    ///     .despawn(DespawnMode::None)
+    ///     .mapping(MappingMode::Strict)
+    ///     .apply()
+    ///     .unwrap();
+    ///
+    /// let neighbors = world.query::<&Neighbors>().single(world).0.clone();
+    ///
+    /// let names = neighbors
+    ///     .iter()
+    ///     .map(|&entity| world.get::<Name>(entity).unwrap().as_str())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(names, ["a", "b", "a"]);
+    /// ```
+    ///
+    /// With [`AppEntityRefs::SaveId`](crate::AppEntityRefs::SaveId), references to entities with a
+    /// [`SaveId`](crate::SaveId) are re-linked once, even when a live id matches a saved index:
+    /// ```
+    /// # use bevy::{prelude::*, utils::HashMap};
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Component, Reflect, Default)]
+    /// #[reflect(Component)]
+    /// struct Links(Vec<Entity>);
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let mut other = App::new();
+    /// # other.add_plugins(MinimalPlugins);
+    /// # other.add_plugins(SavePlugins);
+    /// for app in [&mut app, &mut other] {
+    ///     app.register_saveable::<Links>()
+    ///         .register_type::<Vec<Entity>>()
+    ///         .register_entity_fields::<Links>()
+    ///         .insert_resource(AppEntityRefs::SaveId);
+    /// }
+    ///
+    /// let world = &mut app.world;
+    ///
+    /// let a = world.spawn(SaveId(1)).id();
+    /// let b = world.spawn(SaveId(2)).id();
+    /// let hub = world.spawn((SaveId(3), Links(vec![a, b]))).id();
+    /// world.entity_mut(a).insert(Links(vec![hub]));
+    ///
+    /// let mut save = Vec::new();
+    /// world.serialize(&mut serde_json::Serializer::new(&mut save)).unwrap();
+    ///
+    /// other.insert_resource(AppDespawnMode::new(DespawnMode::None));
+    /// other.insert_resource(AppMappingMode::new(MappingMode::Strict));
+    ///
+    /// // Shift the applied ids by one, so they overlap the saved indices
+    /// let world = &mut other.world;
+    /// world.spawn_empty();
+    /// world.deserialize(&mut serde_json::Deserializer::from_slice(&save)).unwrap();
+    ///
+    /// let mut ids = world.query::<(&SaveId, Entity)>();
+    /// let live = ids.iter(world).map(|(id, entity)| (id.0, entity)).collect::<HashMap<_, _>>();
+    /// let (a, b, hub) = (live[&1], live[&2], live[&3]);
+    ///
+    /// assert_eq!(world.get::<Links>(hub).unwrap().0, [a, b]);
+    /// assert_eq!(world.get::<Links>(a).unwrap().0, [hub]);
+    /// ```
+    fn register_entity_fields<T: GetTypeRegistration>(&mut self) -> &mut Self;
+
+    /// Mark a saveable component as local-only, so applying never overwrites it on existing entities.
+    ///
+    /// Useful for runtime state such as selection highlights. The component is still saved,
+    /// and inserted on entities that are spawned by the snapshot or do not have it yet.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    /// #[reflect(Component)]
+    /// struct Highlight(bool);
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// app.register_saveable::<Highlight>()
+    ///     .mark_local_only::<Highlight>();
+    ///
+    /// let world = &mut app.world;
+    ///
+    /// let live = world.spawn((Transform::default(), Highlight(false))).id();
+    /// let removed = world.spawn((Transform::default(), Highlight(false))).id();
+    ///
+    /// let snapshot = Snapshot::from_world(world);
+    ///
+    /// world.entity_mut(live).insert((Transform::from_xyz(1.0, 0.0, 0.0), Highlight(true)));
+    /// world.despawn(removed);
+    ///
+    /// snapshot.apply(world).unwrap();
+    ///
+    /// // The existing entity keeps its live highlight, but other components are restored
+    /// assert_eq!(world.get::<Highlight>(live), Some(&Highlight(true)));
+    /// assert_eq!(world.get::<Transform>(live), Some(&Transform::default()));
+    ///
+    /// // The respawned entity gets the saved highlight
+    /// let respawned = world
+    ///     .query::<(Entity, &Highlight)>()
+    ///     .iter(world)
+    ///     .find(|(entity, _)| *entity != live)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(respawned.1, &Highlight(false));
+    /// ```
+    fn mark_local_only<T: GetTypeRegistration>(&mut self) -> &mut Self;
+
+    /// Register a [`HashMap`](std::collections::HashMap) instance along with its key and value types.
+    ///
+    /// Maps round-trip with any reflected key type, such as integers or enums, but Bevy's reflection requires each generic
+    /// instance to be registered individually. Both the [`std`] map and Bevy's [`HashMap`](bevy::utils::HashMap) are registered.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Resource, Reflect, Default)]
+    /// #[reflect(Resource)]
+    /// struct HighScores(HashMap<u32, i32>);
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let mut other = App::new();
+    /// # other.add_plugins(MinimalPlugins);
+    /// # other.add_plugins(SavePlugins);
+    /// for app in [&mut app, &mut other] {
+    ///     app.register_saveable::<HighScores>()
+    ///         .register_map::<u32, i32>();
+    /// }
+    ///
+    /// app.insert_resource(HighScores(HashMap::from([(1, 300), (2, -50)])));
+    ///
+    /// let mut save = Vec::new();
+    /// app.world.serialize(&mut serde_json::Serializer::new(&mut save)).unwrap();
+    ///
+    /// other.world.deserialize(&mut serde_json::Deserializer::from_slice(&save)).unwrap();
+    ///
+    /// let scores = &other.world.resource::<HighScores>().0;
+    /// assert_eq!(scores, &HashMap::from([(1, 300), (2, -50)]));
+    /// ```
+    fn register_map<K, V>(&mut self) -> &mut Self
+    where
+        K: GetTypeRegistration + FromReflect + TypePath + Eq + Hash,
+        V: GetTypeRegistration + FromReflect + TypePath;
+
+    /// Check that every type reachable from the fields of saveable types is registered in the [`AppTypeRegistry`].
+    ///
+    /// Bevy's reflection requires each generic instance of a type to be registered individually,
+    /// which is otherwise only discovered when a save fails. Each missing type is also logged as a warning.
+    ///
+    /// # Errors
+    /// - [`SaveableError::MissingRegistrations`] listing the missing types
+    ///
+    /// # Example
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Resource, Reflect, Default)]
+    /// #[reflect(Resource)]
+    /// struct Scores(HashMap<String, u64>);
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// app.register_saveable::<Scores>();
+    ///
+    /// let missing = |app: &App| match app.verify_saveable_registrations() {
+    ///     Err(SaveableError::MissingRegistrations { type_names }) => type_names,
+    ///     _ => Vec::new(),
+    /// };
+    ///
+    /// let scores = std::any::type_name::<HashMap<String, u64>>().to_string();
+    ///
+    /// assert!(missing(&app).contains(&scores));
+    ///
+    /// app.register_type::<HashMap<String, u64>>();
+    ///
+    /// assert!(!missing(&app).contains(&scores));
+    /// ```
+    fn verify_saveable_registrations(&self) -> Result<(), SaveableError>;
+
+    /// Register a save extension, side-channel data that is saved after the snapshot in the same save file.
+    ///
+    /// When saving, `serialize` returns the bytes to store under `name`. When loading, `deserialize` receives
+    /// those bytes, before the snapshot is applied. This is useful for state that lives outside of the [`World`],
+    /// such as an audio or logging system. Saves without data for an extension load without calling it.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::sync::Mutex;
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// static AUDIO: Mutex<String> = Mutex::new(String::new());
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// app.insert_resource(AppBackend::new(MemoryBackend::new()))
+    ///     .register_save_extension(
+    ///         "audio",
+    ///         |_: &World| AUDIO.lock().unwrap().clone().into_bytes(),
+    ///         |_: &mut World, data: &[u8]| {
+    ///             *AUDIO.lock().unwrap() = String::from_utf8_lossy(data).into_owned();
+    ///         },
+    ///     );
+    ///
+    /// app.world.spawn(Transform::from_xyz(1.0, 2.0, 3.0));
+    ///
+    /// for saver in [AppSaver::new(RMPSaver), AppSaver::new(JSONSaver::default())] {
+    ///     app.insert_resource(saver);
+    ///
+    ///     *AUDIO.lock().unwrap() = "track 3 at 1:24".to_string();
+    ///     app.world.save("extended").unwrap();
+    ///
+    ///     AUDIO.lock().unwrap().clear();
+    ///     app.world.clear_entities();
+    ///     app.world.load("extended").unwrap();
+    ///
+    ///     assert_eq!(*AUDIO.lock().unwrap(), "track 3 at 1:24");
+    ///
+    ///     let transform = app.world.query::<&Transform>().single(&app.world);
+    ///     assert_eq!(transform.translation, Vec3::new(1.0, 2.0, 3.0));
+    /// }
+    /// ```
+    fn register_save_extension<S, D>(
+        &mut self,
+        name: &str,
+        serialize: S,
+        deserialize: D,
+    ) -> &mut Self
+    where
+        S: SerializeExtension + 'static,
+        D: DeserializeExtension + 'static;
+
+    /// Register a [`States`] type as saveable, so snapshots capture and restore the current [`State<S>`].
+    ///
+    /// The state is saved through its [`SavedState<S>`] mirror. Applying a snapshot with a different state
+    /// sets [`NextState<S>`], and the transition runs the [`OnExit`] and [`OnEnter`] schedules on the next update.
+    /// The state must be added with [`App::add_state`].
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(States, Reflect, Default, Debug, Clone, PartialEq, Eq, Hash)]
+    /// enum Phase {
+    ///     #[default]
+    ///     Menu,
+    ///     Playing,
+    /// }
+    ///
+    /// #[derive(Resource, Default)]
+    /// struct Entered(u32);
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// app.add_state::<Phase>()
+    ///     .register_saveable_state::<Phase>()
+    ///     .init_resource::<Entered>()
+    ///     .add_systems(OnEnter(Phase::Playing), |mut entered: ResMut<Entered>| entered.0 += 1);
+    ///
+    /// let phase = |app: &App| app.world.resource::<State<Phase>>().get().clone();
+    ///
+    /// app.world.resource_mut::<NextState<Phase>>().set(Phase::Playing);
+    /// app.update();
+    ///
+    /// let snapshot = Snapshot::from_world(&app.world);
+    ///
+    /// app.world.resource_mut::<NextState<Phase>>().set(Phase::Menu);
+    /// app.update();
+    /// assert_eq!(phase(&app), Phase::Menu);
+    ///
+    /// snapshot.apply(&mut app.world).unwrap();
+    /// app.update();
+    ///
+    /// assert_eq!(phase(&app), Phase::Playing);
+    /// assert_eq!(app.world.resource::<Entered>().0, 2);
+    /// ```
+    fn register_saveable_state<S>(&mut self) -> &mut Self
+    where
+        S: States + Reflect + FromReflect + TypePath + GetTypeRegistration;
+
+    /// Register the [`FixedTime`] accumulator as saveable, so fixed-timestep simulations resume in phase.
+    ///
+    /// The accumulator, period and step count are saved through the [`SavedFixedTime`] mirror.
+    /// Applying a snapshot writes them back into [`FixedTime`] at the start of the next update,
+    /// before the fixed update loop runs.
+    ///
+    /// The [`Time`] resource is not saved, so a restored simulation only replays deterministically
+    /// if the following frames advance by the same deltas, e.g. with [`TimeUpdateStrategy`](bevy::time::TimeUpdateStrategy).
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::{prelude::*, time::TimeUpdateStrategy, utils::Duration};
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// app.register_saveable_fixed_time()
+    ///     .insert_resource(FixedTime::new(Duration::from_millis(100)));
+    ///
+    /// let advance = |app: &mut App, millis: u64| {
+    ///     let delta = Duration::from_millis(millis);
+    ///     app.insert_resource(TimeUpdateStrategy::ManualDuration(delta));
+    ///     app.update();
+    /// };
+    ///
+    /// let state = |app: &App| {
+    ///     let saved = app.world.resource::<SavedFixedTime>();
+    ///     (app.world.resource::<FixedTime>().accumulated(), saved.steps)
+    /// };
+    ///
+    /// // Advance partway into a fixed step
+    /// for _ in 0..3 {
+    ///     advance(&mut app, 230);
+    /// }
+    ///
+    /// let snapshot = Snapshot::from_world(&app.world);
+    /// let saved = state(&app);
+    /// assert_ne!(saved.0, Duration::ZERO);
+    ///
+    /// advance(&mut app, 130);
+    /// advance(&mut app, 130);
+    /// assert_ne!(state(&app), saved);
+    ///
+    /// snapshot.apply(&mut app.world).unwrap();
+    /// advance(&mut app, 0);
+    ///
+    /// assert_eq!(state(&app), saved);
+    /// ```
+    fn register_saveable_fixed_time(&mut self) -> &mut Self;
+
+    /// Save the asset path of each [`Handle<T>`], so loading re-requests the asset from the [`AssetServer`].
+    ///
+    /// Handles are saved as their [`SavedAssetPath<T>`], and restored handles are fresh strong handles
+    /// loaded from the saved path. Handles that were not loaded from a path, e.g. added with [`Assets::add`], are not saved.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let mut other = App::new();
+    /// # other.add_plugins(MinimalPlugins);
+    /// # other.add_plugins(SavePlugins);
+    /// for app in [&mut app, &mut other] {
+    ///     app.add_plugins(AssetPlugin::default())
+    ///         .add_asset::<Image>()
+    ///         .register_asset_paths::<Image>();
+    /// }
+    ///
+    /// let handle = app.world.resource::<AssetServer>().load::<Image, _>("textures/player.png");
+    /// app.world.spawn(handle);
+    /// app.update();
+    ///
+    /// let snapshot = Snapshot::from_world(&app.world);
+    /// snapshot.apply(&mut other.world).unwrap();
+    /// other.update();
+    ///
+    /// let handle = other
+    ///     .world
+    ///     .query::<&Handle<Image>>()
+    ///     .single(&other.world)
+    ///     .clone();
+    ///
+    /// let server = other.world.resource::<AssetServer>();
+    ///
+    /// assert!(handle.is_strong());
+    /// assert_eq!(server.get_handle_path(&handle), Some("textures/player.png".into()));
+    /// ```
+    #[cfg(feature = "bevy_asset")]
+    fn register_asset_paths<T: Asset>(&mut self) -> &mut Self;
+
+    /// Add systems to the [`PostLoad`] schedule, run once after each snapshot or rollback is applied.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Resource, Default)]
+    /// struct Loads(usize);
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// app.init_resource::<Loads>()
+    ///     .add_post_load_system(|mut loads: ResMut<Loads>| loads.0 += 1);
+    ///
+    /// app.world.spawn(Transform::default());
+    /// app.world.checkpoint();
+    /// app.update();
+    ///
+    /// assert_eq!(app.world.resource::<Loads>().0, 0);
+    ///
+    /// let snapshot = Snapshot::from_world(&app.world);
+    /// snapshot.apply(&mut app.world).unwrap();
+    ///
+    /// assert_eq!(app.world.resource::<Loads>().0, 1);
+    ///
+    /// app.world.rollback(0).unwrap();
+    /// snapshot.apply(&mut app.world).unwrap();
+    ///
+    /// assert_eq!(app.world.resource::<Loads>().0, 3);
+    /// ```
+    fn add_post_load_system<M>(&mut self, systems: impl IntoSystemConfigs<M>) -> &mut Self;
 }
 
 impl AppSaveableExt for App {
@@ -31,6 +807,36 @@ impl AppSaveableExt for App {
         self
     }
 
+    fn register_saveable_with_entities<T: GetTypeRegistration>(
+        &mut self,
+    ) -> Result<&mut Self, SaveableError> {
+        self.register_type::<T>();
+
+        let type_id = T::get_type_registration().type_id();
+        let registry = self.world.resource::<AppTypeRegistry>().read();
+
+        let mapped = registry
+            .get(type_id)
+            .is_some_and(|reg| reg.data::<ReflectMapEntities>().is_some());
+
+        if !mapped {
+            let type_name = T::get_type_registration().type_name().to_string();
+            return Err(SaveableError::MissingMapEntities { type_name });
+        }
+
+        drop(registry);
+
+        Ok(self.register_saveable::<T>())
+    }
+
+    fn unregister_saveable<T: GetTypeRegistration>(&mut self) -> &mut Self {
+        let mut registry = self.world.resource_mut::<SaveableRegistry>();
+
+        registry.unregister(T::get_type_registration().type_name());
+
+        self
+    }
+
     fn ignore_rollback<T: GetTypeRegistration>(&mut self) -> &mut Self {
         let mut registry = self.world.resource_mut::<SaveableRegistry>();
 
@@ -46,4 +852,198 @@ impl AppSaveableExt for App {
 
         self
     }
+
+    fn register_coupled_types<A, B>(&mut self) -> &mut Self
+    where
+        A: GetTypeRegistration,
+        B: GetTypeRegistration,
+    {
+        let mut registry = self.world.resource_mut::<SaveableRegistry>();
+
+        registry.couple::<A, B>();
+
+        self
+    }
+
+    fn register_serialized_name<T: GetTypeRegistration>(&mut self, name: &str) -> &mut Self {
+        self.register_type::<T>();
+
+        let type_id = T::get_type_registration().type_id();
+        let mut registry = self.world.resource::<AppTypeRegistry>().write();
+
+        let taken = registry.iter().find(|reg| {
+            reg.type_id() != type_id
+                && (reg.type_name() == name
+                    || reg.data::<SerializedName>().is_some_and(|n| n.name() == name))
+        });
+
+        if let Some(reg) = taken {
+            panic!(
+                "Cannot serialize `{}` as `{name}`, the name is already used by `{}`",
+                std::any::type_name::<T>(),
+                reg.type_name()
+            );
+        }
+
+        if let Some(reg) = registry.get_mut(type_id) {
+            reg.insert(SerializedName::new(name));
+        }
+
+        drop(registry);
+
+        self
+    }
+
+    fn register_field_filter<T: GetTypeRegistration>(&mut self, paths: &[&str]) -> &mut Self {
+        self.register_type::<T>();
+
+        let type_id = T::get_type_registration().type_id();
+        let registry = self.world.resource::<AppTypeRegistry>();
+
+        if let Some(reg) = registry.write().get_mut(type_id) {
+            reg.insert(FieldFilter::new(paths.iter().copied()));
+        }
+
+        self
+    }
+
+    fn register_relative_time<T: GetTypeRegistration>(&mut self, path: &str) -> &mut Self {
+        self.register_type::<T>()
+            .register_type::<Duration>()
+            .register_type::<Instant>();
+
+        let type_id = T::get_type_registration().type_id();
+        let registry = self.world.resource::<AppTypeRegistry>();
+
+        if let Some(reg) = registry.write().get_mut(type_id) {
+            if let Some(time) = reg.data_mut::<RelativeTime>() {
+                time.push(path);
+            } else {
+                reg.insert(RelativeTime::new([path]));
+            }
+        }
+
+        self
+    }
+
+    fn register_indexed_fields<T: GetTypeRegistration>(&mut self) -> &mut Self {
+        self.register_type::<T>();
+
+        let type_id = T::get_type_registration().type_id();
+        let registry = self.world.resource::<AppTypeRegistry>();
+
+        if let Some(reg) = registry.write().get_mut(type_id) {
+            reg.insert(IndexedFields);
+        }
+
+        self
+    }
+
+    fn register_entity_fields<T: GetTypeRegistration>(&mut self) -> &mut Self {
+        self.register_type::<T>();
+
+        let type_id = T::get_type_registration().type_id();
+        let registry = self.world.resource::<AppTypeRegistry>();
+
+        if let Some(reg) = registry.write().get_mut(type_id) {
+            reg.insert(EntityFields);
+        }
+
+        self
+    }
+
+    fn mark_local_only<T: GetTypeRegistration>(&mut self) -> &mut Self {
+        self.register_type::<T>();
+
+        let type_id = T::get_type_registration().type_id();
+        let registry = self.world.resource::<AppTypeRegistry>();
+
+        if let Some(reg) = registry.write().get_mut(type_id) {
+            reg.insert(LocalOnly);
+        }
+
+        self
+    }
+
+    fn register_map<K, V>(&mut self) -> &mut Self
+    where
+        K: GetTypeRegistration + FromReflect + TypePath + Eq + Hash,
+        V: GetTypeRegistration + FromReflect + TypePath,
+    {
+        self.register_type::<K>()
+            .register_type::<V>()
+            .register_type::<std::collections::HashMap<K, V>>()
+            .register_type::<HashMap<K, V>>()
+    }
+
+    fn register_save_extension<S, D>(
+        &mut self,
+        name: &str,
+        serialize: S,
+        deserialize: D,
+    ) -> &mut Self
+    where
+        S: SerializeExtension + 'static,
+        D: DeserializeExtension + 'static,
+    {
+        self.init_resource::<AppSaveExtensions>()
+            .world
+            .resource_mut::<AppSaveExtensions>()
+            .insert(name, serialize, deserialize);
+
+        self
+    }
+
+    fn register_saveable_state<S>(&mut self) -> &mut Self
+    where
+        S: States + Reflect + FromReflect + TypePath + GetTypeRegistration,
+    {
+        self.register_type::<S>()
+            .register_saveable::<SavedState<S>>()
+            .init_resource::<SavedState<S>>()
+            .add_systems(PreUpdate, restore_saved_state::<S>)
+            .add_systems(StateTransition, sync_saved_state::<S>.after(apply_state_transition::<S>))
+    }
+
+    fn register_saveable_fixed_time(&mut self) -> &mut Self {
+        self.register_type::<Duration>()
+            .register_saveable::<SavedFixedTime>()
+            .init_resource::<SavedFixedTime>()
+            .add_systems(PreUpdate, restore_saved_fixed_time)
+            .add_systems(FixedUpdate, count_fixed_steps)
+            .add_systems(
+                RunFixedUpdateLoop,
+                sync_saved_fixed_time.after(run_fixed_update_schedule),
+            )
+    }
+
+    #[cfg(feature = "bevy_asset")]
+    fn register_asset_paths<T: Asset>(&mut self) -> &mut Self {
+        self.register_saveable::<SavedAssetPath<T>>()
+            .add_systems(PreUpdate, restore_asset_paths::<T>)
+            .add_systems(PostUpdate, sync_asset_paths::<T>.before(CheckpointSet))
+    }
+
+    fn add_post_load_system<M>(&mut self, systems: impl IntoSystemConfigs<M>) -> &mut Self {
+        self.add_systems(PostLoad, systems)
+    }
+
+    fn verify_saveable_registrations(&self) -> Result<(), SaveableError> {
+        let registry = self.world.resource::<AppTypeRegistry>().read();
+
+        let type_names = self
+            .world
+            .resource::<SaveableRegistry>()
+            .missing_registrations(&registry);
+
+        if type_names.is_empty() {
+            return Ok(());
+        }
+
+        for type_name in &type_names {
+            warn!("Saveable types reference the unregistered type `{type_name}`");
+        }
+
+        Err(SaveableError::MissingRegistrations { type_names })
+    }
 }
@@ -1,5 +1,8 @@
 use std::{
-    collections::HashSet,
+    collections::{
+        HashMap,
+        HashSet,
+    },
     marker::PhantomData,
     sync::Arc,
 };
@@ -14,6 +17,8 @@ use bevy::{
     prelude::*,
 };
 
+use crate::SaveableError;
+
 /// A [`ReadOnlyWorldQuery`] filter.
 pub trait Filter: Send + Sync {
     /// Collect all entities from the given [`World`] matching the filter.
@@ -86,6 +91,40 @@ pub enum DespawnMode {
     /// Despawn unmapped entities matching filter
     UnmappedWith(BoxedFilter),
 
+    /// Despawn entities missing from the save with a [`SpawnTick`](crate::SpawnTick) older than the given tick
+    ///
+    /// Entities spawned at or after the tick, and entities without a [`SpawnTick`](crate::SpawnTick), are kept.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let world = &mut app.world;
+    /// world.clear_entities();
+    ///
+    /// let snapshot = Snapshot::from_world(world);
+    ///
+    /// let old = world.spawn(SpawnTick(1)).id();
+    /// let older = world.spawn(SpawnTick(4)).id();
+    /// let newer = world.spawn(SpawnTick(5)).id();
+    /// let untagged = world.spawn_empty().id();
+    ///
+    /// snapshot
+    ///     .applier(world)
+    ///     .despawn(DespawnMode::MissingOlderThan(5))
+    ///     .apply()
+    ///     .unwrap();
+    ///
+    /// assert!(world.get_entity(old).is_none());
+    /// assert!(world.get_entity(older).is_none());
+    /// assert!(world.get_entity(newer).is_some());
+    /// assert!(world.get_entity(untagged).is_some());
+    /// ```
+    MissingOlderThan(u32),
+
     /// Despawn all entities
     ///
     /// This is probably not what you want - in most cases this will close your app's [`Window`]
@@ -101,6 +140,14 @@ pub enum DespawnMode {
 }
 
 impl DespawnMode {
+    /// Create a new instance of [`DespawnMode::MissingWith`] with the given filter.
+    pub fn missing_with<F>() -> Self
+    where
+        F: ReadOnlyWorldQuery + Send + Sync + 'static,
+    {
+        DespawnMode::MissingWith(<dyn Filter>::boxed::<F>())
+    }
+
     /// Create a new instance of [`DespawnMode::UnmappedWith`] with the given filter.
     pub fn unmapped_with<F>() -> Self
     where
@@ -110,11 +157,48 @@ impl DespawnMode {
     }
 
     /// Create a new instance of [`DespawnMode::AllWith`] with the given filter.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::{ecs::entity::EntityMap, prelude::*};
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let world = &mut app.world;
+    /// world.clear_entities();
+    ///
+    /// let saved = world.spawn((Name::new("Saved"), Transform::default())).id();
+    /// let kept = world.spawn(Name::new("Kept")).id();
+    ///
+    /// let snapshot = Snapshot::from_world(world);
+    ///
+    /// let extra = world.spawn(Transform::default()).id();
+    ///
+    /// // Unlike `DespawnMode::UnmappedWith`, mapped entities are despawned too
+    /// let mut map = EntityMap::default();
+    /// map.insert(Entity::from_raw(saved.index()), saved);
+    ///
+    /// snapshot
+    ///     .applier(world)
+    ///     .map(map)
+    ///     .despawn(DespawnMode::all_with::<With<Transform>>())
+    ///     .apply()
+    ///     .unwrap();
+    ///
+    /// // Every matching entity is despawned, and the saved ones are spawned again
+    /// assert!(world.get_entity(saved).is_none());
+    /// assert!(world.get_entity(extra).is_none());
+    /// assert!(world.get_entity(kept).is_some());
+    ///
+    /// let mut names = world.query::<&Name>();
+    /// assert_eq!(names.iter(world).filter(|name| name.as_str() == "Saved").count(), 1);
+    /// ```
     pub fn all_with<F>() -> Self
     where
         F: ReadOnlyWorldQuery + Send + Sync + 'static,
     {
-        DespawnMode::UnmappedWith(<dyn Filter>::boxed::<F>())
+        DespawnMode::AllWith(<dyn Filter>::boxed::<F>())
     }
 }
 
@@ -147,6 +231,67 @@ impl<T> Hook for T where T: for<'a> Fn(&'a EntityRef, &'a mut EntityCommands) +
 /// A boxed [`Hook`].
 pub type BoxedHook = Box<dyn Hook>;
 
+/// A [`Reinterpret`] rewrites each saved component into zero or more components when applying a snapshot.
+///
+/// It receives the type name of the saved component and its value, and returns the components to apply in its place.
+/// Return a clone of the value to apply the component unchanged.
+pub trait Reinterpret: Fn(&str, &dyn Reflect) -> Vec<Box<dyn Reflect>> + Send + Sync {}
+
+impl<T> Reinterpret for T where T: Fn(&str, &dyn Reflect) -> Vec<Box<dyn Reflect>> + Send + Sync {}
+
+/// A boxed [`Reinterpret`].
+pub type BoxedReinterpret = Box<dyn Reinterpret>;
+
+/// A [`Resolve`] merges a saved component into the live component it would overwrite when applying a snapshot.
+///
+/// It receives the type name of the component, the live value and the saved value, and returns the value to apply.
+pub trait Resolve: Fn(&str, &dyn Reflect, &dyn Reflect) -> Box<dyn Reflect> + Send + Sync {}
+
+impl<T> Resolve for T where T: Fn(&str, &dyn Reflect, &dyn Reflect) -> Box<dyn Reflect> + Send + Sync {}
+
+/// A boxed [`Resolve`].
+pub type BoxedResolve = Box<dyn Resolve>;
+
+/// A [`PhaseHook`] runs with mutable [`World`] access between the phases of applying a snapshot.
+///
+/// See [`Applier::between_phases`].
+pub trait PhaseHook: Fn(&mut World) + Send + Sync {}
+
+impl<T> PhaseHook for T where T: Fn(&mut World) + Send + Sync {}
+
+/// A boxed [`PhaseHook`].
+pub type BoxedPhaseHook = Box<dyn PhaseHook>;
+
+/// An [`OnMapped`] callback receives each saved entity index and the entity it was applied to.
+///
+/// See [`Applier::on_mapped`].
+pub trait OnMapped: Fn(u32, Entity) + Send + Sync {}
+
+impl<T> OnMapped for T where T: Fn(u32, Entity) + Send + Sync {}
+
+/// A boxed [`OnMapped`] callback.
+pub type BoxedOnMapped = Box<dyn OnMapped>;
+
+/// An [`OnOverwrite`] callback receives each live entity and component type name whose differing value was overwritten.
+///
+/// See [`Applier::on_overwrite`].
+pub trait OnOverwrite: Fn(Entity, &str) + Send + Sync {}
+
+impl<T> OnOverwrite for T where T: Fn(Entity, &str) + Send + Sync {}
+
+/// A boxed [`OnOverwrite`] callback.
+pub type BoxedOnOverwrite = Box<dyn OnOverwrite>;
+
+/// A [`DespawnHook`] despawns an entity removed by the [`DespawnMode`] when applying a snapshot.
+///
+/// See [`Applier::despawn_with`].
+pub trait DespawnHook: Fn(&mut World, Entity) + Send + Sync {}
+
+impl<T> DespawnHook for T where T: Fn(&mut World, Entity) + Send + Sync {}
+
+/// A boxed [`DespawnHook`].
+pub type BoxedDespawnHook = Box<dyn DespawnHook>;
+
 /// Determines how the snapshot will map entities when applied.
 #[derive(Default)]
 pub enum MappingMode {
@@ -160,6 +305,56 @@ pub enum MappingMode {
     ///
     /// `bevy_scene` default
     Strict,
+
+    /// If unmapped, map the Nth saved entity onto the Nth live entity. If there are fewer live entities, spawn a new entity.
+    ///
+    /// Unmapped saved entities and live entities that are not the target of the [`Applier::map`] are each sorted
+    /// by entity index, so two worlds that spawned their entities in the same order pair up without [`SaveId`](crate::SaveId)s.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let mut other = App::new();
+    /// # other.add_plugins(MinimalPlugins);
+    /// # other.add_plugins(SavePlugins);
+    /// let generate = |world: &mut World, y: f32| {
+    ///     (0..3)
+    ///         .map(|i| world.spawn(Transform::from_xyz(i as f32, y, 0.0)).id())
+    ///         .collect::<Vec<_>>()
+    /// };
+    ///
+    /// generate(&mut app.world, 10.0);
+    ///
+    /// let snapshot = Snapshot::from_world(&app.world);
+    ///
+    /// // The same world, generated at different entity indices
+    /// let world = &mut other.world;
+    ///
+    /// let fillers = (0..3).map(|_| world.spawn_empty().id()).collect::<Vec<_>>();
+    /// let live = generate(world, 0.0);
+    ///
+    /// for entity in fillers {
+    ///     world.despawn(entity);
+    /// }
+    ///
+    /// snapshot
+    ///     .applier(world)
+    ///     .mapping(MappingMode::Positional)
+    ///     .apply()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(world.entities().len(), 3);
+    ///
+    /// for (i, entity) in live.into_iter().enumerate() {
+    ///     let transform = world.get::<Transform>(entity).unwrap();
+    ///     assert_eq!(transform.translation, Vec3::new(i as f32, 10.0, 0.0));
+    /// }
+    /// ```
+    Positional,
 }
 
 /// The App's default [`DespawnMode`].
@@ -198,30 +393,238 @@ impl AppMappingMode {
     }
 }
 
+/// Type data marking a component that is never overwritten on existing entities when applying.
+///
+/// Register with [`AppSaveableExt::mark_local_only`](crate::AppSaveableExt::mark_local_only).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LocalOnly;
+
+/// Merge two [`EntityMap`]s into one, for example before passing them to [`Applier::map`].
+///
+/// Keys present in both maps must map to the same entity.
+///
+/// # Errors
+/// - [`SaveableError::ConflictingMapping`] if a key maps to different entities in each map
+///
+/// # Example
+/// ```
+/// # use bevy::{ecs::entity::EntityMap, prelude::*};
+/// # use bevy_save::prelude::*;
+/// let map = |pairs: &[(u32, u32)]| {
+///     let mut map = EntityMap::default();
+///     for &(saved, live) in pairs {
+///         map.insert(Entity::from_raw(saved), Entity::from_raw(live));
+///     }
+///     map
+/// };
+///
+/// let server = map(&[(0, 10), (1, 11)]);
+/// let local = map(&[(1, 11), (2, 12)]);
+///
+/// let merged = merge_entity_maps(&server, &local).unwrap();
+///
+/// assert_eq!(merged.len(), 3);
+/// assert_eq!(merged.get(Entity::from_raw(2)), Some(Entity::from_raw(12)));
+///
+/// let conflicting = map(&[(1, 20)]);
+///
+/// assert!(matches!(
+///     merge_entity_maps(&server, &conflicting),
+///     Err(SaveableError::ConflictingMapping { key, .. }) if key == Entity::from_raw(1)
+/// ));
+/// ```
+pub fn merge_entity_maps(a: &EntityMap, b: &EntityMap) -> Result<EntityMap, SaveableError> {
+    let mut merged = EntityMap::default();
+
+    for (key, entity) in a.iter() {
+        merged.insert(key, entity);
+    }
+
+    for (key, entity) in b.iter() {
+        match merged.get(key) {
+            Some(first) if first != entity => {
+                return Err(SaveableError::ConflictingMapping {
+                    key,
+                    first,
+                    second: entity,
+                });
+            }
+            _ => {
+                merged.insert(key, entity);
+            }
+        }
+    }
+
+    Ok(merged)
+}
+
+/// The result of [`Applier::dry_run`], describing what applying a snapshot would do to the [`World`].
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// # let world = &mut app.world;
+/// let player = world.spawn(Transform::default()).id();
+/// let snapshot = Snapshot::from_world(world);
+///
+/// world.entity_mut(player).insert(Transform::from_xyz(1.0, 2.0, 3.0));
+/// let extra = world.spawn_empty().id();
+///
+/// let plan = snapshot.applier(world).dry_run().unwrap();
+///
+/// assert_eq!(plan.despawned, vec![extra]);
+/// assert_eq!(plan.overwritten, vec![(
+///     player,
+///     "bevy_transform::components::transform::Transform".to_string()
+/// )]);
+///
+/// // Nothing was changed
+/// assert!(world.get_entity(extra).is_some());
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ApplyPlan {
+    /// Existing entities that would be despawned.
+    pub despawned: Vec<Entity>,
+
+    /// Saved entity indices that would be spawned as new entities.
+    pub spawned: Vec<u32>,
+
+    /// Existing components whose live value differs from the saved value and would be overwritten.
+    pub overwritten: Vec<(Entity, String)>,
+}
+
 /// [`Applier`] lets you configure how a snapshot will be applied to the [`World`].
+#[allow(clippy::struct_excessive_bools)]
 pub struct Applier<'a, S> {
     pub(crate) world: &'a mut World,
     pub(crate) snapshot: S,
     pub(crate) map: EntityMap,
     pub(crate) despawn: Option<DespawnMode>,
+    pub(crate) despawn_with: Option<BoxedDespawnHook>,
     pub(crate) mapping: Option<MappingMode>,
     pub(crate) hook: Option<BoxedHook>,
+    pub(crate) between: Option<BoxedPhaseHook>,
+    pub(crate) mapped: Option<BoxedOnMapped>,
+    pub(crate) overwrite: Option<BoxedOnOverwrite>,
+    pub(crate) protect: Option<BoxedFilter>,
+    pub(crate) reinterpret: Option<BoxedReinterpret>,
+    pub(crate) resolve: Option<BoxedResolve>,
+    pub(crate) exact: bool,
+    pub(crate) layout: bool,
+    pub(crate) skip_mapping: bool,
+    pub(crate) skip_unregistered: bool,
+    pub(crate) skipped_resources: HashSet<String>,
+    pub(crate) only_types: Option<HashSet<String>>,
+    pub(crate) preserved: HashMap<Entity, HashSet<String>>,
+    pub(crate) reparent: Option<Entity>,
+    pub(crate) failures: Option<Vec<(Entity, SaveableError)>>,
 }
 
 impl<'a, S> Applier<'a, S> {
     /// Create a new [`Applier`] with default settings from the world and snapshot.
+    ///
+    /// The [`World`] does not have to be the [`App`] world, but it must contain the [`AppTypeRegistry`]
+    /// and [`SaveableRegistry`](crate::SaveableRegistry) resources, with every type in the snapshot registered.
+    /// [`World::sub_world`](crate::WorldSaveableExt::sub_world) creates such a [`World`] from an existing one.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// app.world.spawn(Transform::from_xyz(1.0, 2.0, 3.0));
+    ///
+    /// let snapshot = Snapshot::builder(&app.world).extract_all_entities().build();
+    ///
+    /// // A bare world with only the registries `bevy_save` needs
+    /// let registry = AppTypeRegistry::default();
+    /// registry.write().register::<Transform>();
+    ///
+    /// let mut saveables = SaveableRegistry::default();
+    /// saveables.register::<Transform>();
+    ///
+    /// let mut world = World::new();
+    /// world.insert_resource(registry);
+    /// world.insert_resource(saveables);
+    ///
+    /// Applier::new(&mut world, &snapshot).apply().unwrap();
+    ///
+    /// let transform = world.query::<&Transform>().single(&world);
+    /// assert_eq!(transform.translation, Vec3::new(1.0, 2.0, 3.0));
+    /// ```
     pub fn new(world: &'a mut World, snapshot: S) -> Self {
         Self {
             world,
             snapshot,
             map: EntityMap::default(),
             despawn: None,
+            despawn_with: None,
             mapping: None,
             hook: None,
+            between: None,
+            mapped: None,
+            overwrite: None,
+            protect: None,
+            reinterpret: None,
+            resolve: None,
+            exact: false,
+            layout: false,
+            skip_mapping: false,
+            skip_unregistered: false,
+            skipped_resources: HashSet::new(),
+            only_types: None,
+            preserved: HashMap::new(),
+            reparent: None,
+            failures: None,
         }
     }
 
     /// Map entities to new ids with the [`EntityMap`].
+    ///
+    /// Each existing entity receives at most one snapshot entity, whether it was targeted by the [`EntityMap`]
+    /// or by the [`MappingMode`] fallback. Any further snapshot entities targeting it are spawned instead.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::{ecs::entity::EntityMap, prelude::*};
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let world = &mut app.world;
+    /// world.clear_entities();
+    ///
+    /// let a = world.spawn(Transform::from_xyz(1.0, 0.0, 0.0)).id();
+    /// let b = world.spawn(Transform::from_xyz(2.0, 0.0, 0.0)).id();
+    ///
+    /// let snapshot = Snapshot::from_world(world);
+    ///
+    /// // Saved `a` is mapped onto `b`, which is also the fallback for saved `b`
+    /// let mut map = EntityMap::default();
+    /// map.insert(Entity::from_raw(a.index()), b);
+    ///
+    /// // `a` is despawned and its index is freed for the entity spawned for saved `b`
+    /// let applied = snapshot.applier(world).map(map).apply_and_return_map().unwrap();
+    ///
+    /// assert!(world.get_entity(a).is_none());
+    /// assert_eq!(applied.get(Entity::from_raw(a.index())), Some(b));
+    /// assert_ne!(applied.get(Entity::from_raw(b.index())), Some(b));
+    ///
+    /// let mut xs = world
+    ///     .query::<&Transform>()
+    ///     .iter(world)
+    ///     .map(|t| t.translation.x)
+    ///     .collect::<Vec<_>>();
+    /// xs.sort_by(f32::total_cmp);
+    ///
+    /// assert_eq!(xs, [1.0, 2.0]);
+    /// ```
     pub fn map(mut self, map: EntityMap) -> Self {
         self.map = map;
         self
@@ -233,6 +636,51 @@ impl<'a, S> Applier<'a, S> {
         self
     }
 
+    /// Despawn the entities removed by the [`DespawnMode`] with a [`DespawnHook`] instead of [`World::despawn`].
+    ///
+    /// Use this to run custom teardown, such as returning pooled ids or notifying external systems.
+    /// The hook is responsible for despawning the entity.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Resource, Default)]
+    /// struct Despawned(u32);
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let world = &mut app.world;
+    /// world.clear_entities();
+    /// world.init_resource::<Despawned>();
+    ///
+    /// world.spawn(Transform::default());
+    /// let snapshot = Snapshot::from_world(world);
+    ///
+    /// // Missing from the snapshot, so despawned when applying
+    /// world.spawn_batch((0..3).map(|_| Transform::default()));
+    ///
+    /// snapshot
+    ///     .applier(world)
+    ///     .despawn_with(|world: &mut World, entity| {
+    ///         world.resource_mut::<Despawned>().0 += 1;
+    ///         world.despawn(entity);
+    ///     })
+    ///     .apply()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(world.resource::<Despawned>().0, 3);
+    /// assert_eq!(world.query::<&Transform>().iter(world).count(), 1);
+    /// ```
+    pub fn despawn_with<F>(mut self, despawn: F) -> Self
+    where
+        F: DespawnHook + 'static,
+    {
+        self.despawn_with = Some(Box::new(despawn));
+        self
+    }
+
     /// Change how the snapshot maps entities when applying.
     pub fn mapping(mut self, mode: MappingMode) -> Self {
         self.mapping = Some(mode);
@@ -247,4 +695,665 @@ impl<'a, S> Applier<'a, S> {
         self.hook = Some(Box::new(hook));
         self
     }
+
+    /// Add a [`PhaseHook`] that runs after entities are despawned, but before any snapshot entities are applied or spawned.
+    ///
+    /// This is the place to clean up state tied to despawned entities, such as GPU resources.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::sync::{Arc, Mutex};
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let world = &mut app.world;
+    /// world.clear_entities();
+    ///
+    /// let kept = world.spawn(Transform::default()).id();
+    /// let snapshot = Snapshot::from_world(world);
+    ///
+    /// // Missing from the snapshot, so despawned when applying
+    /// world.spawn_batch((0..3).map(|_| Transform::default()));
+    /// world.despawn(kept);
+    ///
+    /// let count = Arc::new(Mutex::new(None));
+    /// let recorded = count.clone();
+    ///
+    /// snapshot
+    ///     .applier(world)
+    ///     .between_phases(move |world: &mut World| {
+    ///         *recorded.lock().unwrap() = Some(world.entities().len());
+    ///     })
+    ///     .apply()
+    ///     .unwrap();
+    ///
+    /// // Everything was despawned, and the saved entity was not yet spawned
+    /// assert_eq!(*count.lock().unwrap(), Some(0));
+    /// assert_eq!(world.entities().len(), 1);
+    /// ```
+    pub fn between_phases<F>(mut self, hook: F) -> Self
+    where
+        F: PhaseHook + 'static,
+    {
+        self.between = Some(Box::new(hook));
+        self
+    }
+
+    /// Add an [`OnMapped`] callback that runs as each saved entity is mapped to the entity it is applied to.
+    ///
+    /// It runs once per saved entity, with the saved index and the live entity, before that entity's components are applied.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::sync::{Arc, Mutex};
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let mut other = App::new();
+    /// # other.add_plugins(MinimalPlugins);
+    /// # other.add_plugins(SavePlugins);
+    /// # let world = &mut app.world;
+    /// for i in 0..3 {
+    ///     world.spawn(Transform::from_xyz(i as f32, 0.0, 0.0));
+    /// }
+    ///
+    /// let snapshot = Snapshot::from_world(world);
+    ///
+    /// let world = &mut other.world;
+    /// world.spawn_batch((0..5).map(|_| ()));
+    ///
+    /// let mapped = Arc::new(Mutex::new(Vec::new()));
+    /// let recorded = mapped.clone();
+    ///
+    /// let map = snapshot
+    ///     .applier(world)
+    ///     .despawn(DespawnMode::None)
+    ///     .mapping(MappingMode::Strict)
+    ///     .on_mapped(move |index, entity| recorded.lock().unwrap().push((index, entity)))
+    ///     .apply_and_return_map()
+    ///     .unwrap();
+    ///
+    /// let mapped = mapped.lock().unwrap();
+    /// assert_eq!(mapped.len(), 3);
+    ///
+    /// for &(index, entity) in mapped.iter() {
+    ///     assert_eq!(map.get(Entity::from_raw(index)), Some(entity));
+    ///
+    ///     let transform = world.get::<Transform>(entity).unwrap();
+    ///     assert_eq!(transform.translation.x, index as f32);
+    /// }
+    /// ```
+    pub fn on_mapped<F>(mut self, mapped: F) -> Self
+    where
+        F: OnMapped + 'static,
+    {
+        self.mapped = Some(Box::new(mapped));
+        self
+    }
+
+    /// Add an [`OnOverwrite`] callback that runs for each live component overwritten by a differing saved value.
+    ///
+    /// Each live component is compared to the saved value with [`reflect_values_eq`] before it is overwritten,
+    /// so this costs a deep comparison per applied component. Newly inserted components are not reported.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::sync::{Arc, Mutex};
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let world = &mut app.world;
+    /// let player = world.spawn((Name::new("Player"), Transform::default())).id();
+    ///
+    /// let snapshot = Snapshot::from_world(world);
+    ///
+    /// world.entity_mut(player).insert(Transform::from_xyz(1.0, 0.0, 0.0));
+    ///
+    /// let overwritten = Arc::new(Mutex::new(Vec::new()));
+    /// let recorded = overwritten.clone();
+    ///
+    /// snapshot
+    ///     .applier(world)
+    ///     .on_overwrite(move |entity, type_name| {
+    ///         recorded.lock().unwrap().push((entity, type_name.to_string()));
+    ///     })
+    ///     .apply()
+    ///     .unwrap();
+    ///
+    /// // Only the differing `Transform` is reported, not the identical `Name`
+    /// assert_eq!(*overwritten.lock().unwrap(), vec![(
+    ///     player,
+    ///     std::any::type_name::<Transform>().to_string(),
+    /// )]);
+    /// ```
+    pub fn on_overwrite<F>(mut self, overwrite: F) -> Self
+    where
+        F: OnOverwrite + 'static,
+    {
+        self.overwrite = Some(Box::new(overwrite));
+        self
+    }
+
+    /// Protect entities matching the [`ReadOnlyWorldQuery`] from being despawned, regardless of [`DespawnMode`].
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let world = &mut app.world;
+    /// #[derive(Component)]
+    /// struct Persistent;
+    ///
+    /// let snapshot = Snapshot::from_world(world);
+    ///
+    /// let camera = world.spawn(Persistent).id();
+    /// let other = world.spawn_empty().id();
+    ///
+    /// snapshot
+    ///     .applier(world)
+    ///     .despawn(DespawnMode::Missing)
+    ///     .protect::<With<Persistent>>()
+    ///     .apply()
+    ///     .unwrap();
+    ///
+    /// assert!(world.get_entity(camera).is_some());
+    /// assert!(world.get_entity(other).is_none());
+    /// ```
+    pub fn protect<F>(mut self) -> Self
+    where
+        F: ReadOnlyWorldQuery + Send + Sync + 'static,
+    {
+        self.protect = Some(<dyn Filter>::boxed::<F>());
+        self
+    }
+
+    /// Add a [`Reinterpret`] that will rewrite each saved component when applying.
+    ///
+    /// This can be used to migrate old saves after changing component types.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy::reflect::FromReflect;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Component, Reflect, Default)]
+    /// #[reflect(Component)]
+    /// struct Stats {
+    ///     health: f32,
+    ///     mana: f32,
+    /// }
+    ///
+    /// #[derive(Component, Reflect, Default)]
+    /// #[reflect(Component)]
+    /// struct Health(f32);
+    ///
+    /// #[derive(Component, Reflect, Default)]
+    /// #[reflect(Component)]
+    /// struct Mana(f32);
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// app.register_saveable::<Stats>()
+    ///     .register_saveable::<Health>()
+    ///     .register_saveable::<Mana>();
+    ///
+    /// let world = &mut app.world;
+    ///
+    /// let entity = world.spawn(Stats { health: 10.0, mana: 5.0 }).id();
+    /// let snapshot = Snapshot::from_world(world);
+    ///
+    /// world.entity_mut(entity).remove::<Stats>();
+    ///
+    /// snapshot
+    ///     .applier(world)
+    ///     .reinterpret(|type_name, reflect| {
+    ///         if type_name == std::any::type_name::<Stats>() {
+    ///             let stats = Stats::from_reflect(reflect).unwrap();
+    ///             vec![Box::new(Health(stats.health)), Box::new(Mana(stats.mana))]
+    ///         } else {
+    ///             vec![reflect.clone_value()]
+    ///         }
+    ///     })
+    ///     .apply()
+    ///     .unwrap();
+    ///
+    /// let entity = world.entity(entity);
+    ///
+    /// assert!(!entity.contains::<Stats>());
+    /// assert_eq!(entity.get::<Health>().unwrap().0, 10.0);
+    /// assert_eq!(entity.get::<Mana>().unwrap().0, 5.0);
+    /// ```
+    pub fn reinterpret<F>(mut self, reinterpret: F) -> Self
+    where
+        F: Reinterpret + 'static,
+    {
+        self.reinterpret = Some(Box::new(reinterpret));
+        self
+    }
+
+    /// Resolve conflicts between saved components and the live components they would overwrite with a [`Resolve`].
+    ///
+    /// The resolver only runs when the entity already has the component, and the value it returns is applied
+    /// in place of the saved value. By default, the saved value overwrites the live value.
+    ///
+    /// With [`Applier::exact_overwrite`], live components are removed first, so the resolver never runs.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Component, Reflect, Default)]
+    /// #[reflect(Component)]
+    /// struct Health(f32);
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// app.register_saveable::<Health>();
+    ///
+    /// let world = &mut app.world;
+    ///
+    /// let healed = world.spawn(Health(5.0)).id();
+    /// let hurt = world.spawn(Health(20.0)).id();
+    /// let snapshot = Snapshot::from_world(world);
+    ///
+    /// world.entity_mut(healed).insert(Health(15.0));
+    /// world.entity_mut(hurt).insert(Health(10.0));
+    ///
+    /// snapshot
+    ///     .applier(world)
+    ///     .resolve_conflicts(|type_name, live, saved| {
+    ///         if type_name == std::any::type_name::<Health>() {
+    ///             let live = Health::from_reflect(live).unwrap();
+    ///             let saved = Health::from_reflect(saved).unwrap();
+    ///             Box::new(Health(live.0.max(saved.0)))
+    ///         } else {
+    ///             saved.clone_value()
+    ///         }
+    ///     })
+    ///     .apply()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(world.get::<Health>(healed).unwrap().0, 15.0);
+    /// assert_eq!(world.get::<Health>(hurt).unwrap().0, 20.0);
+    /// ```
+    pub fn resolve_conflicts<F>(mut self, resolve: F) -> Self
+    where
+        F: Resolve + 'static,
+    {
+        self.resolve = Some(Box::new(resolve));
+        self
+    }
+
+    /// Remove all saveable components from existing entities before applying the saved components.
+    ///
+    /// By default, components missing from the snapshot are left untouched on existing entities.
+    /// With this set, applying a snapshot to any world derived from it restores exactly the saved state.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Component, Reflect, Default)]
+    /// #[reflect(Component)]
+    /// struct Marker;
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// app.register_saveable::<Marker>();
+    ///
+    /// let world = &mut app.world;
+    /// let entity = world.spawn(Transform::default()).id();
+    ///
+    /// let snapshot = Snapshot::from_world(world);
+    ///
+    /// let serialize = |world: &World| {
+    ///     let mut out = Vec::new();
+    ///     world.serialize(&mut serde_json::Serializer::new(&mut out)).unwrap();
+    ///     out
+    /// };
+    ///
+    /// snapshot.applier(world).exact_overwrite().apply().unwrap();
+    /// let once = serialize(world);
+    ///
+    /// // Mutate the world between applies
+    /// world
+    ///     .entity_mut(entity)
+    ///     .insert((Marker, Transform::from_xyz(1.0, 2.0, 3.0)));
+    ///
+    /// snapshot.applier(world).exact_overwrite().apply().unwrap();
+    /// let twice = serialize(world);
+    ///
+    /// assert_eq!(once, twice);
+    /// assert!(!world.entity(entity).contains::<Marker>());
+    /// ```
+    pub fn exact_overwrite(mut self) -> Self {
+        self.exact = true;
+        self
+    }
+
+    /// Spawn each new entity at its saved index when that index is free, instead of the next available index.
+    ///
+    /// Applied to an empty [`World`] with a snapshot recorded by [`Builder::extract_layout`],
+    /// the live entity indices match the original [`World`] exactly.
+    /// Entities whose saved index is taken are spawned at the next available index as usual.
+    pub fn preserve_layout(mut self) -> Self {
+        self.layout = true;
+        self
+    }
+
+    /// Apply saved values verbatim, without running [`ReflectMapEntities`](bevy::ecs::reflect::ReflectMapEntities) for any type.
+    ///
+    /// This skips both the per-resource mapping and the final pass over every registered type,
+    /// which is pure overhead for rollbacks within the same session, where saved entity references are still valid.
+    ///
+    /// Only use this when every saved entity is applied to the entity it was captured from,
+    /// otherwise entity references will point at the wrong entities.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::sync::atomic::{AtomicUsize, Ordering};
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::{entity::{EntityMapper, MapEntities}, reflect::ReflectMapEntities};
+    /// # use bevy_save::prelude::*;
+    /// static MAPPED: AtomicUsize = AtomicUsize::new(0);
+    ///
+    /// #[derive(Component, Reflect)]
+    /// #[reflect(Component, MapEntities)]
+    /// struct Target(Entity);
+    ///
+    /// impl MapEntities for Target {
+    ///     fn map_entities(&mut self, mapper: &mut EntityMapper) {
+    ///         MAPPED.fetch_add(1, Ordering::SeqCst);
+    ///         self.0 = mapper.get_or_reserve(self.0);
+    ///     }
+    /// }
+    ///
+    /// impl FromWorld for Target {
+    ///     fn from_world(_: &mut World) -> Self {
+    ///         Self(Entity::PLACEHOLDER)
+    ///     }
+    /// }
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// app.register_saveable_with_entities::<Target>().unwrap();
+    ///
+    /// let world = &mut app.world;
+    ///
+    /// // A long chain of entities, each targeting the previous one
+    /// let mut previous = world.spawn(Transform::default()).id();
+    ///
+    /// for i in 1..1000 {
+    ///     previous = world
+    ///         .spawn((Transform::from_xyz(i as f32, 0.0, 0.0), Target(previous)))
+    ///         .id();
+    /// }
+    ///
+    /// world.checkpoint();
+    ///
+    /// for mut transform in world.query::<&mut Transform>().iter_mut(world) {
+    ///     transform.translation.x = -1.0;
+    /// }
+    ///
+    /// world
+    ///     .rollback_applier(0)
+    ///     .unwrap()
+    ///     .skip_entity_mapping()
+    ///     .apply()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(MAPPED.load(Ordering::SeqCst), 0);
+    ///
+    /// // State is restored and every reference still points at a live entity
+    /// let mut targets = world.query::<(&Target, &Transform)>();
+    /// assert_eq!(targets.iter(world).count(), 999);
+    ///
+    /// for (target, transform) in targets.iter(world) {
+    ///     let previous = world.get::<Transform>(target.0).unwrap();
+    ///     assert_eq!(previous.translation.x, transform.translation.x - 1.0);
+    /// }
+    /// ```
+    pub fn skip_entity_mapping(mut self) -> Self {
+        self.skip_mapping = true;
+        self
+    }
+
+    /// Skip saved resources that are not registered, logging a warning for each, instead of failing the whole apply.
+    ///
+    /// Useful when loading saves from a build that had extra resources. Entities and registered resources are still applied.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Resource, Reflect, Default)]
+    /// #[reflect(Resource)]
+    /// struct Score(u32);
+    ///
+    /// #[derive(Resource, Reflect, Default)]
+    /// #[reflect(Resource)]
+    /// struct Debug(bool);
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let mut other = App::new();
+    /// # other.add_plugins(MinimalPlugins);
+    /// # other.add_plugins(SavePlugins);
+    /// app.register_saveable::<Score>()
+    ///     .register_saveable::<Debug>()
+    ///     .insert_resource(Score(10))
+    ///     .insert_resource(Debug(true));
+    ///
+    /// app.world.spawn(Transform::from_xyz(1.0, 2.0, 3.0));
+    ///
+    /// let snapshot = Snapshot::from_world(&app.world);
+    ///
+    /// // A build without `Debug`
+    /// other.register_saveable::<Score>();
+    ///
+    /// let err = snapshot.apply(&mut other.world).unwrap_err();
+    /// assert!(matches!(err, SaveableError::UnregisteredType { .. }));
+    ///
+    /// snapshot
+    ///     .applier(&mut other.world)
+    ///     .skip_unregistered_resources()
+    ///     .apply()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(other.world.resource::<Score>().0, 10);
+    /// assert!(!other.world.contains_resource::<Debug>());
+    ///
+    /// let transform = other.world.query::<&Transform>().single(&other.world);
+    /// assert_eq!(transform.translation, Vec3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn skip_unregistered_resources(mut self) -> Self {
+        self.skip_unregistered = true;
+        self
+    }
+
+    /// Skip applying the saved resources with the given type names, keeping their live values.
+    ///
+    /// This is useful for resources that were captured but should not be forced onto the current session,
+    /// such as window or input settings.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// #[derive(Resource, Reflect, Default)]
+    /// #[reflect(Resource)]
+    /// struct Score(u32);
+    ///
+    /// #[derive(Resource, Reflect, Default)]
+    /// #[reflect(Resource)]
+    /// struct WindowSettings {
+    ///     width: u32,
+    /// }
+    ///
+    /// app.register_saveable::<Score>()
+    ///     .register_saveable::<WindowSettings>()
+    ///     .insert_resource(Score(10))
+    ///     .insert_resource(WindowSettings { width: 800 });
+    ///
+    /// let snapshot = Snapshot::from_world(&app.world);
+    ///
+    /// app.insert_resource(Score(0))
+    ///     .insert_resource(WindowSettings { width: 1920 });
+    ///
+    /// snapshot
+    ///     .applier(&mut app.world)
+    ///     .skip_resources(&[std::any::type_name::<WindowSettings>()])
+    ///     .apply()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(app.world.resource::<Score>().0, 10);
+    /// assert_eq!(app.world.resource::<WindowSettings>().width, 1920);
+    /// ```
+    pub fn skip_resources(mut self, type_names: &[&str]) -> Self {
+        self.skipped_resources.extend(type_names.iter().map(|name| (*name).to_owned()));
+        self
+    }
+
+    /// Apply only the saved components and resources with the given type names, leaving everything else untouched.
+    ///
+    /// Names may be full type names or short names, such as `"Transform"`.
+    /// No entities are despawned and [`Applier::exact_overwrite`] is ignored, so this re-applies part of a full
+    /// snapshot onto existing entities, such as after a hot reload. Saved entities without a matching entity
+    /// are still spawned, with only the listed components.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let world = &mut app.world;
+    /// let entity = world.spawn((Name::new("Player"), Transform::from_xyz(1.0, 2.0, 3.0))).id();
+    /// let snapshot = Snapshot::from_world(world);
+    ///
+    /// world.entity_mut(entity).insert((Name::new("Renamed"), Transform::default()));
+    /// let extra = world.spawn(Transform::default()).id();
+    ///
+    /// snapshot.applier(world).only_types(&["Transform"]).apply().unwrap();
+    ///
+    /// assert_eq!(world.get::<Transform>(entity).unwrap().translation, Vec3::new(1.0, 2.0, 3.0));
+    ///
+    /// // Other components are kept, and entities missing from the snapshot are not despawned
+    /// assert_eq!(world.get::<Name>(entity).unwrap().as_str(), "Renamed");
+    /// assert!(world.get_entity(extra).is_some());
+    /// ```
+    pub fn only_types(mut self, type_names: &[&str]) -> Self {
+        self.only_types
+            .get_or_insert_with(HashSet::new)
+            .extend(type_names.iter().map(|name| (*name).to_owned()));
+        self
+    }
+
+    /// Keep the live values of the components with the given type names on `entity`, taking everything else
+    /// from the snapshot.
+    ///
+    /// Names may be full type names or short names, such as `"Transform"`. Preserved components are neither
+    /// overwritten nor removed by [`Applier::exact_overwrite`], and can be set for several entities, e.g. to keep
+    /// the player where they are during a partial reload. Unlike [`LocalOnly`](crate::LocalOnly), which applies
+    /// to every entity, this only affects the given live entity.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let world = &mut app.world;
+    /// let player = world.spawn((Name::new("Player"), Transform::from_xyz(1.0, 0.0, 0.0))).id();
+    /// let enemy = world.spawn((Name::new("Enemy"), Transform::from_xyz(5.0, 0.0, 0.0))).id();
+    ///
+    /// let snapshot = Snapshot::from_world(world);
+    ///
+    /// world.entity_mut(player).insert((Name::new("Renamed"), Transform::from_xyz(2.0, 0.0, 0.0)));
+    /// world.entity_mut(enemy).insert(Transform::from_xyz(6.0, 0.0, 0.0));
+    ///
+    /// snapshot
+    ///     .applier(world)
+    ///     .preserve(player, &["Transform"])
+    ///     .apply()
+    ///     .unwrap();
+    ///
+    /// // The player keeps its live transform, but every other component comes from the snapshot
+    /// assert_eq!(world.get::<Transform>(player).unwrap().translation.x, 2.0);
+    /// assert_eq!(world.get::<Name>(player).unwrap().as_str(), "Player");
+    /// assert_eq!(world.get::<Transform>(enemy).unwrap().translation.x, 5.0);
+    /// ```
+    pub fn preserve(mut self, entity: Entity, type_names: &[&str]) -> Self {
+        self.preserved
+            .entry(entity)
+            .or_default()
+            .extend(type_names.iter().map(|name| (*name).to_owned()));
+        self
+    }
+
+    /// Attach the snapshot's root entities, those saved without a [`Parent`], as children of the given entity.
+    ///
+    /// This runs after entity references are re-linked and before the [`Hook`], so pasted subtrees keep their
+    /// own hierarchy while being nested under the chosen parent.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// app.register_saveable::<Parent>()
+    ///     .register_saveable::<Children>();
+    ///
+    /// let world = &mut app.world;
+    ///
+    /// // Two roots, one with a child
+    /// world.spawn(Transform::default()).with_children(|parent| {
+    ///     parent.spawn(Transform::default());
+    /// });
+    /// world.spawn(Transform::default());
+    ///
+    /// let snapshot = Snapshot::from_world(world);
+    /// let container = world.spawn_empty().id();
+    ///
+    /// // Paste a copy of the snapshot under the container
+    /// snapshot
+    ///     .applier(world)
+    ///     .despawn(DespawnMode::None)
+    ///     .mapping(MappingMode::Strict)
+    ///     .reparent_roots_to(container)
+    ///     .apply()
+    ///     .unwrap();
+    ///
+    /// let children = world.get::<Children>(container).unwrap();
+    /// assert_eq!(children.len(), 2);
+    ///
+    /// // The saved hierarchy below the roots is kept
+    /// let nested = children.iter().filter(|&&root| world.get::<Children>(root).is_some());
+    /// assert_eq!(nested.count(), 1);
+    /// ```
+    pub fn reparent_roots_to(mut self, parent: Entity) -> Self {
+        self.reparent = Some(parent);
+        self
+    }
 }
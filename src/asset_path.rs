@@ -0,0 +1,112 @@
+use std::marker::PhantomData;
+
+use bevy::{
+    asset::{
+        Asset,
+        AssetPath,
+        HandleId,
+    },
+    prelude::*,
+};
+
+/// The asset path of an entity's [`Handle<T>`], registered by
+/// [`AppSaveableExt::register_asset_paths`](crate::AppSaveableExt::register_asset_paths).
+///
+/// A saved [`Handle<T>`] only stores the asset id, which restores as a weak handle that does not load anything.
+/// This component records the path the handle was loaded from, kept up to date by [`sync_asset_paths`],
+/// and when a snapshot restores it, [`restore_asset_paths`] requests the asset from the [`AssetServer`] again
+/// and replaces the handle with the fresh one.
+///
+/// The asset bytes are not saved, so the asset must still exist at the same path when loading.
+#[derive(Component, Reflect, Debug, Clone, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct SavedAssetPath<T: Asset> {
+    /// The path the asset was loaded from.
+    pub path: String,
+
+    #[reflect(ignore)]
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Asset> SavedAssetPath<T> {
+    /// Create a new [`SavedAssetPath`] with the given path.
+    pub fn new<S: Into<String>>(path: S) -> Self {
+        Self {
+            path: path.into(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Asset> Default for SavedAssetPath<T> {
+    fn default() -> Self {
+        Self::new(String::new())
+    }
+}
+
+/// Records the asset path of each changed [`Handle<T>`] in its [`SavedAssetPath<T>`].
+///
+/// Added to [`PostUpdate`] before the [`CheckpointSet`](crate::CheckpointSet).
+/// Handles that were not loaded from a path have their [`SavedAssetPath<T>`] removed.
+#[allow(clippy::needless_pass_by_value, clippy::type_complexity)]
+pub fn sync_asset_paths<T: Asset>(
+    mut commands: Commands,
+    server: Res<AssetServer>,
+    handles: Query<(Entity, &Handle<T>, Option<&SavedAssetPath<T>>), Changed<Handle<T>>>,
+    mut removed: RemovedComponents<Handle<T>>,
+) {
+    for (entity, handle, saved) in &handles {
+        match server.get_handle_path(handle) {
+            Some(path) => {
+                let path = path_string(&path);
+
+                if saved.map(|saved| &saved.path) != Some(&path) {
+                    commands.entity(entity).insert(SavedAssetPath::<T>::new(path));
+                }
+            }
+            None if saved.is_some() => {
+                commands.entity(entity).remove::<SavedAssetPath<T>>();
+            }
+            None => {}
+        }
+    }
+
+    for entity in &mut removed {
+        if let Some(mut entity) = commands.get_entity(entity) {
+            entity.remove::<SavedAssetPath<T>>();
+        }
+    }
+}
+
+/// Requests the asset of each changed [`SavedAssetPath<T>`] from the [`AssetServer`],
+/// replacing the entity's [`Handle<T>`] if it does not already point at that path.
+///
+/// Added to [`PreUpdate`], so restored handles are in place before the update.
+#[allow(clippy::needless_pass_by_value, clippy::type_complexity)]
+pub fn restore_asset_paths<T: Asset>(
+    mut commands: Commands,
+    server: Res<AssetServer>,
+    saved: Query<(Entity, &SavedAssetPath<T>, Option<&Handle<T>>), Changed<SavedAssetPath<T>>>,
+) {
+    for (entity, saved, handle) in &saved {
+        let id = HandleId::from(AssetPath::from(saved.path.as_str()));
+
+        if handle.is_some_and(|handle| handle.is_strong() && handle.id() == id) {
+            continue;
+        }
+
+        commands.entity(entity).insert(server.load::<T, _>(saved.path.as_str()));
+    }
+}
+
+/// Formats an [`AssetPath`] the way [`AssetPath::from`] parses it, with the label after a `#`.
+fn path_string(path: &AssetPath) -> String {
+    let mut string = path.path().to_string_lossy().into_owned();
+
+    if let Some(label) = path.label() {
+        string.push('#');
+        string.push_str(label);
+    }
+
+    string
+}
@@ -0,0 +1,193 @@
+use std::io::Write;
+
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use bevy::prelude::*;
+
+use crate::{
+    AppBackend,
+    SaveHeader,
+    SaveableError,
+    WorldSaveableExt,
+};
+
+/// A slot written by an [`AutosaveRotation`], as recorded in its [`AutosaveManifest`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AutosaveSlot {
+    /// The save name of the slot.
+    pub name: String,
+
+    /// The number of autosaves written before this one, increasing with every autosave.
+    pub sequence: u64,
+
+    /// The time the slot was written, in seconds since the Unix epoch, or `0` if the time is unavailable.
+    pub saved_at: u64,
+}
+
+/// The slots written by an [`AutosaveRotation`], oldest first.
+///
+/// Stored through the [`AppBackend`] next to the slots, so the rotation continues across sessions.
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+pub struct AutosaveManifest {
+    /// The written slots, ordered by [`AutosaveSlot::sequence`].
+    pub slots: Vec<AutosaveSlot>,
+}
+
+impl AutosaveManifest {
+    /// Returns the most recently written slot.
+    pub fn latest(&self) -> Option<&AutosaveSlot> {
+        self.slots.last()
+    }
+}
+
+/// Rotating autosaves, cycling through a fixed number of named slots so the latest few autosaves are kept.
+///
+/// Slots are named `{prefix}_0` to `{prefix}_{n - 1}`, and the manifest is saved as `{prefix}_manifest`.
+/// Each autosave writes to an unused slot, or overwrites the oldest one.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// app.insert_resource(AppBackend::new(MemoryBackend::new()));
+///
+/// let rotation = AutosaveRotation::new("autosave", 5);
+///
+/// for i in 0..6 {
+///     let header = SaveHeader::new().with("autosave", i.to_string());
+///     rotation.autosave_with_header(&app.world, &header).unwrap();
+/// }
+///
+/// let world = &app.world;
+///
+/// assert!((0..5).all(|i| world.save_exists(&format!("autosave_{i}"))));
+/// assert!(!world.save_exists("autosave_5"));
+///
+/// // The sixth autosave overwrote the oldest slot
+/// let latest = world.load_header("autosave_0").unwrap();
+/// assert_eq!(latest.get("autosave"), Some("5"));
+///
+/// let manifest = rotation.manifest(world).unwrap();
+/// assert_eq!(manifest.slots.len(), 5);
+/// assert_eq!(manifest.latest().unwrap().name, "autosave_0");
+/// assert_eq!(manifest.slots[0].name, "autosave_1");
+/// ```
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct AutosaveRotation {
+    prefix: String,
+    slots: usize,
+}
+
+impl AutosaveRotation {
+    /// Create a new [`AutosaveRotation`] cycling through the given number of slots.
+    ///
+    /// A slot count of `0` is treated as `1`.
+    pub fn new<S: Into<String>>(prefix: S, slots: usize) -> Self {
+        Self {
+            prefix: prefix.into(),
+            slots: slots.max(1),
+        }
+    }
+
+    /// Returns the save name of the slot with the given index.
+    pub fn slot_name(&self, index: usize) -> String {
+        format!("{}_{index}", self.prefix)
+    }
+
+    /// Returns the save name of the manifest.
+    pub fn manifest_name(&self) -> String {
+        format!("{}_manifest", self.prefix)
+    }
+
+    /// Loads the [`AutosaveManifest`] from the [`AppBackend`], or an empty manifest if none was saved yet.
+    ///
+    /// # Errors
+    /// - See [`SaveableError`]
+    pub fn manifest(&self, world: &World) -> Result<AutosaveManifest, SaveableError> {
+        let backend = world.resource::<AppBackend>();
+        let name = self.manifest_name();
+
+        if !backend.exists(&name) {
+            return Ok(AutosaveManifest::default());
+        }
+
+        rmp_serde::from_read(backend.reader(&name)?).map_err(SaveableError::other)
+    }
+
+    /// Returns the save name of the slot the next autosave will write to.
+    ///
+    /// # Errors
+    /// - See [`SaveableError`]
+    pub fn next_slot(&self, world: &World) -> Result<String, SaveableError> {
+        self.manifest(world).map(|manifest| self.pick(&manifest))
+    }
+
+    /// Returns the first unused slot, or the oldest slot in the manifest.
+    fn pick(&self, manifest: &AutosaveManifest) -> String {
+        (0..self.slots)
+            .map(|index| self.slot_name(index))
+            .find(|name| !manifest.slots.iter().any(|slot| &slot.name == name))
+            .or_else(|| manifest.slots.first().map(|slot| slot.name.clone()))
+            .unwrap_or_else(|| self.slot_name(0))
+    }
+
+    /// Saves the game state to the next slot, returning its save name.
+    ///
+    /// # Errors
+    /// - See [`SaveableError`]
+    pub fn autosave(&self, world: &World) -> Result<String, SaveableError> {
+        self.autosave_with_header(world, &SaveHeader::default())
+    }
+
+    /// Saves the game state to the next slot with the given [`SaveHeader`], returning its save name.
+    ///
+    /// # Errors
+    /// - See [`SaveableError`]
+    pub fn autosave_with_header(
+        &self,
+        world: &World,
+        header: &SaveHeader,
+    ) -> Result<String, SaveableError> {
+        let mut manifest = self.manifest(world)?;
+        let name = self.pick(&manifest);
+
+        world.save_with_header(&name, header)?;
+
+        let sequence = manifest.latest().map_or(0, |slot| slot.sequence + 1);
+
+        manifest.slots.retain(|slot| slot.name != name);
+        manifest.slots.push(AutosaveSlot {
+            name: name.clone(),
+            sequence,
+            saved_at: unix_time(),
+        });
+
+        let bytes = rmp_serde::to_vec(&manifest).map_err(SaveableError::other)?;
+        let mut writer = world.resource::<AppBackend>().writer(&self.manifest_name())?;
+
+        writer.write_all(&bytes).map_err(SaveableError::other)?;
+        writer.flush().map_err(SaveableError::other)?;
+
+        Ok(name)
+    }
+}
+
+/// Returns the current time in seconds since the Unix epoch.
+#[cfg(not(target_arch = "wasm32"))]
+fn unix_time() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |time| time.as_secs())
+}
+
+/// The system clock is not available on the web.
+#[cfg(target_arch = "wasm32")]
+fn unix_time() -> u64 {
+    0
+}
@@ -1,19 +1,30 @@
 use std::{
+    collections::HashMap,
     fs::File,
     io::{
         BufReader,
         BufWriter,
+        Cursor,
+        ErrorKind,
         Read,
         Write,
     },
+    sync::{
+        Arc,
+        Mutex,
+    },
 };
 
 use bevy::prelude::*;
 
 use crate::{
     get_save_file,
+    peek_metadata,
+    Cipher,
+    Compression,
     OwnedReader,
     OwnedWriter,
+    SaveMetadata,
     SaveableError,
 };
 
@@ -36,6 +47,20 @@ pub trait Backend: Send + Sync + 'static {
     /// # Errors
     /// - See [`SaveableError`]
     fn writer(name: &str) -> Result<Self::Writer, SaveableError>;
+
+    /// Returns true if a save with the given name exists.
+    ///
+    /// By default this attempts to open a reader for the save.
+    fn exists(name: &str) -> bool {
+        Self::reader(name).is_ok()
+    }
+
+    /// Returns the [`SaveMetadata`] of the save with the given name, reading only its header segment.
+    ///
+    /// Returns `None` if the save does not exist or its header cannot be read.
+    fn metadata(name: &str) -> Option<SaveMetadata> {
+        peek_metadata(Self::reader(name).ok()?).ok()
+    }
 }
 
 /// Type-erased version of [`Backend`].
@@ -51,6 +76,16 @@ pub trait ErasedBackend: Send + Sync + 'static {
     /// # Errors
     /// - See [`Backend::writer`]
     fn writer(&self, name: &str) -> Result<OwnedWriter, SaveableError>;
+
+    /// Type-erased version of [`Backend::exists`]
+    fn exists(&self, name: &str) -> bool {
+        self.reader(name).is_ok()
+    }
+
+    /// Type-erased version of [`Backend::metadata`]
+    fn metadata(&self, name: &str) -> Option<SaveMetadata> {
+        peek_metadata(self.reader(name).ok()?).ok()
+    }
 }
 
 impl<T> ErasedBackend for T
@@ -64,6 +99,14 @@ where
     fn writer(&self, name: &str) -> Result<OwnedWriter, SaveableError> {
         T::writer(name).map(|w| Box::new(w).into())
     }
+
+    fn exists(&self, name: &str) -> bool {
+        T::exists(name)
+    }
+
+    fn metadata(&self, name: &str) -> Option<SaveMetadata> {
+        T::metadata(name)
+    }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -99,6 +142,10 @@ mod desktop {
 
             Ok(BufWriter::new(file))
         }
+
+        fn exists(name: &str) -> bool {
+            get_save_file(name).is_file()
+        }
     }
 }
 
@@ -159,6 +206,721 @@ mod wasm {
 #[cfg(target_arch = "wasm32")]
 pub use wasm::WebStorage;
 
+/// A [`Backend`] wrapper that compresses entire save files with any [`Compression`] codec.
+///
+/// Each flush of the writer stores the data written since the previous flush as one length-prefixed compressed chunk.
+/// Unlike [`AppCompression`](crate::AppCompression), this also compresses the [`SaveHeader`](crate::SaveHeader),
+/// so reading only the header still decompresses the whole save.
+///
+/// # Example
+/// ```
+/// # use std::{collections::HashMap, io::{Cursor, Write}, sync::{Mutex, atomic::{AtomicUsize, Ordering}}};
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// # static SAVES: Mutex<Option<HashMap<String, Vec<u8>>>> = Mutex::new(None);
+/// # struct Memory;
+/// # struct MemoryWriter(String);
+/// # impl Write for MemoryWriter {
+/// #     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+/// #         let mut saves = SAVES.lock().unwrap();
+/// #         let save = saves.get_or_insert_with(HashMap::new).entry(self.0.clone()).or_default();
+/// #         save.extend_from_slice(buf);
+/// #         Ok(buf.len())
+/// #     }
+/// #     fn flush(&mut self) -> std::io::Result<()> { Ok(()) }
+/// # }
+/// # impl Backend for Memory {
+/// #     type Reader = Cursor<Vec<u8>>;
+/// #     type Writer = MemoryWriter;
+/// #     fn reader(name: &str) -> Result<Self::Reader, SaveableError> {
+/// #         let saves = SAVES.lock().unwrap();
+/// #         let save = saves.as_ref().and_then(|s| s.get(name)).cloned().unwrap_or_default();
+/// #         Ok(Cursor::new(save))
+/// #     }
+/// #     fn writer(name: &str) -> Result<Self::Writer, SaveableError> {
+/// #         SAVES.lock().unwrap().get_or_insert_with(HashMap::new).remove(name);
+/// #         Ok(MemoryWriter(name.to_owned()))
+/// #     }
+/// # }
+/// static COMPRESSED: AtomicUsize = AtomicUsize::new(0);
+/// static DECOMPRESSED: AtomicUsize = AtomicUsize::new(0);
+///
+/// struct Identity;
+///
+/// impl Compression for Identity {
+///     fn compress(&self, data: &[u8]) -> Vec<u8> {
+///         COMPRESSED.fetch_add(1, Ordering::SeqCst);
+///         data.to_vec()
+///     }
+///
+///     fn decompress(&self, data: &[u8]) -> Result<Vec<u8>, SaveableError> {
+///         DECOMPRESSED.fetch_add(1, Ordering::SeqCst);
+///         Ok(data.to_vec())
+///     }
+/// }
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// app.insert_resource(AppBackend::compressed(Memory, Box::new(Identity)));
+///
+/// app.world.spawn(Transform::from_xyz(1.0, 2.0, 3.0));
+/// app.world.save("identity").unwrap();
+///
+/// assert!(COMPRESSED.load(Ordering::SeqCst) > 0);
+/// assert_eq!(DECOMPRESSED.load(Ordering::SeqCst), 0);
+///
+/// app.world.clear_entities();
+/// app.world.load("identity").unwrap();
+///
+/// assert!(DECOMPRESSED.load(Ordering::SeqCst) > 0);
+///
+/// let transform = app.world.query::<&Transform>().single(&app.world);
+/// assert_eq!(transform.translation, Vec3::new(1.0, 2.0, 3.0));
+/// ```
+pub struct CompressedBackend {
+    backend: Box<dyn ErasedBackend>,
+    codec: Arc<dyn Compression>,
+}
+
+impl CompressedBackend {
+    /// Create a new [`CompressedBackend`] that compresses the saves of `backend` with `codec`.
+    pub fn new<B: Backend>(backend: B, codec: Box<dyn Compression>) -> Self {
+        Self {
+            backend: Box::new(backend),
+            codec: codec.into(),
+        }
+    }
+}
+
+impl ErasedBackend for CompressedBackend {
+    fn reader(&self, name: &str) -> Result<OwnedReader, SaveableError> {
+        let mut reader = self.backend.reader(name)?;
+        let mut data = Vec::new();
+
+        loop {
+            let mut len = [0; 4];
+
+            match reader.read_exact(&mut len) {
+                Err(err) if err.kind() == ErrorKind::UnexpectedEof => break,
+                result => result.map_err(SaveableError::other)?,
+            }
+
+            let mut chunk = vec![0; u32::from_le_bytes(len) as usize];
+            reader.read_exact(&mut chunk).map_err(SaveableError::other)?;
+
+            data.extend(self.codec.decompress(&chunk)?);
+        }
+
+        Ok(Box::new(Cursor::new(data)).into())
+    }
+
+    fn writer(&self, name: &str) -> Result<OwnedWriter, SaveableError> {
+        let writer = CompressedWriter {
+            writer: self.backend.writer(name)?,
+            codec: self.codec.clone(),
+            buffer: Vec::new(),
+        };
+
+        Ok(Box::new(writer).into())
+    }
+
+    fn exists(&self, name: &str) -> bool {
+        self.backend.exists(name)
+    }
+}
+
+struct CompressedWriter {
+    writer: OwnedWriter,
+    codec: Arc<dyn Compression>,
+    buffer: Vec<u8>,
+}
+
+impl Write for CompressedWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        if !self.buffer.is_empty() {
+            let chunk = self.codec.compress(&self.buffer);
+            let len = u32::try_from(chunk.len())
+                .map_err(|err| std::io::Error::new(ErrorKind::InvalidInput, err))?;
+
+            self.writer.write_all(&len.to_le_bytes())?;
+            self.writer.write_all(&chunk)?;
+            self.buffer.clear();
+        }
+
+        self.writer.flush()
+    }
+}
+
+impl Drop for CompressedWriter {
+    fn drop(&mut self) {
+        if let Err(err) = self.flush() {
+            error!("Failed to write compressed save: {err}");
+        }
+    }
+}
+
+/// A [`Backend`] wrapper that encrypts entire save files with any [`Cipher`], so they cannot be read or edited by hand.
+///
+/// The writer buffers the whole save and encrypts it as a single message when it is first flushed or dropped,
+/// storing a random nonce followed by the encrypted data, so a truncated or spliced save cannot pass as valid.
+/// Writing to it after that flush fails.
+/// Loading with the wrong key, or a save that was tampered with, fails with [`SaveableError::DecryptionFailed`].
+///
+/// # Example
+/// ```
+/// # use std::{collections::hash_map::DefaultHasher, hash::{Hash, Hasher}};
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// // A toy cipher for illustration only, use a real AEAD cipher such as `AesGcm` from the `aes-gcm` feature
+/// struct Xor;
+///
+/// impl Xor {
+///     fn xor(key: &[u8], nonce: &[u8], data: &[u8]) -> Vec<u8> {
+///         let stream = key.iter().chain(nonce).cycle();
+///         data.iter().zip(stream).map(|(b, k)| b ^ k).collect()
+///     }
+///
+///     fn tag(key: &[u8], nonce: &[u8], data: &[u8]) -> [u8; 8] {
+///         let mut hasher = DefaultHasher::new();
+///         (key, nonce, data).hash(&mut hasher);
+///         hasher.finish().to_le_bytes()
+///     }
+/// }
+///
+/// impl Cipher for Xor {
+///     fn nonce_len(&self) -> usize {
+///         8
+///     }
+///
+///     fn encrypt(&self, key: &[u8], nonce: &[u8], data: &[u8]) -> Result<Vec<u8>, SaveableError> {
+///         let mut out = Self::xor(key, nonce, data);
+///         out.extend(Self::tag(key, nonce, data));
+///         Ok(out)
+///     }
+///
+///     fn decrypt(&self, key: &[u8], nonce: &[u8], data: &[u8]) -> Result<Vec<u8>, SaveableError> {
+///         if data.len() < 8 {
+///             return Err(SaveableError::DecryptionFailed);
+///         }
+///
+///         let (data, tag) = data.split_at(data.len() - 8);
+///
+///         let out = Self::xor(key, nonce, data);
+///
+///         if Self::tag(key, nonce, &out) == tag {
+///             Ok(out)
+///         } else {
+///             Err(SaveableError::DecryptionFailed)
+///         }
+///     }
+/// }
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// let memory = MemoryBackend::new();
+/// app.insert_resource(AppBackend::encrypted(memory.clone(), "secret", Box::new(Xor)));
+///
+/// app.world.spawn(Transform::from_xyz(1.0, 2.0, 3.0));
+/// app.world.save("encrypted").unwrap();
+///
+/// app.world.clear_entities();
+/// app.world.load("encrypted").unwrap();
+///
+/// let transform = app.world.query::<&Transform>().single(&app.world);
+/// assert_eq!(transform.translation, Vec3::new(1.0, 2.0, 3.0));
+///
+/// // Loading with the wrong key fails
+/// app.insert_resource(AppBackend::encrypted(memory, "guess", Box::new(Xor)));
+///
+/// let result = app.world.load("encrypted");
+/// assert!(matches!(result, Err(SaveableError::DecryptionFailed)));
+/// ```
+pub struct EncryptedBackend {
+    backend: Box<dyn ErasedBackend>,
+    key: Arc<[u8]>,
+    cipher: Arc<dyn Cipher>,
+}
+
+impl EncryptedBackend {
+    /// Create a new [`EncryptedBackend`] that encrypts the saves of `backend` with `cipher` and `key`.
+    pub fn new<B: ErasedBackend>(
+        backend: B,
+        key: impl AsRef<[u8]>,
+        cipher: Box<dyn Cipher>,
+    ) -> Self {
+        Self {
+            backend: Box::new(backend),
+            key: key.as_ref().into(),
+            cipher: cipher.into(),
+        }
+    }
+}
+
+impl ErasedBackend for EncryptedBackend {
+    fn reader(&self, name: &str) -> Result<OwnedReader, SaveableError> {
+        let mut data = Vec::new();
+        self.backend
+            .reader(name)?
+            .read_to_end(&mut data)
+            .map_err(SaveableError::other)?;
+
+        let nonce_len = self.cipher.nonce_len();
+
+        if data.len() < nonce_len {
+            return Err(SaveableError::DecryptionFailed);
+        }
+
+        let (nonce, data) = data.split_at(nonce_len);
+        let data = self.cipher.decrypt(&self.key, nonce, data)?;
+
+        Ok(Box::new(Cursor::new(data)).into())
+    }
+
+    fn writer(&self, name: &str) -> Result<OwnedWriter, SaveableError> {
+        let writer = EncryptedWriter {
+            writer: self.backend.writer(name)?,
+            key: self.key.clone(),
+            cipher: self.cipher.clone(),
+            buffer: Vec::new(),
+            sealed: false,
+        };
+
+        Ok(Box::new(writer).into())
+    }
+
+    fn exists(&self, name: &str) -> bool {
+        self.backend.exists(name)
+    }
+}
+
+/// Buffers the whole save and encrypts it as a single message, so that the authentication tag covers
+/// every byte and a truncated or spliced save fails to decrypt.
+struct EncryptedWriter {
+    writer: OwnedWriter,
+    key: Arc<[u8]>,
+    cipher: Arc<dyn Cipher>,
+    buffer: Vec<u8>,
+    sealed: bool,
+}
+
+impl EncryptedWriter {
+    fn seal(&mut self) -> std::io::Result<()> {
+        let mut nonce = vec![0; self.cipher.nonce_len()];
+        getrandom::getrandom(&mut nonce)?;
+
+        let encrypted = self
+            .cipher
+            .encrypt(&self.key, &nonce, &self.buffer)
+            .map_err(|err| std::io::Error::new(ErrorKind::InvalidInput, err.to_string()))?;
+
+        self.buffer = Vec::new();
+        self.sealed = true;
+
+        self.writer.write_all(&nonce)?;
+        self.writer.write_all(&encrypted)
+    }
+}
+
+impl Write for EncryptedWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if self.sealed {
+            return Err(std::io::Error::new(
+                ErrorKind::InvalidInput,
+                "an encrypted save cannot be written to after it was flushed",
+            ));
+        }
+
+        self.buffer.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        if !self.sealed && !self.buffer.is_empty() {
+            self.seal()?;
+        }
+
+        self.writer.flush()
+    }
+}
+
+impl Drop for EncryptedWriter {
+    fn drop(&mut self) {
+        if self.sealed {
+            return;
+        }
+
+        if let Err(err) = self.seal().and_then(|()| self.writer.flush()) {
+            error!("Failed to write encrypted save: {err}");
+        }
+    }
+}
+
+/// An in-memory backend, useful for tests and for keeping saves only for the current session.
+///
+/// Clones share the same saves.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// let memory = MemoryBackend::new();
+/// app.insert_resource(AppBackend::new(memory.clone()));
+///
+/// app.world.spawn(Transform::from_xyz(1.0, 2.0, 3.0));
+/// app.world.save("session").unwrap();
+///
+/// assert!(memory.contains("session"));
+///
+/// app.world.clear_entities();
+/// app.world.load("session").unwrap();
+///
+/// let transform = app.world.query::<&Transform>().single(&app.world);
+/// assert_eq!(transform.translation, Vec3::new(1.0, 2.0, 3.0));
+/// ```
+#[derive(Clone, Default)]
+pub struct MemoryBackend {
+    saves: Arc<Mutex<HashMap<String, Vec<u8>>>>,
+}
+
+impl MemoryBackend {
+    /// Create a new, empty [`MemoryBackend`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns true if a save with the given name exists.
+    pub fn contains(&self, name: &str) -> bool {
+        self.saves().contains_key(name)
+    }
+
+    /// Returns a copy of the bytes of the save with the given name, if it exists.
+    pub fn get(&self, name: &str) -> Option<Vec<u8>> {
+        self.saves().get(name).cloned()
+    }
+
+    /// Remove the save with the given name, returning its bytes if it existed.
+    pub fn remove(&self, name: &str) -> Option<Vec<u8>> {
+        self.saves().remove(name)
+    }
+
+    /// Remove every save.
+    pub fn clear(&self) {
+        self.saves().clear();
+    }
+
+    fn saves(&self) -> std::sync::MutexGuard<'_, HashMap<String, Vec<u8>>> {
+        self.saves.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+impl ErasedBackend for MemoryBackend {
+    fn reader(&self, name: &str) -> Result<OwnedReader, SaveableError> {
+        let save = self.get(name).ok_or_else(|| {
+            SaveableError::other(std::io::Error::new(
+                ErrorKind::NotFound,
+                format!("no save named `{name}`"),
+            ))
+        })?;
+
+        Ok(Box::new(Cursor::new(save)).into())
+    }
+
+    fn writer(&self, name: &str) -> Result<OwnedWriter, SaveableError> {
+        self.saves().insert(name.to_owned(), Vec::new());
+
+        let writer = MemoryWriter {
+            backend: self.clone(),
+            name: name.to_owned(),
+        };
+
+        Ok(Box::new(writer).into())
+    }
+
+    fn exists(&self, name: &str) -> bool {
+        self.contains(name)
+    }
+}
+
+struct MemoryWriter {
+    backend: MemoryBackend,
+    name: String,
+}
+
+impl Write for MemoryWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.backend
+            .saves()
+            .entry(self.name.clone())
+            .or_default()
+            .extend_from_slice(buf);
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// A backend that writes every save to several backends at once, for resilience.
+///
+/// Saving fails only if every backend fails, or if any backend fails when using [`MirrorBackend::require_all`].
+/// Loading reads from the first backend that can open the save, in the order the backends were added.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// let local = MemoryBackend::new();
+/// let remote = MemoryBackend::new();
+///
+/// app.insert_resource(AppBackend::new(
+///     MirrorBackend::new()
+///         .with(local.clone())
+///         .with(remote.clone()),
+/// ));
+///
+/// app.world.spawn(Transform::from_xyz(1.0, 2.0, 3.0));
+/// app.world.save("mirrored").unwrap();
+///
+/// assert!(local.contains("mirrored"));
+/// assert_eq!(local.get("mirrored"), remote.get("mirrored"));
+///
+/// // Loading still succeeds with the first backend missing the save
+/// local.clear();
+///
+/// app.world.clear_entities();
+/// app.world.load("mirrored").unwrap();
+///
+/// let transform = app.world.query::<&Transform>().single(&app.world);
+/// assert_eq!(transform.translation, Vec3::new(1.0, 2.0, 3.0));
+/// ```
+#[derive(Default)]
+pub struct MirrorBackend {
+    backends: Vec<Box<dyn ErasedBackend>>,
+    require_all: bool,
+}
+
+impl MirrorBackend {
+    /// Create a new [`MirrorBackend`] without any backends.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a backend to mirror saves to.
+    pub fn with<B: ErasedBackend>(mut self, backend: B) -> Self {
+        self.backends.push(Box::new(backend));
+        self
+    }
+
+    /// Fail saving if any backend fails, instead of only if every backend fails.
+    ///
+    /// Opening a writer truncates the previous save, so the previous saves are read first. If a backend
+    /// fails to open its writer, the backends after it are left untouched and the previous saves of the
+    /// backends before it are written back. A backend that had no previous save is left with an empty one.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// struct Offline;
+    ///
+    /// impl ErasedBackend for Offline {
+    ///     fn reader(&self, _name: &str) -> Result<OwnedReader, SaveableError> {
+    ///         Err(SaveableError::other(std::io::Error::new(std::io::ErrorKind::Other, "offline")))
+    ///     }
+    ///
+    ///     fn writer(&self, _name: &str) -> Result<OwnedWriter, SaveableError> {
+    ///         Err(SaveableError::other(std::io::Error::new(std::io::ErrorKind::Other, "offline")))
+    ///     }
+    /// }
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// let local = MemoryBackend::new();
+    /// app.insert_resource(AppBackend::new(local.clone()));
+    ///
+    /// app.world.spawn(Transform::from_xyz(1.0, 2.0, 3.0));
+    /// app.world.save("mirrored").unwrap();
+    ///
+    /// let previous = local.get("mirrored").unwrap();
+    ///
+    /// app.insert_resource(AppBackend::new(
+    ///     MirrorBackend::new()
+    ///         .with(local.clone())
+    ///         .with(Offline)
+    ///         .require_all(),
+    /// ));
+    ///
+    /// app.world.spawn(Transform::from_xyz(4.0, 5.0, 6.0));
+    /// assert!(app.world.save("mirrored").is_err());
+    ///
+    /// // The local copy still holds the previous save
+    /// assert_eq!(local.get("mirrored"), Some(previous));
+    /// ```
+    pub fn require_all(mut self) -> Self {
+        self.require_all = true;
+        self
+    }
+}
+
+impl ErasedBackend for MirrorBackend {
+    fn reader(&self, name: &str) -> Result<OwnedReader, SaveableError> {
+        let mut last = None;
+
+        for backend in &self.backends {
+            match backend.reader(name) {
+                Ok(reader) => return Ok(reader),
+                Err(err) => last = Some(err),
+            }
+        }
+
+        Err(last.unwrap_or_else(no_backends))
+    }
+
+    fn writer(&self, name: &str) -> Result<OwnedWriter, SaveableError> {
+        // Opening a writer truncates the save, so keep the previous saves in case a writer fails
+        let previous = if self.require_all {
+            self.backends
+                .iter()
+                .map(|backend| read_previous(&**backend, name))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let mut writers = Vec::new();
+        let mut last = None;
+
+        for backend in &self.backends {
+            match backend.writer(name) {
+                Ok(writer) => writers.push(writer),
+                Err(err) => {
+                    last = Some(err);
+
+                    if self.require_all {
+                        break;
+                    }
+                }
+            }
+        }
+
+        if let Some(err) = last {
+            if self.require_all {
+                restore_previous(name, writers, &previous);
+                return Err(err);
+            }
+
+            if writers.is_empty() {
+                return Err(err);
+            }
+
+            warn!("Failed to open a mirrored save writer for `{name}`: {err}");
+        }
+
+        if writers.is_empty() {
+            return Err(no_backends());
+        }
+
+        let writer = MirrorWriter {
+            writers,
+            require_all: self.require_all,
+        };
+
+        Ok(Box::new(writer).into())
+    }
+
+    fn exists(&self, name: &str) -> bool {
+        self.backends.iter().any(|backend| backend.exists(name))
+    }
+}
+
+/// Returns the bytes of the save with the given name, if it exists and can be read.
+fn read_previous(backend: &dyn ErasedBackend, name: &str) -> Option<Vec<u8>> {
+    if !backend.exists(name) {
+        return None;
+    }
+
+    let mut bytes = Vec::new();
+    backend.reader(name).ok()?.read_to_end(&mut bytes).ok()?;
+
+    Some(bytes)
+}
+
+/// Write the previous saves back through the writers that were opened, in backend order.
+fn restore_previous(name: &str, writers: Vec<OwnedWriter>, previous: &[Option<Vec<u8>>]) {
+    for (mut writer, previous) in writers.into_iter().zip(previous) {
+        let Some(bytes) = previous else {
+            continue;
+        };
+
+        if let Err(err) = writer.write_all(bytes).and_then(|()| writer.flush()) {
+            error!("Failed to restore the mirrored save `{name}`: {err}");
+        }
+    }
+}
+
+fn no_backends() -> SaveableError {
+    SaveableError::other(std::io::Error::new(
+        ErrorKind::NotFound,
+        "mirror backend has no backends",
+    ))
+}
+
+struct MirrorWriter {
+    writers: Vec<OwnedWriter>,
+    require_all: bool,
+}
+
+impl MirrorWriter {
+    /// Run `f` on every writer, dropping writers that fail.
+    fn each(
+        &mut self,
+        mut f: impl FnMut(&mut OwnedWriter) -> std::io::Result<()>,
+    ) -> std::io::Result<()> {
+        let mut last = None;
+
+        self.writers.retain_mut(|writer| match f(writer) {
+            Ok(()) => true,
+            Err(err) => {
+                warn!("A mirrored save writer failed: {err}");
+                last = Some(err);
+                false
+            }
+        });
+
+        match last {
+            Some(err) if self.require_all || self.writers.is_empty() => Err(err),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Write for MirrorWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.each(|writer| writer.write_all(buf))?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.each(|writer| writer.flush())
+    }
+}
+
 /// The App's [`Backend`].
 ///
 /// `bevy_save` will use this as the interface for saving and loading snapshots.
@@ -167,12 +929,32 @@ pub struct AppBackend(Box<dyn ErasedBackend>);
 
 impl AppBackend {
     /// Create a new [`AppBackend`] from the given [`Backend`].
-    pub fn new<B: Backend>(backend: B) -> Self {
+    ///
+    /// Any [`ErasedBackend`] can be used, such as [`MemoryBackend`] or [`MirrorBackend`].
+    pub fn new<B: ErasedBackend>(backend: B) -> Self {
         Self(Box::new(backend))
     }
 
+    /// Create a new [`AppBackend`] that compresses the saves of the given [`Backend`] with `codec`.
+    ///
+    /// See [`CompressedBackend`].
+    pub fn compressed<B: Backend>(backend: B, codec: Box<dyn Compression>) -> Self {
+        Self(Box::new(CompressedBackend::new(backend, codec)))
+    }
+
+    /// Create a new [`AppBackend`] that encrypts the saves of the given [`Backend`] with `cipher` and `key`.
+    ///
+    /// See [`EncryptedBackend`].
+    pub fn encrypted<B: ErasedBackend>(
+        backend: B,
+        key: impl AsRef<[u8]>,
+        cipher: Box<dyn Cipher>,
+    ) -> Self {
+        Self(Box::new(EncryptedBackend::new(backend, key, cipher)))
+    }
+
     /// Override the current [`Backend`].
-    pub fn set<B: Backend>(&mut self, backend: B) {
+    pub fn set<B: ErasedBackend>(&mut self, backend: B) {
         self.0 = Box::new(backend);
     }
 
@@ -191,6 +973,16 @@ impl AppBackend {
     pub fn writer(&self, name: &str) -> Result<OwnedWriter, SaveableError> {
         self.0.writer(name)
     }
+
+    /// Returns true if a save with the given name exists.
+    pub fn exists(&self, name: &str) -> bool {
+        self.0.exists(name)
+    }
+
+    /// Returns the [`SaveMetadata`] of the save with the given name, reading only its header segment.
+    pub fn metadata(&self, name: &str) -> Option<SaveMetadata> {
+        self.0.metadata(name)
+    }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
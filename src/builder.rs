@@ -1,11 +1,26 @@
-use std::collections::BTreeMap;
+use std::collections::{
+    BTreeMap,
+    BTreeSet,
+    HashMap,
+};
 
 use bevy::{
+    ecs::reflect::ReflectMapEntities,
     prelude::*,
-    reflect::TypeRegistration,
+    reflect::{
+        ReflectMut,
+        TypeRegistration,
+    },
 };
 
-use crate::entity::SaveableEntity;
+use crate::{
+    entity::SaveableEntity,
+    registry::contains_entity,
+    EntityFields,
+    SaveId,
+    SaveableError,
+    SpawnTick,
+};
 
 /// A snapshot builder that may extract entities and resources from a [`World`].
 pub struct Builder<'w, S = (), F = fn(&&TypeRegistration) -> bool> {
@@ -14,6 +29,10 @@ pub struct Builder<'w, S = (), F = fn(&&TypeRegistration) -> bool> {
     pub(crate) entities: BTreeMap<Entity, SaveableEntity>,
     pub(crate) resources: BTreeMap<String, Box<dyn Reflect>>,
     pub(crate) snapshot: Option<S>,
+    pub(crate) parallel: bool,
+    pub(crate) canonical_floats: bool,
+    pub(crate) spawn_order: bool,
+    pub(crate) recycled: HashMap<u32, SaveableEntity>,
 }
 
 impl<'w> Builder<'w> {
@@ -49,6 +68,10 @@ impl<'w> Builder<'w> {
             entities: BTreeMap::default(),
             resources: BTreeMap::default(),
             snapshot: None,
+            parallel: false,
+            canonical_floats: false,
+            spawn_order: false,
+            recycled: HashMap::new(),
         }
     }
 }
@@ -67,10 +90,370 @@ impl<'w, S> Builder<'w, S> {
             entities: self.entities,
             resources: self.resources,
             snapshot: self.snapshot,
+            parallel: self.parallel,
+            canonical_floats: self.canonical_floats,
+            spawn_order: self.spawn_order,
+            recycled: self.recycled,
         }
     }
 }
 
+impl<S, F> Builder<'_, S, F>
+where
+    Self: Build,
+{
+    /// Set whether entities are extracted in parallel using the [`ComputeTaskPool`](bevy::tasks::ComputeTaskPool).
+    ///
+    /// The built snapshot is identical to one extracted sequentially.
+    /// This is only worthwhile for worlds with many entities.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let world = &mut app.world;
+    /// for i in 0..5000 {
+    ///     world.spawn(Transform::from_xyz(i as f32, 0.0, 0.0));
+    /// }
+    ///
+    /// let sequential = Snapshot::builder(world).extract_all().build();
+    ///
+    /// let parallel = Snapshot::builder(world)
+    ///     .parallel(true)
+    ///     .extract_all()
+    ///     .build();
+    ///
+    /// assert!(sequential.diff(&parallel).is_empty());
+    /// ```
+    pub fn parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        self
+    }
+
+    /// Set whether float fields are canonicalized when the snapshot is built.
+    ///
+    /// Negative zero is replaced with positive zero and every NaN with the same canonical NaN,
+    /// so states that compare equal also serialize to identical bytes.
+    /// This changes the captured values, so it is disabled by default.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # use serde::Serialize;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// #[derive(Component, Reflect, Default)]
+    /// #[reflect(Component)]
+    /// struct Sample {
+    ///     offset: f32,
+    ///     weight: f64,
+    /// }
+    ///
+    /// app.register_saveable::<Sample>();
+    ///
+    /// let save = |world: &World| {
+    ///     let snapshot = Snapshot::builder(world)
+    ///         .canonical_floats(true)
+    ///         .extract_all_entities()
+    ///         .build();
+    ///
+    ///     let registry = world.resource::<AppTypeRegistry>();
+    ///     let mut bytes = Vec::new();
+    ///
+    ///     SnapshotSerializer::new(&snapshot, registry)
+    ///         .serialize(&mut rmp_serde::Serializer::new(&mut bytes))
+    ///         .unwrap();
+    ///
+    ///     bytes
+    /// };
+    ///
+    /// let entity = app.world.spawn(Sample { offset: -0.0, weight: f64::NAN }).id();
+    /// let first = save(&app.world);
+    ///
+    /// // Equal values with a different sign and NaN payload
+    /// let mut sample = app.world.get_mut::<Sample>(entity).unwrap();
+    /// sample.offset = 0.0;
+    /// sample.weight = -f64::from_bits(f64::NAN.to_bits() | 1);
+    ///
+    /// assert_eq!(save(&app.world), first);
+    /// ```
+    pub fn canonical_floats(mut self, canonical: bool) -> Self {
+        self.canonical_floats = canonical;
+        self
+    }
+
+    /// Set whether the spawn order of each entity is recorded, so applying the snapshot spawns them in that order.
+    ///
+    /// Entities are ranked by their [`SpawnTick`](crate::SpawnTick), then by index, with untagged entities last.
+    /// Without [`SpawnTick`](crate::SpawnTick)s, this is the index order, which differs from the spawn order
+    /// once indices are reused. The entities are stored in spawn order and each saves its rank, and applying
+    /// spawns new entities strictly by rank, so order-dependent systems see them in the same sequence.
+    ///
+    /// # Example
+    /// ```
+    /// # use serde::de::DeserializeSeed;
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let mut other = App::new();
+    /// # other.add_plugins(MinimalPlugins);
+    /// # other.add_plugins(SavePlugins);
+    /// let world = &mut app.world;
+    ///
+    /// let first = world.spawn((Name::new("First"), SpawnTick(1))).id();
+    /// world.spawn((Name::new("Second"), SpawnTick(2)));
+    /// world.spawn((Name::new("Third"), SpawnTick(3)));
+    ///
+    /// // The last entity reuses the index of the first
+    /// world.despawn(first);
+    /// world.spawn((Name::new("Fourth"), SpawnTick(4)));
+    ///
+    /// let snapshot = Snapshot::builder(world)
+    ///     .spawn_order(true)
+    ///     .extract_all_entities()
+    ///     .build();
+    ///
+    /// let registry = world.resource::<AppTypeRegistry>();
+    /// let mut json = serde_json::to_value(SnapshotSerializer::new(&snapshot, registry)).unwrap();
+    ///
+    /// // Store the entities by index instead of in spawn order, as a hand-edited save might
+    /// let entities = json["snapshot"]["entities"].as_object_mut().unwrap();
+    /// let mut sorted = std::mem::take(entities).into_iter().collect::<Vec<_>>();
+    /// sorted.sort_by(|(a, _), (b, _)| a.cmp(b));
+    /// entities.extend(sorted);
+    ///
+    /// let stored = entities.values().map(|e| e["order"].as_u64().unwrap()).collect::<Vec<_>>();
+    /// assert_eq!(stored, [2, 0, 1]);
+    ///
+    /// let registry = registry.read();
+    /// let loaded = SnapshotDeserializer::new(&registry).deserialize(json).unwrap();
+    ///
+    /// loaded.apply(&mut other.world).unwrap();
+    ///
+    /// // The fresh world hands out indices in spawn order
+    /// let mut spawned = other
+    ///     .world
+    ///     .query::<(Entity, &Name)>()
+    ///     .iter(&other.world)
+    ///     .map(|(entity, name)| (entity.index(), name.to_string()))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// spawned.sort();
+    ///
+    /// assert_eq!(spawned, [(0, "Second".into()), (1, "Third".into()), (2, "Fourth".into())]);
+    /// ```
+    pub fn spawn_order(mut self, spawn_order: bool) -> Self {
+        self.spawn_order = spawn_order;
+        self
+    }
+
+    /// Build the snapshot like [`Build::build`], first checking that every [`Entity`] held by an extracted component
+    /// will be mapped when the snapshot is applied.
+    ///
+    /// Components containing an [`Entity`] anywhere in their fields must reflect
+    /// [`MapEntities`](bevy::ecs::entity::MapEntities) or be registered with
+    /// [`AppSaveableExt::register_entity_fields`](crate::AppSaveableExt::register_entity_fields).
+    /// Otherwise their references would silently point at the wrong entities after loading.
+    ///
+    /// # Errors
+    /// - [`SaveableError::MissingMapEntities`] for the first extracted component that holds unmapped entities
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Component, Reflect)]
+    /// #[reflect(Component)]
+    /// struct Target(Entity);
+    ///
+    /// impl FromWorld for Target {
+    ///     fn from_world(_: &mut World) -> Self {
+    ///         Self(Entity::PLACEHOLDER)
+    ///     }
+    /// }
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// app.register_saveable::<Target>();
+    ///
+    /// let world = &mut app.world;
+    /// let enemy = world.spawn(Transform::default()).id();
+    ///
+    /// assert!(Snapshot::builder(world).extract_all().build_strict().is_ok());
+    ///
+    /// world.spawn(Target(enemy));
+    ///
+    /// assert!(matches!(
+    ///     Snapshot::builder(world).extract_all().build_strict(),
+    ///     Err(SaveableError::MissingMapEntities { type_name }) if type_name.ends_with("Target")
+    /// ));
+    /// ```
+    pub fn build_strict(self) -> Result<<Self as Build>::Output, SaveableError> {
+        let registry = self.world.resource::<AppTypeRegistry>().read();
+
+        let type_names = self
+            .entities
+            .values()
+            .flat_map(|entity| entity.components.iter())
+            .map(|component| component.type_name())
+            .collect::<BTreeSet<_>>();
+
+        for type_name in type_names {
+            let Some(reg) = registry.get_with_name(type_name) else {
+                continue;
+            };
+
+            let mapped =
+                reg.data::<ReflectMapEntities>().is_some() || reg.data::<EntityFields>().is_some();
+
+            if !mapped && contains_entity(&registry, reg.type_id()) {
+                return Err(SaveableError::MissingMapEntities {
+                    type_name: type_name.to_string(),
+                });
+            }
+        }
+
+        drop(registry);
+
+        Ok(self.build())
+    }
+
+    /// Record every live entity in the builder's [`World`], including entities without any saveable components.
+    ///
+    /// Entities that were not already extracted are recorded without components.
+    /// Together with [`Applier::preserve_layout`](crate::Applier::preserve_layout), this lets a snapshot recreate
+    /// the exact set of live entity indices, which deterministic lockstep simulations may depend on.
+    ///
+    /// Calling [`Build::clear_empty`] afterwards removes the recorded empty entities again.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let mut other = App::new();
+    /// # other.add_plugins(MinimalPlugins);
+    /// # other.add_plugins(SavePlugins);
+    /// # let world = &mut app.world;
+    /// #[derive(Component)]
+    /// struct Unsaved;
+    ///
+    /// let entities = (0..10)
+    ///     .map(|i| match i % 3 {
+    ///         0 => world.spawn(Transform::from_xyz(i as f32, 0.0, 0.0)).id(),
+    ///         1 => world.spawn(Unsaved).id(),
+    ///         _ => world.spawn_empty().id(),
+    ///     })
+    ///     .collect::<Vec<_>>();
+    ///
+    /// // Leave gaps in the index space
+    /// world.despawn(entities[2]);
+    /// world.despawn(entities[7]);
+    ///
+    /// let snapshot = Snapshot::builder(world)
+    ///     .extract_entity(entities[0])
+    ///     .extract_layout()
+    ///     .build();
+    ///
+    /// let indices = |world: &World| {
+    ///     let mut indices = world.iter_entities().map(|e| e.id().index()).collect::<Vec<_>>();
+    ///     indices.sort_unstable();
+    ///     indices
+    /// };
+    ///
+    /// snapshot
+    ///     .applier(&mut other.world)
+    ///     .preserve_layout()
+    ///     .apply()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(indices(&other.world), indices(world));
+    /// ```
+    pub fn extract_layout(mut self) -> Self {
+        for entity in self.world.iter_entities() {
+            self.entities
+                .entry(entity.id())
+                .or_insert_with(|| SaveableEntity {
+                    entity: entity.id().index(),
+                    components: Vec::new(),
+                    defaults: Vec::new(),
+                    order: None,
+                });
+        }
+
+        self
+    }
+
+    /// Extract a single entity and all of its descendants from the builder's [`World`].
+    ///
+    /// Descendants are found by walking [`Children`], so only the declared hierarchy is extracted.
+    pub fn extract_entity_recursive(self, entity: Entity) -> Self {
+        self.extract_entities_recursive([entity].into_iter())
+    }
+
+    /// Extract entities and all of their descendants from the builder's [`World`].
+    ///
+    /// Descendants are found by walking [`Children`], so only the declared hierarchy is extracted.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let mut other = App::new();
+    /// # other.add_plugins(MinimalPlugins);
+    /// # other.add_plugins(SavePlugins);
+    /// # let world = &mut app.world;
+    /// let root = world
+    ///     .spawn(Transform::default())
+    ///     .with_children(|parent| {
+    ///         parent.spawn(Transform::default()).with_children(|parent| {
+    ///             parent.spawn(Transform::default());
+    ///         });
+    ///         parent.spawn(Transform::default());
+    ///     })
+    ///     .id();
+    ///
+    /// // Not part of the hierarchy
+    /// world.spawn(Transform::default());
+    ///
+    /// let snapshot = Snapshot::builder(world)
+    ///     .extract_entity_recursive(root)
+    ///     .build();
+    ///
+    /// let world = &mut other.world;
+    /// snapshot.apply(world).unwrap();
+    ///
+    /// assert_eq!(world.query::<&Transform>().iter(world).count(), 4);
+    /// ```
+    pub fn extract_entities_recursive(self, entities: impl Iterator<Item = Entity>) -> Self {
+        let mut stack = entities.collect::<Vec<_>>();
+        let mut found = Vec::new();
+
+        while let Some(entity) = stack.pop() {
+            if let Some(children) = self.world.get::<Children>(entity) {
+                stack.extend(children.iter().copied());
+            }
+
+            found.push(entity);
+        }
+
+        self.extract_entities(found.into_iter())
+    }
+}
+
 /// A snapshot builder that may extract entities and resources from a [`World`].
 ///
 /// Filters extracted components and resources with the given filter.
@@ -122,5 +505,163 @@ pub trait Build: Sized {
     fn clear_empty(self) -> Self;
 
     /// Build the extracted resources into a snapshot.
+    ///
+    /// Entities are ordered by their [`SaveId`], then by index for entities without one.
+    /// This does not depend on Bevy's internal entity order, which changes as entities are respawned
+    /// and between Bevy versions, so the same state always serializes identically.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let world = &mut app.world;
+    /// for i in 0..3 {
+    ///     world.spawn(Transform::from_xyz(i as f32, 0.0, 0.0));
+    /// }
+    ///
+    /// let save = |world: &World| {
+    ///     let snapshot = Snapshot::builder(world).extract_all_entities().build();
+    ///     let registry = world.resource::<AppTypeRegistry>();
+    ///
+    ///     serde_json::to_string(&SnapshotSerializer::new(&snapshot, registry)).unwrap()
+    /// };
+    ///
+    /// let before = save(world);
+    ///
+    /// // Respawn entities at the same indices with newer generations, reversing the order of their ids
+    /// for index in [1, 0, 0] {
+    ///     let entity = world.iter_entities().find(|e| e.id().index() == index).unwrap().id();
+    ///     let transform = *world.get::<Transform>(entity).unwrap();
+    ///
+    ///     world.despawn(entity);
+    ///     assert_eq!(world.spawn(transform).id().index(), index);
+    /// }
+    ///
+    /// let mut ids = world.iter_entities().map(|e| e.id()).collect::<Vec<_>>();
+    /// ids.sort();
+    /// assert_eq!(ids.iter().map(|e| e.index()).collect::<Vec<_>>(), [2, 1, 0]);
+    ///
+    /// assert_eq!(save(world), before);
+    /// ```
     fn build(self) -> Self::Output;
 }
+
+/// Canonicalize the extracted components and resources if [`Builder::canonical_floats`] is set.
+pub(crate) fn canonicalize<S, F>(builder: &mut Builder<'_, S, F>) {
+    if !builder.canonical_floats {
+        return;
+    }
+
+    let entities = builder.entities.values_mut().flat_map(|e| e.components.iter_mut());
+
+    for value in entities.chain(builder.resources.values_mut()) {
+        canonicalize_floats(&mut **value);
+    }
+}
+
+/// Replace negative zero with positive zero and every NaN with the canonical NaN in a reflected value.
+pub(crate) fn canonicalize_floats(value: &mut dyn Reflect) {
+    if let Some(value) = value.downcast_mut::<f32>() {
+        if value.is_nan() {
+            *value = f32::NAN;
+        } else if *value == 0.0 {
+            *value = 0.0;
+        }
+        return;
+    }
+
+    if let Some(value) = value.downcast_mut::<f64>() {
+        if value.is_nan() {
+            *value = f64::NAN;
+        } else if *value == 0.0 {
+            *value = 0.0;
+        }
+        return;
+    }
+
+    match value.reflect_mut() {
+        ReflectMut::Struct(value) => {
+            for i in 0..value.field_len() {
+                if let Some(field) = value.field_at_mut(i) {
+                    canonicalize_floats(field);
+                }
+            }
+        }
+        ReflectMut::TupleStruct(value) => {
+            for i in 0..value.field_len() {
+                if let Some(field) = value.field_mut(i) {
+                    canonicalize_floats(field);
+                }
+            }
+        }
+        ReflectMut::Tuple(value) => {
+            for i in 0..value.field_len() {
+                if let Some(field) = value.field_mut(i) {
+                    canonicalize_floats(field);
+                }
+            }
+        }
+        ReflectMut::List(value) => {
+            for i in 0..value.len() {
+                if let Some(item) = value.get_mut(i) {
+                    canonicalize_floats(item);
+                }
+            }
+        }
+        ReflectMut::Array(value) => {
+            for i in 0..value.len() {
+                if let Some(item) = value.get_mut(i) {
+                    canonicalize_floats(item);
+                }
+            }
+        }
+        ReflectMut::Map(value) => {
+            for i in 0..value.len() {
+                if let Some((_, item)) = value.get_at_mut(i) {
+                    canonicalize_floats(item);
+                }
+            }
+        }
+        ReflectMut::Enum(value) => {
+            for i in 0..value.field_len() {
+                if let Some(field) = value.field_at_mut(i) {
+                    canonicalize_floats(field);
+                }
+            }
+        }
+        ReflectMut::Value(_) => {}
+    }
+}
+
+/// Returns the extracted entities ordered by [`SaveId`], then by index for entities without one.
+///
+/// With [`Builder::spawn_order`], the entities are instead ordered and ranked by their spawn order.
+pub(crate) fn ordered_entities(
+    world: &World,
+    entities: BTreeMap<Entity, SaveableEntity>,
+    spawn_order: bool,
+) -> Vec<SaveableEntity> {
+    let mut entities = entities.into_iter().collect::<Vec<_>>();
+
+    if spawn_order {
+        // Untagged entities were spawned after the last tagging, so they come last
+        entities.sort_by_key(|(entity, _)| {
+            let tick = world.get::<SpawnTick>(*entity);
+            (tick.is_none(), tick.copied(), entity.index())
+        });
+    } else {
+        entities.sort_by_key(|(entity, _)| {
+            let id = world.get::<SaveId>(*entity);
+            (id.is_none(), id.map(|id| id.0), entity.index())
+        });
+    }
+
+    for (rank, (_, entity)) in (0..).zip(&mut entities) {
+        entity.order = spawn_order.then_some(rank);
+    }
+
+    entities.into_iter().map(|(_, entity)| entity).collect()
+}
@@ -0,0 +1,59 @@
+use bevy::prelude::*;
+
+use crate::WorldSaveableExt;
+
+/// System set for capturing checkpoints and snapshots at a consistent point in the frame.
+///
+/// Configured in [`PostUpdate`] by [`SavePlugin`](crate::SavePlugin), after transform propagation,
+/// so captured [`GlobalTransform`] values always match their [`Transform`].
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// app.add_plugins(TransformPlugin);
+///
+/// let entity = app.world.spawn(TransformBundle::default()).id();
+///
+/// app.add_systems(
+///     Update,
+///     move |mut transforms: Query<&mut Transform>, mut requests: EventWriter<RequestCheckpoint>| {
+///         transforms.get_mut(entity).unwrap().translation.x += 1.0;
+///         requests.send(RequestCheckpoint);
+///     },
+/// );
+///
+/// app.update();
+///
+/// // Overwrite the propagated value, then restore the checkpoint
+/// app.world.entity_mut(entity).insert(GlobalTransform::default());
+/// app.world.rollback(0).unwrap();
+///
+/// let global = app.world.get::<GlobalTransform>(entity).unwrap();
+/// assert_eq!(global.translation().x, 1.0);
+/// ```
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CheckpointSet;
+
+/// Event requesting a checkpoint, captured by [`checkpoint_system`] in the [`CheckpointSet`].
+///
+/// Several requests sent in the same frame create a single checkpoint.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestCheckpoint;
+
+/// Run condition that is true if a [`RequestCheckpoint`] was sent since it last ran.
+pub fn checkpoint_requested(mut requests: EventReader<RequestCheckpoint>) -> bool {
+    let requested = !requests.is_empty();
+    requests.clear();
+    requested
+}
+
+/// Creates a checkpoint of the [`World`].
+///
+/// Added to the [`CheckpointSet`] by [`SavePlugin`](crate::SavePlugin), running if [`checkpoint_requested`].
+pub fn checkpoint_system(world: &mut World) {
+    world.checkpoint();
+}
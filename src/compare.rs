@@ -0,0 +1,307 @@
+use std::collections::{
+    BTreeMap,
+    BTreeSet,
+};
+
+use bevy::{
+    prelude::*,
+    reflect::ReflectRef,
+};
+
+use crate::snapshot::RawSnapshot;
+
+/// Returns true if the two reflected values are deeply equal.
+///
+/// Unlike [`Reflect::reflect_partial_eq`], values are compared field by field, so a concrete value
+/// and its dynamic clone, such as a component stored in a [`Snapshot`](crate::Snapshot), compare equal.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// let transform = Transform::from_xyz(1.0, 2.0, 3.0);
+/// let clone = transform.clone_value();
+///
+/// assert!(reflect_values_eq(&transform, &*clone));
+/// assert!(!reflect_values_eq(&transform, &Transform::default()));
+/// ```
+pub fn reflect_values_eq(a: &dyn Reflect, b: &dyn Reflect) -> bool {
+    let mut differences = Vec::new();
+    reflect_differences(a, b, "", &mut differences);
+    differences.is_empty()
+}
+
+/// Push a readable description of each difference between the two reflected values.
+///
+/// `path` is the dot-separated path of the values, empty for the root.
+fn reflect_differences(a: &dyn Reflect, b: &dyn Reflect, path: &str, out: &mut Vec<String>) {
+    let at = |name: &dyn std::fmt::Display| {
+        if path.is_empty() {
+            name.to_string()
+        } else {
+            format!("{path}.{name}")
+        }
+    };
+
+    let mismatch = |out: &mut Vec<String>| {
+        let path = if path.is_empty() { "value" } else { path };
+        out.push(format!("`{path}`: {a:?} != {b:?}"));
+    };
+
+    if a.type_name() != b.type_name() {
+        mismatch(out);
+        return;
+    }
+
+    match (a.reflect_ref(), b.reflect_ref()) {
+        (ReflectRef::Struct(a), ReflectRef::Struct(b)) => {
+            let names = (0..a.field_len())
+                .filter_map(|i| a.name_at(i))
+                .chain((0..b.field_len()).filter_map(|i| b.name_at(i)))
+                .collect::<BTreeSet<_>>();
+
+            for name in names {
+                match (a.field(name), b.field(name)) {
+                    (Some(a), Some(b)) => reflect_differences(a, b, &at(&name), out),
+                    _ => out.push(format!("`{}`: missing on one side", at(&name))),
+                }
+            }
+        }
+        (ReflectRef::TupleStruct(a), ReflectRef::TupleStruct(b)) => {
+            for i in 0..a.field_len().max(b.field_len()) {
+                match (a.field(i), b.field(i)) {
+                    (Some(a), Some(b)) => reflect_differences(a, b, &at(&i), out),
+                    _ => out.push(format!("`{}`: missing on one side", at(&i))),
+                }
+            }
+        }
+        (ReflectRef::Tuple(a), ReflectRef::Tuple(b)) => {
+            for i in 0..a.field_len().max(b.field_len()) {
+                match (a.field(i), b.field(i)) {
+                    (Some(a), Some(b)) => reflect_differences(a, b, &at(&i), out),
+                    _ => out.push(format!("`{}`: missing on one side", at(&i))),
+                }
+            }
+        }
+        (ReflectRef::List(a), ReflectRef::List(b)) => {
+            for i in 0..a.len().max(b.len()) {
+                match (a.get(i), b.get(i)) {
+                    (Some(a), Some(b)) => reflect_differences(a, b, &at(&i), out),
+                    _ => out.push(format!("`{}`: missing on one side", at(&i))),
+                }
+            }
+        }
+        (ReflectRef::Array(a), ReflectRef::Array(b)) => {
+            for i in 0..a.len().max(b.len()) {
+                match (a.get(i), b.get(i)) {
+                    (Some(a), Some(b)) => reflect_differences(a, b, &at(&i), out),
+                    _ => out.push(format!("`{}`: missing on one side", at(&i))),
+                }
+            }
+        }
+        (ReflectRef::Map(a), ReflectRef::Map(b)) => {
+            if a.len() != b.len() {
+                mismatch(out);
+                return;
+            }
+
+            for (key, a) in a.iter() {
+                let name = format!("{key:?}");
+
+                match b.get(key) {
+                    Some(b) => reflect_differences(a, b, &at(&name), out),
+                    None => out.push(format!("`{}`: missing on one side", at(&name))),
+                }
+            }
+        }
+        (ReflectRef::Enum(a), ReflectRef::Enum(b)) => {
+            if a.variant_name() != b.variant_name() || a.field_len() != b.field_len() {
+                out.push(format!(
+                    "`{}`: variant {} != {}",
+                    if path.is_empty() { "value" } else { path },
+                    a.variant_name(),
+                    b.variant_name()
+                ));
+                return;
+            }
+
+            for i in 0..a.field_len() {
+                let name = a.name_at(i).map_or_else(|| i.to_string(), str::to_string);
+
+                if let (Some(a), Some(b)) = (a.field_at(i), b.field_at(i)) {
+                    reflect_differences(a, b, &at(&name), out);
+                }
+            }
+        }
+        (ReflectRef::Value(a), ReflectRef::Value(b)) => {
+            let eq = a
+                .reflect_partial_eq(b)
+                .or_else(|| b.reflect_partial_eq(a))
+                .unwrap_or(false);
+
+            if !eq {
+                mismatch(out);
+            }
+        }
+        _ => mismatch(out),
+    }
+}
+
+/// Whether [`World::save`](crate::WorldSaveableExt::save) checks that the save deserializes back to the saved values.
+///
+/// With [`AppSelfCheck::Enabled`], the snapshot is serialized into memory and deserialized again before it is written,
+/// and the save fails with [`SaveableError::LossySerialization`](crate::SaveableError::LossySerialization)
+/// if any component or resource comes back different, such as a type with a custom serialization that drops data.
+/// This roughly doubles the cost of saving, so it is meant for debug builds and critical data.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// # use serde::{Deserialize, Deserializer, Serialize, Serializer};
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// // Only the whole part of the health is serialized
+/// #[derive(Component, Reflect, Default, Debug, Clone, Copy, PartialEq)]
+/// #[reflect_value(Component, Serialize, Deserialize, PartialEq)]
+/// struct Health(f32);
+///
+/// impl Serialize for Health {
+///     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+///         serializer.serialize_i32(self.0 as i32)
+///     }
+/// }
+///
+/// impl<'de> Deserialize<'de> for Health {
+///     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+///         i32::deserialize(deserializer).map(|health| Self(health as f32))
+///     }
+/// }
+///
+/// app.register_saveable::<Health>()
+///     .insert_resource(AppBackend::new(MemoryBackend::new()))
+///     .insert_resource(AppSelfCheck::Enabled);
+///
+/// let world = &mut app.world;
+/// world.spawn((Transform::from_xyz(1.5, 2.0, 3.0), Health(100.0)));
+///
+/// // Whole health survives the round trip
+/// world.save("whole").unwrap();
+///
+/// world.spawn(Health(2.5));
+///
+/// match world.save("fractional") {
+///     Err(SaveableError::LossySerialization { type_name }) => assert!(type_name.ends_with("Health")),
+///     _ => panic!("expected the self-check to flag Health"),
+/// }
+///
+/// assert!(!world.save_exists("fractional"));
+/// ```
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AppSelfCheck {
+    /// Write saves without checking them.
+    ///
+    /// `bevy_save` default
+    #[default]
+    Disabled,
+
+    /// Deserialize each save in memory and compare it to the saved values before writing it.
+    Enabled,
+}
+
+/// Returns the type name of the first component or resource that differs between the two snapshots.
+pub(crate) fn lossy_type(saved: &RawSnapshot, loaded: &RawSnapshot) -> Option<String> {
+    let loaded_entities = loaded
+        .entities
+        .iter()
+        .map(|e| (e.entity, &e.components))
+        .collect::<BTreeMap<_, _>>();
+
+    let empty = Vec::new();
+
+    std::iter::once((&saved.resources, &loaded.resources))
+        .chain(saved.entities.iter().map(|e| {
+            let loaded = loaded_entities.get(&e.entity).copied().unwrap_or(&empty);
+            (&e.components, loaded)
+        }))
+        .find_map(|(saved, loaded)| {
+            saved.iter().find_map(|value| {
+                let equal = loaded
+                    .iter()
+                    .find(|loaded| loaded.type_name() == value.type_name())
+                    .is_some_and(|loaded| {
+                        reflect_values_eq(value.as_reflect(), loaded.as_reflect())
+                    });
+
+                (!equal).then(|| value.type_name().to_string())
+            })
+        })
+}
+
+/// Returns a readable description of each difference between the two snapshots.
+///
+/// Entities are identified by their saved index, components and resources by their type name.
+pub(crate) fn snapshot_mismatches(left: &RawSnapshot, right: &RawSnapshot) -> Vec<String> {
+    let mut out = Vec::new();
+
+    reflects_mismatches(&left.resources, &right.resources, "resource", &mut out);
+
+    let left = left
+        .entities
+        .iter()
+        .map(|e| (e.entity, &e.components))
+        .collect::<BTreeMap<_, _>>();
+
+    let right = right
+        .entities
+        .iter()
+        .map(|e| (e.entity, &e.components))
+        .collect::<BTreeMap<_, _>>();
+
+    for index in left.keys().chain(right.keys()).collect::<BTreeSet<_>>() {
+        match (left.get(index), right.get(index)) {
+            (Some(a), Some(b)) => {
+                reflects_mismatches(a, b, &format!("entity {index}: component"), &mut out);
+            }
+            (Some(_), None) => out.push(format!("entity {index}: only in the left snapshot")),
+            _ => out.push(format!("entity {index}: only in the right snapshot")),
+        }
+    }
+
+    out
+}
+
+fn reflects_mismatches(
+    left: &[Box<dyn Reflect>],
+    right: &[Box<dyn Reflect>],
+    kind: &str,
+    out: &mut Vec<String>,
+) {
+    let left = left
+        .iter()
+        .map(|r| (r.type_name(), r))
+        .collect::<BTreeMap<_, _>>();
+
+    let right = right
+        .iter()
+        .map(|r| (r.type_name(), r))
+        .collect::<BTreeMap<_, _>>();
+
+    for name in left.keys().chain(right.keys()).collect::<BTreeSet<_>>() {
+        match (left.get(name), right.get(name)) {
+            (Some(a), Some(b)) => {
+                let mut differences = Vec::new();
+                reflect_differences(a.as_reflect(), b.as_reflect(), "", &mut differences);
+
+                out.extend(
+                    differences
+                        .into_iter()
+                        .map(|difference| format!("{kind} `{name}` {difference}")),
+                );
+            }
+            (Some(_), None) => out.push(format!("{kind} `{name}` only in the left snapshot")),
+            _ => out.push(format!("{kind} `{name}` only in the right snapshot")),
+        }
+    }
+}
@@ -0,0 +1,204 @@
+use std::io::{
+    Read,
+    Write,
+};
+
+use bevy::prelude::*;
+#[cfg(feature = "gzip")]
+use flate2::{
+    read::GzDecoder,
+    write::GzEncoder,
+};
+
+use crate::{
+    Reader,
+    SaveableError,
+    Writer,
+};
+
+/// The App's save body compression.
+///
+/// `bevy_save` will compress everything after the [`SaveHeader`](crate::SaveHeader) with this when saving.
+/// The header is always stored uncompressed so it can be read without inflating the save.
+///
+/// The gzip variants require the default `gzip` feature. Without it, loading a gzip save fails with
+/// [`SaveableError::UnknownFormat`].
+#[derive(Resource, Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AppCompression {
+    /// Store the save body as-is.
+    #[default]
+    None,
+
+    /// Compress the save body with gzip.
+    #[cfg(feature = "gzip")]
+    Gzip,
+
+    /// Compress the save body with gzip at the given level, from `0` (fastest) to `9` (smallest).
+    #[cfg(feature = "gzip")]
+    GzipLevel(u32),
+}
+
+impl AppCompression {
+    pub(crate) fn id(self) -> u8 {
+        match self {
+            Self::None => 0,
+            #[cfg(feature = "gzip")]
+            Self::Gzip | Self::GzipLevel(_) => 1,
+        }
+    }
+
+    pub(crate) fn from_id(id: u8) -> Option<Self> {
+        match id {
+            0 => Some(Self::None),
+            #[cfg(feature = "gzip")]
+            1 => Some(Self::Gzip),
+            _ => None,
+        }
+    }
+
+    /// Write the save body to `writer` with `write`, compressing it if necessary.
+    pub(crate) fn compress<W, F>(self, writer: W, write: F) -> Result<(), SaveableError>
+    where
+        W: Write,
+        F: FnOnce(Writer<'_>) -> Result<(), SaveableError>,
+    {
+        let mut writer = writer;
+
+        match self {
+            Self::None => write((&mut writer).into()),
+            #[cfg(feature = "gzip")]
+            Self::Gzip | Self::GzipLevel(_) => {
+                let level = match self {
+                    Self::GzipLevel(level) => flate2::Compression::new(level.min(9)),
+                    _ => flate2::Compression::default(),
+                };
+
+                let mut encoder = GzEncoder::new(writer, level);
+                write((&mut encoder).into())?;
+                encoder.finish().map_err(SaveableError::other)?;
+                Ok(())
+            }
+        }
+    }
+
+    /// Wrap `reader` so that the save body is decompressed as it is read.
+    pub(crate) fn decompress<'r, R>(self, reader: R) -> Reader<'r>
+    where
+        R: Read + 'r,
+    {
+        match self {
+            Self::None => Box::new(reader).into(),
+            #[cfg(feature = "gzip")]
+            Self::Gzip | Self::GzipLevel(_) => Box::new(GzDecoder::new(reader)).into(),
+        }
+    }
+}
+
+/// A codec that compresses whole buffers, used by [`CompressedBackend`](crate::CompressedBackend).
+///
+/// Implement this to plug in LZ4, a game-specific codec, or anything else not covered by [`AppCompression`].
+pub trait Compression: Send + Sync + 'static {
+    /// Compress `data`.
+    fn compress(&self, data: &[u8]) -> Vec<u8>;
+
+    /// Decompress `data` that was produced by [`Compression::compress`].
+    ///
+    /// # Errors
+    /// - [`SaveableError::Other`] if `data` could not be decompressed
+    fn decompress(&self, data: &[u8]) -> Result<Vec<u8>, SaveableError>;
+}
+
+/// Gzip [`Compression`] codec.
+#[cfg(feature = "gzip")]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GzipCodec {
+    level: Option<u32>,
+}
+
+#[cfg(feature = "gzip")]
+impl GzipCodec {
+    /// Create a new [`GzipCodec`] with the default compression level.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the compression level, from `0` (fastest) to `9` (smallest).
+    pub fn level(mut self, level: u32) -> Self {
+        self.level = Some(level.min(9));
+        self
+    }
+}
+
+#[cfg(feature = "gzip")]
+impl Compression for GzipCodec {
+    fn compress(&self, data: &[u8]) -> Vec<u8> {
+        let level = self
+            .level
+            .map_or_else(flate2::Compression::default, flate2::Compression::new);
+
+        let mut encoder = GzEncoder::new(Vec::new(), level);
+
+        // Writing to a `Vec` cannot fail
+        encoder.write_all(data).expect("Failed to compress data");
+        encoder.finish().expect("Failed to compress data")
+    }
+
+    fn decompress(&self, data: &[u8]) -> Result<Vec<u8>, SaveableError> {
+        let mut out = Vec::new();
+
+        GzDecoder::new(data)
+            .read_to_end(&mut out)
+            .map_err(SaveableError::other)?;
+
+        Ok(out)
+    }
+}
+
+/// Zstandard [`Compression`] codec.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// let codec = ZstdCodec::new().level(19);
+/// let data = b"bevy_save bevy_save bevy_save bevy_save".repeat(16);
+///
+/// let compressed = codec.compress(&data);
+/// assert!(compressed.len() < data.len());
+/// assert_eq!(codec.decompress(&compressed).unwrap(), data);
+///
+/// assert!(codec.decompress(b"not zstd").is_err());
+/// ```
+#[cfg(feature = "zstd")]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZstdCodec {
+    level: Option<i32>,
+}
+
+#[cfg(feature = "zstd")]
+impl ZstdCodec {
+    /// Create a new [`ZstdCodec`] with the default compression level.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the compression level, from `1` (fastest) to `22` (smallest).
+    pub fn level(mut self, level: i32) -> Self {
+        self.level = Some(level.clamp(1, 22));
+        self
+    }
+}
+
+#[cfg(feature = "zstd")]
+impl Compression for ZstdCodec {
+    fn compress(&self, data: &[u8]) -> Vec<u8> {
+        let level = self.level.unwrap_or(zstd::DEFAULT_COMPRESSION_LEVEL);
+
+        // Reading from a slice cannot fail
+        zstd::encode_all(data, level).expect("Failed to compress data")
+    }
+
+    fn decompress(&self, data: &[u8]) -> Result<Vec<u8>, SaveableError> {
+        zstd::decode_all(data).map_err(SaveableError::other)
+    }
+}
@@ -0,0 +1,417 @@
+use std::{
+    cell::Cell,
+    fmt,
+};
+
+use bevy::{
+    prelude::*,
+    reflect::ReflectRef,
+};
+use serde::de::{
+    self,
+    DeserializeSeed,
+    Deserializer,
+    EnumAccess,
+    MapAccess,
+    SeqAccess,
+    VariantAccess,
+    Visitor,
+};
+
+use crate::SaveableError;
+
+/// The maximum nesting depth of the components and resources the App saves and loads.
+///
+/// Every struct, tuple, list, map and enum inside a value adds a level, so `Transform` is 2 levels deep.
+/// When inserted, [`World::save`](crate::WorldSaveableExt::save) and [`World::load`](crate::WorldSaveableExt::load)
+/// return [`SaveableError::DepthExceeded`] for deeper values instead of overflowing the stack,
+/// which protects against pathological or malicious saves.
+/// [`SnapshotSerializer::max_depth`](crate::SnapshotSerializer::max_depth) and
+/// [`SnapshotDeserializer::max_depth`](crate::SnapshotDeserializer::max_depth) set the same limit directly.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// #[derive(Component, Reflect, Default)]
+/// #[reflect(Component)]
+/// struct Grid {
+///     cells: Vec<Vec<Vec<Vec<u32>>>>,
+/// }
+///
+/// app.register_saveable::<Grid>()
+///     .register_type::<Vec<u32>>()
+///     .register_type::<Vec<Vec<u32>>>()
+///     .register_type::<Vec<Vec<Vec<u32>>>>()
+///     .register_type::<Vec<Vec<Vec<Vec<u32>>>>>()
+///     .insert_resource(AppBackend::new(MemoryBackend::new()))
+///     .insert_resource(AppMaxDepth(4));
+///
+/// let world = &mut app.world;
+///
+/// // `Transform` is 2 levels deep
+/// world.spawn(Transform::default());
+/// world.save("shallow").unwrap();
+///
+/// // `Grid` is 5 levels deep
+/// world.spawn(Grid { cells: vec![vec![vec![vec![1]]]] });
+///
+/// assert!(matches!(
+///     world.save("deep"),
+///     Err(SaveableError::DepthExceeded { max_depth: 4 })
+/// ));
+///
+/// // A deep save written without the limit is rejected when loading
+/// world.remove_resource::<AppMaxDepth>();
+/// world.save("deep").unwrap();
+/// world.insert_resource(AppMaxDepth(4));
+///
+/// assert!(matches!(
+///     world.load("deep"),
+///     Err(SaveableError::DepthExceeded { max_depth: 4 })
+/// ));
+///
+/// world.load("shallow").unwrap();
+/// ```
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AppMaxDepth(pub usize);
+
+/// A nesting limit shared by the serializers and deserializers of a snapshot,
+/// recording whether any value exceeded it.
+pub(crate) struct DepthLimit {
+    max: usize,
+    exceeded: Cell<bool>,
+}
+
+impl DepthLimit {
+    pub(crate) fn new(max: usize) -> Self {
+        Self {
+            max,
+            exceeded: Cell::new(false),
+        }
+    }
+
+    /// Returns true if a value exceeded the limit.
+    pub(crate) fn exceeded(&self) -> bool {
+        self.exceeded.get()
+    }
+
+    /// Returns [`SaveableError::DepthExceeded`] for this limit.
+    pub(crate) fn error(&self) -> SaveableError {
+        SaveableError::DepthExceeded { max_depth: self.max }
+    }
+
+    /// Checks the nesting depth of a reflected value, returning an error if it is deeper than the limit.
+    ///
+    /// The check stops descending at the limit, so it cannot overflow the stack itself.
+    pub(crate) fn check(&self, value: &dyn Reflect) -> Result<(), SaveableError> {
+        if exceeds(value, 0, self.max) {
+            self.exceeded.set(true);
+            Err(self.error())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Wraps a seed so its deserializer fails once values nest deeper than the limit.
+    pub(crate) fn seed<T>(&self, seed: T) -> Limited<'_, T> {
+        Limited {
+            inner: seed,
+            limit: self,
+            depth: 0,
+        }
+    }
+
+    /// Returns the nested depth, or an error if it is deeper than the limit.
+    fn enter<E: de::Error>(&self, depth: usize) -> Result<usize, E> {
+        if depth >= self.max {
+            self.exceeded.set(true);
+            Err(E::custom(self.error()))
+        } else {
+            Ok(depth + 1)
+        }
+    }
+}
+
+/// Returns true if the value nests deeper than `max` levels below `depth`.
+fn exceeds(value: &dyn Reflect, depth: usize, max: usize) -> bool {
+    let nested = |mut fields: Box<dyn Iterator<Item = &dyn Reflect> + '_>| {
+        depth >= max || fields.any(|field| exceeds(field, depth + 1, max))
+    };
+
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => nested(Box::new(value.iter_fields())),
+        ReflectRef::TupleStruct(value) => nested(Box::new(value.iter_fields())),
+        ReflectRef::Tuple(value) => nested(Box::new(value.iter_fields())),
+        ReflectRef::List(value) => nested(Box::new(value.iter())),
+        ReflectRef::Array(value) => nested(Box::new(value.iter())),
+        ReflectRef::Map(value) => nested(Box::new(value.iter().flat_map(|(k, v)| [k, v]))),
+        ReflectRef::Enum(value) => nested(Box::new(value.iter_fields().map(|field| field.value()))),
+        ReflectRef::Value(_) => false,
+    }
+}
+
+/// A seed, deserializer, visitor or access wrapped to track its nesting depth against a [`DepthLimit`].
+pub(crate) struct Limited<'a, T> {
+    inner: T,
+    limit: &'a DepthLimit,
+    depth: usize,
+}
+
+impl<'a, T> Limited<'a, T> {
+    fn wrap<U>(&self, inner: U) -> Limited<'a, U> {
+        Limited {
+            inner,
+            limit: self.limit,
+            depth: self.depth,
+        }
+    }
+}
+
+impl<'de, T: DeserializeSeed<'de>> DeserializeSeed<'de> for Limited<'_, T> {
+    type Value = T::Value;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let deserializer = self.wrap(deserializer);
+        self.inner.deserialize(deserializer)
+    }
+}
+
+macro_rules! forward_deserialize {
+    ($($method:ident $(($($arg:ident: $ty:ty),*))?),* $(,)?) => {
+        $(
+            fn $method<V>(self, $($($arg: $ty,)*)? visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: Visitor<'de>,
+            {
+                let visitor = self.wrap(visitor);
+                self.inner.$method($($($arg,)*)? visitor)
+            }
+        )*
+    };
+}
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for Limited<'_, D> {
+    type Error = D::Error;
+
+    forward_deserialize! {
+        deserialize_any,
+        deserialize_bool,
+        deserialize_i8,
+        deserialize_i16,
+        deserialize_i32,
+        deserialize_i64,
+        deserialize_i128,
+        deserialize_u8,
+        deserialize_u16,
+        deserialize_u32,
+        deserialize_u64,
+        deserialize_u128,
+        deserialize_f32,
+        deserialize_f64,
+        deserialize_char,
+        deserialize_str,
+        deserialize_string,
+        deserialize_bytes,
+        deserialize_byte_buf,
+        deserialize_option,
+        deserialize_unit,
+        deserialize_unit_struct(name: &'static str),
+        deserialize_newtype_struct(name: &'static str),
+        deserialize_seq,
+        deserialize_tuple(len: usize),
+        deserialize_tuple_struct(name: &'static str, len: usize),
+        deserialize_map,
+        deserialize_struct(name: &'static str, fields: &'static [&'static str]),
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]),
+        deserialize_identifier,
+        deserialize_ignored_any,
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+macro_rules! forward_visit {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method<E: de::Error>(self, value: $ty) -> Result<Self::Value, E> {
+                self.inner.$method(value)
+            }
+        )*
+    };
+}
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for Limited<'_, V> {
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.inner.expecting(formatter)
+    }
+
+    forward_visit! {
+        visit_bool(bool),
+        visit_i8(i8),
+        visit_i16(i16),
+        visit_i32(i32),
+        visit_i64(i64),
+        visit_i128(i128),
+        visit_u8(u8),
+        visit_u16(u16),
+        visit_u32(u32),
+        visit_u64(u64),
+        visit_u128(u128),
+        visit_f32(f32),
+        visit_f64(f64),
+        visit_char(char),
+        visit_str(&str),
+        visit_borrowed_str(&'de str),
+        visit_string(String),
+        visit_bytes(&[u8]),
+        visit_borrowed_bytes(&'de [u8]),
+        visit_byte_buf(Vec<u8>),
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.inner.visit_none()
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.inner.visit_unit()
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        let depth = self.limit.enter(self.depth)?;
+        let deserializer = Limited { depth, ..self.wrap(deserializer) };
+        self.inner.visit_some(deserializer)
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        let depth = self.limit.enter(self.depth)?;
+        let deserializer = Limited { depth, ..self.wrap(deserializer) };
+        self.inner.visit_newtype_struct(deserializer)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        let depth = self.limit.enter(self.depth)?;
+        let seq = Limited { depth, ..self.wrap(seq) };
+        self.inner.visit_seq(seq)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        let depth = self.limit.enter(self.depth)?;
+        let map = Limited { depth, ..self.wrap(map) };
+        self.inner.visit_map(map)
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        let depth = self.limit.enter(self.depth)?;
+        let data = Limited { depth, ..self.wrap(data) };
+        self.inner.visit_enum(data)
+    }
+}
+
+impl<'de, A: SeqAccess<'de>> SeqAccess<'de> for Limited<'_, A> {
+    type Error = A::Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        let seed = self.wrap(seed);
+        self.inner.next_element_seed(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'de, A: MapAccess<'de>> MapAccess<'de> for Limited<'_, A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        let seed = self.wrap(seed);
+        self.inner.next_key_seed(seed)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let seed = self.wrap(seed);
+        self.inner.next_value_seed(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, 'de, A: EnumAccess<'de>> EnumAccess<'de> for Limited<'a, A> {
+    type Error = A::Error;
+    type Variant = Limited<'a, A::Variant>;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let seed = self.wrap(seed);
+        let (value, variant) = self.inner.variant_seed(seed)?;
+
+        Ok((value, Limited {
+            inner: variant,
+            limit: self.limit,
+            depth: self.depth,
+        }))
+    }
+}
+
+impl<'de, A: VariantAccess<'de>> VariantAccess<'de> for Limited<'_, A> {
+    type Error = A::Error;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        self.inner.unit_variant()
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        let seed = self.wrap(seed);
+        self.inner.newtype_variant_seed(seed)
+    }
+
+    fn tuple_variant<V>(self, len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let visitor = self.wrap(visitor);
+        self.inner.tuple_variant(len, visitor)
+    }
+
+    fn struct_variant<V>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let visitor = self.wrap(visitor);
+        self.inner.struct_variant(fields, visitor)
+    }
+}
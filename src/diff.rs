@@ -0,0 +1,181 @@
+use std::collections::{
+    BTreeMap,
+    BTreeSet,
+};
+
+use bevy::prelude::*;
+
+use crate::{
+    entity::SaveableEntity,
+    snapshot::RawSnapshot,
+};
+
+/// The difference between two snapshots, as produced by [`Snapshot::diff`](crate::Snapshot::diff).
+///
+/// Entities are identified by their saved index, components and resources by their type name.
+/// All lists are sorted.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SnapshotDelta {
+    /// Entities present only in the newer snapshot.
+    pub entities_added: Vec<u32>,
+
+    /// Entities present only in the older snapshot.
+    pub entities_removed: Vec<u32>,
+
+    /// Components that were added or whose value changed.
+    pub components_changed: Vec<(u32, String)>,
+
+    /// Components removed from entities present in both snapshots.
+    pub components_removed: Vec<(u32, String)>,
+
+    /// Resources that were added or whose value changed.
+    pub resources_changed: Vec<String>,
+
+    /// Resources present only in the older snapshot.
+    pub resources_removed: Vec<String>,
+}
+
+impl SnapshotDelta {
+    /// Returns true if the snapshots were identical.
+    pub fn is_empty(&self) -> bool {
+        self.entities_added.is_empty()
+            && self.entities_removed.is_empty()
+            && self.components_changed.is_empty()
+            && self.components_removed.is_empty()
+            && self.resources_changed.is_empty()
+            && self.resources_removed.is_empty()
+    }
+
+    /// Compute the difference from `before` to `after`.
+    pub(crate) fn between(before: &RawSnapshot, after: &RawSnapshot) -> Self {
+        let mut delta = Self::default();
+
+        let (changed, removed) = diff_reflects(&before.resources, &after.resources);
+        delta.resources_changed = changed;
+        delta.resources_removed = removed;
+
+        let before_entities = before
+            .entities
+            .iter()
+            .map(|e| (e.entity, &e.components))
+            .collect::<BTreeMap<_, _>>();
+
+        let after_entities = after
+            .entities
+            .iter()
+            .map(|e| (e.entity, &e.components))
+            .collect::<BTreeMap<_, _>>();
+
+        for (&entity, components) in &after_entities {
+            let Some(previous) = before_entities.get(&entity) else {
+                delta.entities_added.push(entity);
+
+                for component in *components {
+                    delta
+                        .components_changed
+                        .push((entity, component.type_name().to_string()));
+                }
+
+                continue;
+            };
+
+            let (changed, removed) = diff_reflects(previous, components);
+
+            delta
+                .components_changed
+                .extend(changed.into_iter().map(|name| (entity, name)));
+
+            delta
+                .components_removed
+                .extend(removed.into_iter().map(|name| (entity, name)));
+        }
+
+        delta.entities_removed = before_entities
+            .keys()
+            .filter(|e| !after_entities.contains_key(e))
+            .copied()
+            .collect();
+
+        delta
+    }
+}
+
+impl RawSnapshot {
+    /// Returns the entities and resources of `self` that were added or changed since `base`.
+    ///
+    /// Every entity of `self` is kept, without its unchanged components, so entities missing from the result
+    /// are exactly those that were removed.
+    pub(crate) fn delta_from(&self, base: &RawSnapshot) -> RawSnapshot {
+        let delta = SnapshotDelta::between(base, self);
+
+        let resources = delta
+            .resources_changed
+            .iter()
+            .map(String::as_str)
+            .collect::<BTreeSet<_>>();
+        let components = delta
+            .components_changed
+            .iter()
+            .map(|(entity, name)| (*entity, name.as_str()))
+            .collect::<BTreeSet<_>>();
+
+        RawSnapshot {
+            resources: self
+                .resources
+                .iter()
+                .filter(|r| resources.contains(r.type_name()))
+                .map(|r| r.clone_value())
+                .collect(),
+            entities: self
+                .entities
+                .iter()
+                .map(|e| SaveableEntity {
+                    entity: e.entity,
+                    components: e
+                        .components
+                        .iter()
+                        .filter(|c| components.contains(&(e.entity, c.type_name())))
+                        .map(|c| c.clone_value())
+                        .collect(),
+                    defaults: e.defaults.clone(),
+                    order: e.order,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Returns the sorted type names that were added or changed, and those that were removed.
+fn diff_reflects(
+    before: &[Box<dyn Reflect>],
+    after: &[Box<dyn Reflect>],
+) -> (Vec<String>, Vec<String>) {
+    let before = before
+        .iter()
+        .map(|r| (r.type_name(), r))
+        .collect::<BTreeMap<_, _>>();
+
+    let after = after
+        .iter()
+        .map(|r| (r.type_name(), r))
+        .collect::<BTreeMap<_, _>>();
+
+    let changed = after
+        .iter()
+        .filter(|(name, value)| {
+            before
+                .get(*name)
+                .and_then(|previous| previous.reflect_partial_eq(value.as_reflect()))
+                != Some(true)
+        })
+        .map(|(name, _)| (*name).to_string())
+        .collect();
+
+    let removed = before
+        .keys()
+        .filter(|name| !after.contains_key(*name))
+        .map(|name| (*name).to_string())
+        .collect();
+
+    (changed, removed)
+}
@@ -0,0 +1,466 @@
+use std::any::{
+    type_name,
+    Any,
+};
+
+use bevy::{
+    prelude::*,
+    reflect::{
+        enum_hash,
+        enum_partial_eq,
+        utility::{
+            GenericTypeInfoCell,
+            GenericTypePathCell,
+        },
+        DynamicEnum,
+        Enum,
+        EnumInfo,
+        FromType,
+        GetTypeRegistration,
+        ReflectFromPtr,
+        ReflectMut,
+        ReflectOwned,
+        ReflectRef,
+        TupleVariantInfo,
+        TypeInfo,
+        TypePath,
+        TypeRegistration,
+        Typed,
+        UnnamedField,
+        VariantFieldIter,
+        VariantInfo,
+        VariantType,
+    },
+};
+
+type FromReflectFn<T> = dyn Fn(&dyn Reflect) -> Option<Box<T>> + Send + Sync;
+
+/// A concrete type that may be stored behind the trait object `T` in a [`DynBox<T>`].
+pub struct DynVariant<T: ?Sized> {
+    name: &'static str,
+    field: UnnamedField,
+    from_reflect: Box<FromReflectFn<T>>,
+}
+
+impl<T: ?Sized + 'static> DynVariant<T> {
+    /// Create a new [`DynVariant`] for the concrete type `C`, saved under the given name.
+    ///
+    /// `into` converts the concrete type into the trait object, usually `|value| Box::new(value)`.
+    pub fn new<C: FromReflect>(name: &'static str, into: fn(C) -> Box<T>) -> Self {
+        Self {
+            name,
+            field: UnnamedField::new::<C>(0),
+            from_reflect: Box::new(move |value| C::from_reflect(value).map(into)),
+        }
+    }
+}
+
+/// Implemented for a trait object type, such as `dyn MyTrait`, to allow it to be reflected in a [`DynBox`].
+///
+/// Each concrete implementor must also be registered in the [`AppTypeRegistry`].
+///
+/// # Panics
+/// - A [`DynBox`] holding a concrete type missing from [`DynVariants::variants`] panics when it is reflected
+///   as an enum, e.g. when it is saved. Use [`DynBox::try_new`] to catch this when boxing the value.
+pub trait DynVariants: Send + Sync + 'static {
+    /// Returns the concrete types that may be stored behind the trait object.
+    ///
+    /// The order of the variants must not change between calls.
+    fn variants() -> Vec<DynVariant<Self>>;
+
+    /// Returns the concrete value as a [`Reflect`].
+    fn reflect(&self) -> &dyn Reflect;
+
+    /// Returns the concrete value as a mutable [`Reflect`].
+    fn reflect_mut(&mut self) -> &mut dyn Reflect;
+}
+
+/// A reflectable box around a trait object, allowing components to store values whose concrete type varies.
+///
+/// The box is reflected as an enum with one variant per [`DynVariant`], so it serializes as a tagged union
+/// and deserializes into the concrete type named by the tag.
+///
+/// Register it with `app.register_type::<DynBox<dyn MyTrait>>()`.
+///
+/// # Panics
+/// - When reflected as an enum, e.g. when saved, if the boxed value is not one of the [`DynVariants::variants`].
+///   Use [`DynBox::try_new`] instead of the tuple constructor to check the value up front.
+/// - When applying a reflected enum whose variant name is not one of the [`DynVariants::variants`].
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// trait Shape: Reflect {
+///     fn area(&self) -> f32;
+/// }
+///
+/// #[derive(Reflect, Default)]
+/// struct Circle {
+///     radius: f32,
+/// }
+///
+/// impl Shape for Circle {
+///     fn area(&self) -> f32 {
+///         std::f32::consts::PI * self.radius * self.radius
+///     }
+/// }
+///
+/// #[derive(Reflect, Default)]
+/// struct Square {
+///     side: f32,
+/// }
+///
+/// impl Shape for Square {
+///     fn area(&self) -> f32 {
+///         self.side * self.side
+///     }
+/// }
+///
+/// impl DynVariants for dyn Shape {
+///     fn variants() -> Vec<DynVariant<Self>> {
+///         vec![
+///             DynVariant::new::<Circle>("Circle", |value| Box::new(value)),
+///             DynVariant::new::<Square>("Square", |value| Box::new(value)),
+///         ]
+///     }
+///
+///     fn reflect(&self) -> &dyn Reflect {
+///         self.as_reflect()
+///     }
+///
+///     fn reflect_mut(&mut self) -> &mut dyn Reflect {
+///         self.as_reflect_mut()
+///     }
+/// }
+///
+/// #[derive(Component, Reflect)]
+/// #[reflect(Component)]
+/// struct Drawn(DynBox<dyn Shape>);
+///
+/// impl Default for Drawn {
+///     fn default() -> Self {
+///         Self(DynBox(Box::new(Circle::default())))
+///     }
+/// }
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// app.register_saveable::<Drawn>()
+///     .register_type::<DynBox<dyn Shape>>()
+///     .register_type::<Circle>()
+///     .register_type::<Square>();
+///
+/// # let mut other = App::new();
+/// # other.add_plugins(MinimalPlugins);
+/// # other.add_plugins(SavePlugins);
+/// # other.register_saveable::<Drawn>()
+/// #     .register_type::<DynBox<dyn Shape>>()
+/// #     .register_type::<Circle>()
+/// #     .register_type::<Square>();
+/// app.world.spawn(Drawn(DynBox(Box::new(Circle { radius: 1.0 }))));
+/// app.world.spawn(Drawn(DynBox(Box::new(Square { side: 2.0 }))));
+///
+/// let mut save = Vec::new();
+/// app.world.serialize(&mut serde_json::Serializer::new(&mut save)).unwrap();
+///
+/// assert!(String::from_utf8_lossy(&save).contains(r#"{"Square":{"side":2.0}}"#));
+///
+/// other.world.deserialize(&mut serde_json::Deserializer::from_slice(&save)).unwrap();
+///
+/// let mut shapes = other
+///     .world
+///     .query::<&Drawn>()
+///     .iter(&other.world)
+///     .map(|drawn| (drawn.0.reflect().type_name().to_string(), drawn.0.area()))
+///     .collect::<Vec<_>>();
+///
+/// shapes.sort_by(|a, b| a.1.total_cmp(&b.1));
+///
+/// assert_eq!(shapes[0].0, std::any::type_name::<Circle>());
+/// assert_eq!(shapes[0].1, std::f32::consts::PI);
+/// assert_eq!(shapes[1].0, std::any::type_name::<Square>());
+/// assert_eq!(shapes[1].1, 4.0);
+/// ```
+pub struct DynBox<T: DynVariants + ?Sized>(pub Box<T>);
+
+impl<T: DynVariants + ?Sized> std::ops::Deref for DynBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: DynVariants + ?Sized> std::ops::DerefMut for DynBox<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T: DynVariants + ?Sized> DynBox<T> {
+    /// Box `value` if its concrete type is one of the [`DynVariants::variants`], or return it otherwise.
+    ///
+    /// # Errors
+    /// - Returns `value` back if its concrete type is not a variant
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// trait Shape: Reflect {}
+    ///
+    /// #[derive(Reflect, Default)]
+    /// struct Circle;
+    ///
+    /// #[derive(Reflect, Default)]
+    /// struct Triangle;
+    ///
+    /// impl Shape for Circle {}
+    /// impl Shape for Triangle {}
+    ///
+    /// impl DynVariants for dyn Shape {
+    ///     fn variants() -> Vec<DynVariant<Self>> {
+    ///         vec![DynVariant::new::<Circle>("Circle", |value| Box::new(value))]
+    ///     }
+    ///
+    ///     fn reflect(&self) -> &dyn Reflect {
+    ///         self.as_reflect()
+    ///     }
+    ///
+    ///     fn reflect_mut(&mut self) -> &mut dyn Reflect {
+    ///         self.as_reflect_mut()
+    ///     }
+    /// }
+    ///
+    /// assert!(DynBox::<dyn Shape>::try_new(Box::new(Circle)).is_ok());
+    ///
+    /// // `Triangle` is not listed, so saving it would panic
+    /// let triangle = DynBox::<dyn Shape>::try_new(Box::new(Triangle));
+    /// assert!(triangle.is_err_and(|value| value.reflect().is::<Triangle>()));
+    /// ```
+    pub fn try_new(value: Box<T>) -> Result<Self, Box<T>> {
+        match Self::position(&value) {
+            Some(_) => Ok(Self(value)),
+            None => Err(value),
+        }
+    }
+
+    /// Returns the index of the variant for the concrete type of `value`.
+    fn position(value: &T) -> Option<usize> {
+        let type_id = value.reflect().as_any().type_id();
+
+        Self::enum_info().iter().position(|variant| match variant {
+            VariantInfo::Tuple(info) => info.field_at(0).is_some_and(|f| f.type_id() == type_id),
+            _ => false,
+        })
+    }
+
+    fn enum_info() -> &'static EnumInfo {
+        let TypeInfo::Enum(info) = Self::type_info() else {
+            unreachable!()
+        };
+
+        info
+    }
+
+    fn from_variant(name: &str, value: &dyn Reflect) -> Option<Self> {
+        T::variants()
+            .into_iter()
+            .find(|variant| variant.name == name)
+            .and_then(|variant| (variant.from_reflect)(value))
+            .map(Self)
+    }
+}
+
+impl<T: DynVariants + ?Sized> Enum for DynBox<T> {
+    fn field(&self, _name: &str) -> Option<&dyn Reflect> {
+        None
+    }
+
+    fn field_at(&self, index: usize) -> Option<&dyn Reflect> {
+        (index == 0).then(|| self.0.reflect())
+    }
+
+    fn field_mut(&mut self, _name: &str) -> Option<&mut dyn Reflect> {
+        None
+    }
+
+    fn field_at_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+        (index == 0).then(|| self.0.reflect_mut())
+    }
+
+    fn index_of(&self, _name: &str) -> Option<usize> {
+        None
+    }
+
+    fn name_at(&self, _index: usize) -> Option<&str> {
+        None
+    }
+
+    fn iter_fields(&self) -> VariantFieldIter<'_> {
+        VariantFieldIter::new(self)
+    }
+
+    fn field_len(&self) -> usize {
+        1
+    }
+
+    fn variant_name(&self) -> &str {
+        Self::enum_info()
+            .variant_at(self.variant_index())
+            .unwrap()
+            .name()
+    }
+
+    fn variant_index(&self) -> usize {
+        Self::position(&self.0).unwrap_or_else(|| {
+            panic!(
+                "`{}` is not a variant of `{}`",
+                self.0.reflect().type_name(),
+                type_name::<Self>()
+            )
+        })
+    }
+
+    fn variant_type(&self) -> VariantType {
+        VariantType::Tuple
+    }
+
+    fn clone_dynamic(&self) -> DynamicEnum {
+        DynamicEnum::from_ref::<Self>(self)
+    }
+}
+
+impl<T: DynVariants + ?Sized> Reflect for DynBox<T> {
+    fn type_name(&self) -> &str {
+        type_name::<Self>()
+    }
+
+    fn get_represented_type_info(&self) -> Option<&'static TypeInfo> {
+        Some(<Self as Typed>::type_info())
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    fn apply(&mut self, value: &dyn Reflect) {
+        if let ReflectRef::Enum(value) = value.reflect_ref() {
+            let field = value.field_at(0).unwrap_or_else(|| {
+                panic!(
+                    "Field in `{}` variant of {} should exist",
+                    value.variant_name(),
+                    type_name::<Self>()
+                )
+            });
+
+            if self.variant_name() == value.variant_name() {
+                self.0.reflect_mut().apply(field);
+            } else {
+                *self = Self::from_variant(value.variant_name(), field).unwrap_or_else(|| {
+                    panic!(
+                        "variant with name `{}` does not exist on `{}`",
+                        value.variant_name(),
+                        type_name::<Self>()
+                    )
+                });
+            }
+        }
+    }
+
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = value.take()?;
+        Ok(())
+    }
+
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::Enum(self)
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::Enum(self)
+    }
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Enum(self)
+    }
+
+    fn clone_value(&self) -> Box<dyn Reflect> {
+        Box::new(Enum::clone_dynamic(self))
+    }
+
+    fn reflect_hash(&self) -> Option<u64> {
+        enum_hash(self)
+    }
+
+    fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
+        enum_partial_eq(self, value)
+    }
+}
+
+impl<T: DynVariants + ?Sized> FromReflect for DynBox<T> {
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        let ReflectRef::Enum(value) = reflect.reflect_ref() else {
+            return None;
+        };
+
+        Self::from_variant(value.variant_name(), value.field_at(0)?)
+    }
+}
+
+impl<T: DynVariants + ?Sized> Typed for DynBox<T> {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            let variants = T::variants()
+                .into_iter()
+                .map(|v| VariantInfo::Tuple(TupleVariantInfo::new(v.name, &[v.field])))
+                .collect::<Vec<_>>();
+
+            TypeInfo::Enum(EnumInfo::new::<Self>("DynBox", &variants))
+        })
+    }
+}
+
+impl<T: DynVariants + ?Sized> TypePath for DynBox<T> {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| format!("bevy_save::DynBox<{}>", type_name::<T>()))
+    }
+
+    fn short_type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| format!("DynBox<{}>", type_name::<T>()))
+    }
+}
+
+impl<T: DynVariants + ?Sized> GetTypeRegistration for DynBox<T> {
+    fn get_type_registration() -> TypeRegistration {
+        let mut registration = TypeRegistration::of::<Self>();
+        registration.insert::<ReflectFromPtr>(FromType::<Self>::from_type());
+        registration.insert::<ReflectFromReflect>(FromType::<Self>::from_type());
+        registration
+    }
+}
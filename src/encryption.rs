@@ -0,0 +1,120 @@
+use crate::SaveableError;
+
+/// An authenticated cipher that encrypts whole buffers, used by [`EncryptedBackend`](crate::EncryptedBackend).
+///
+/// Implement this to plug in ChaCha20-Poly1305, or any other AEAD cipher.
+/// The cipher must detect a wrong key or tampered data, so that loading fails instead of reading garbage.
+///
+/// With the `aes-gcm` feature, [`AesGcm`] implements this with AES-GCM.
+pub trait Cipher: Send + Sync + 'static {
+    /// The length in bytes of the nonce passed to [`Cipher::encrypt`] and [`Cipher::decrypt`].
+    fn nonce_len(&self) -> usize;
+
+    /// Encrypt `data` with `key` and a freshly generated random `nonce`.
+    ///
+    /// # Errors
+    /// - [`SaveableError::InvalidKey`] if the cipher does not support the length of `key`
+    fn encrypt(&self, key: &[u8], nonce: &[u8], data: &[u8]) -> Result<Vec<u8>, SaveableError>;
+
+    /// Decrypt `data` that was produced by [`Cipher::encrypt`] with the same `key` and `nonce`.
+    ///
+    /// # Errors
+    /// - [`SaveableError::DecryptionFailed`] if the key is wrong or `data` was tampered with
+    /// - [`SaveableError::InvalidKey`] if the cipher does not support the length of `key`
+    fn decrypt(&self, key: &[u8], nonce: &[u8], data: &[u8]) -> Result<Vec<u8>, SaveableError>;
+}
+
+/// A [`Cipher`] using AES-GCM, with AES-128 for 16 byte keys and AES-256 for 32 byte keys.
+///
+/// # Example
+/// ```
+/// # use std::io::Write;
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// let memory = MemoryBackend::new();
+/// let key = [7; 32];
+///
+/// app.insert_resource(AppBackend::encrypted(memory.clone(), key, Box::new(AesGcm)));
+///
+/// app.world.spawn(Transform::from_xyz(1.0, 2.0, 3.0));
+/// app.world.save("encrypted").unwrap();
+///
+/// app.world.clear_entities();
+/// app.world.load("encrypted").unwrap();
+///
+/// let transform = app.world.query::<&Transform>().single(&app.world);
+/// assert_eq!(transform.translation, Vec3::new(1.0, 2.0, 3.0));
+///
+/// // A truncated save fails to decrypt
+/// let save = memory.get("encrypted").unwrap();
+/// let mut writer = memory.writer("encrypted").unwrap();
+/// writer.write_all(&save[..save.len() - 1]).unwrap();
+/// drop(writer);
+///
+/// let result = app.world.load("encrypted");
+/// assert!(matches!(result, Err(SaveableError::DecryptionFailed)));
+///
+/// // Keys of unsupported lengths are rejected
+/// app.insert_resource(AppBackend::encrypted(memory, "secret", Box::new(AesGcm)));
+///
+/// let result = app.world.save("encrypted");
+/// assert!(result.is_err());
+/// ```
+#[cfg(feature = "aes-gcm")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AesGcm;
+
+#[cfg(feature = "aes-gcm")]
+impl AesGcm {
+    fn cipher<C: aes_gcm::KeyInit>(key: &[u8]) -> Result<C, SaveableError> {
+        C::new_from_slice(key).map_err(|_| SaveableError::InvalidKey { len: key.len() })
+    }
+}
+
+#[cfg(feature = "aes-gcm")]
+impl Cipher for AesGcm {
+    fn nonce_len(&self) -> usize {
+        12
+    }
+
+    fn encrypt(&self, key: &[u8], nonce: &[u8], data: &[u8]) -> Result<Vec<u8>, SaveableError> {
+        use aes_gcm::{
+            aead::Aead,
+            Aes128Gcm,
+            Aes256Gcm,
+            Nonce,
+        };
+
+        let nonce = Nonce::from_slice(nonce);
+
+        let encrypted = match key.len() {
+            16 => Self::cipher::<Aes128Gcm>(key)?.encrypt(nonce, data),
+            32 => Self::cipher::<Aes256Gcm>(key)?.encrypt(nonce, data),
+            len => return Err(SaveableError::InvalidKey { len }),
+        };
+
+        encrypted.map_err(SaveableError::other)
+    }
+
+    fn decrypt(&self, key: &[u8], nonce: &[u8], data: &[u8]) -> Result<Vec<u8>, SaveableError> {
+        use aes_gcm::{
+            aead::Aead,
+            Aes128Gcm,
+            Aes256Gcm,
+            Nonce,
+        };
+
+        let nonce = Nonce::from_slice(nonce);
+
+        let decrypted = match key.len() {
+            16 => Self::cipher::<Aes128Gcm>(key)?.decrypt(nonce, data),
+            32 => Self::cipher::<Aes256Gcm>(key)?.decrypt(nonce, data),
+            len => return Err(SaveableError::InvalidKey { len }),
+        };
+
+        decrypted.map_err(|_| SaveableError::DecryptionFailed)
+    }
+}
@@ -1,6 +1,10 @@
 use bevy::{
     ecs::entity::EntityMap,
     prelude::*,
+    reflect::{
+        std_traits::ReflectDefault,
+        TypeRegistryInternal,
+    },
 };
 
 use crate::prelude::*;
@@ -13,12 +17,49 @@ pub(crate) struct SaveableEntity {
     /// A vector of boxed components that belong to the given entity and
     /// implement the `Reflect` trait.
     pub components: Vec<Box<dyn Reflect>>,
+
+    /// The type names of components removed by [`Snapshot::compact`] because they held their default value.
+    pub defaults: Vec<String>,
+
+    /// The rank of the entity in spawn order, recorded with [`Builder::spawn_order`].
+    pub order: Option<u32>,
 }
 
 impl SaveableEntity {
     /// Returns true if there are no saved components for this Entity.
     pub fn is_empty(&self) -> bool {
-        self.components.is_empty()
+        self.components.is_empty() && self.defaults.is_empty()
+    }
+
+    /// Returns the saved components with the compacted ones restored to their default value,
+    /// or `None` if no components were compacted.
+    pub fn expanded(
+        &self,
+        registry: &TypeRegistryInternal,
+    ) -> Result<Option<Vec<Box<dyn Reflect>>>, SaveableError> {
+        if self.defaults.is_empty() {
+            return Ok(None);
+        }
+
+        let mut components = self.components.clone_value();
+
+        for type_name in &self.defaults {
+            let reg = registry.get_with_name(type_name).ok_or_else(|| {
+                SaveableError::UnregisteredType {
+                    type_name: type_name.clone(),
+                }
+            })?;
+
+            let default = reg.data::<ReflectDefault>().ok_or_else(|| {
+                SaveableError::MissingDefault {
+                    type_name: type_name.clone(),
+                }
+            })?;
+
+            components.push(default.default());
+        }
+
+        Ok(Some(components))
     }
 
     /// Attempts to map the stored index with the given [`EntityMap`].
@@ -37,6 +78,8 @@ impl CloneReflect for SaveableEntity {
         Self {
             entity: self.entity,
             components: self.components.clone_value(),
+            defaults: self.defaults.clone(),
+            order: self.order,
         }
     }
 }
@@ -1,3 +1,4 @@
+use bevy::prelude::Entity;
 use thiserror::Error;
 
 /// An error that may occur when loading saves or rollbacks.
@@ -24,6 +25,99 @@ pub enum SaveableError {
         type_name: String,
     },
 
+    /// A component removed by [`Snapshot::compact`](crate::Snapshot::compact) has no registered default.
+    #[error("scene contains the compacted component `{type_name}` without a default. you must add `#[reflect(Default)]` to your type")]
+    MissingDefault {
+        /// The type name of the compacted component
+        type_name: String,
+    },
+
+    /// A component registered with [`AppSaveableExt::register_saveable_with_entities`](crate::AppSaveableExt::register_saveable_with_entities)
+    /// does not reflect `MapEntities`.
+    #[error("the component `{type_name}` holds entity references without mapping them. you must add `#[reflect(MapEntities)]` to your type")]
+    MissingMapEntities {
+        /// The type name of the component
+        type_name: String,
+    },
+
+    /// Types reachable from saveable types were not registered in the `AppTypeRegistry`.
+    #[error("saveable types reference the unregistered types {type_names:?}. you must register each of them using `app.register_type::<T>()`")]
+    MissingRegistrations {
+        /// The type names of the unregistered types
+        type_names: Vec<String>,
+    },
+
+    /// Two [`EntityMap`](bevy::ecs::entity::EntityMap)s merged with [`merge_entity_maps`](crate::merge_entity_maps)
+    /// map the same key to different entities.
+    #[error("entity maps map `{key:?}` to both `{first:?}` and `{second:?}`")]
+    ConflictingMapping {
+        /// The conflicting key
+        key: Entity,
+
+        /// The entity the key maps to in the first map
+        first: Entity,
+
+        /// The entity the key maps to in the second map
+        second: Entity,
+    },
+
+    /// A value did not deserialize back to the value it was serialized from,
+    /// found with [`AppSelfCheck::Enabled`](crate::AppSelfCheck::Enabled).
+    #[error("the type `{type_name}` does not deserialize back to the saved value. check its serialization for lost data")]
+    LossySerialization {
+        /// The type name of the lossy component or resource
+        type_name: String,
+    },
+
+    /// A component or resource nests deeper than the [`AppMaxDepth`](crate::AppMaxDepth).
+    #[error("a saved value nests deeper than the maximum depth of {max_depth}")]
+    DepthExceeded {
+        /// The maximum depth
+        max_depth: usize,
+    },
+
+    /// A component captured by [`Snapshot::from_roots_strict`](crate::Snapshot::from_roots_strict)
+    /// references an entity outside the captured hierarchies.
+    #[error("the component `{type_name}` references the entity `{entity:?}` outside the captured hierarchies")]
+    ExternalReference {
+        /// The type name of the component
+        type_name: String,
+
+        /// The referenced entity
+        entity: Entity,
+    },
+
+    /// A save file header contained an unrecognized id.
+    #[error("save file has an unknown {kind} `{id}`")]
+    UnknownFormat {
+        /// The part of the header that was not recognized
+        kind: &'static str,
+
+        /// The unrecognized id
+        id: u8,
+    },
+
+    /// A save was written by a newer version of the App than the current [`AppSaveVersion`](crate::AppSaveVersion).
+    #[error("save file has version {found}, but only versions up to {supported} are supported")]
+    UnsupportedVersion {
+        /// The version of the save
+        found: u32,
+
+        /// The newest supported version
+        supported: u32,
+    },
+
+    /// An encrypted save could not be decrypted, because the key is wrong or the save was tampered with.
+    #[error("save could not be decrypted. the key is wrong or the save was tampered with")]
+    DecryptionFailed,
+
+    /// The encryption key does not have a length the cipher supports.
+    #[error("encryption key of {len} bytes is not supported by the cipher")]
+    InvalidKey {
+        /// The length of the key in bytes
+        len: usize,
+    },
+
     /// Deserialization failure or other error.
     #[error("other error: {0}")]
     Other(Box<dyn std::error::Error>),
@@ -0,0 +1,80 @@
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+
+/// A [`SerializeExtension`] writes the state of a save extension as bytes stored alongside the snapshot.
+///
+/// See [`AppSaveableExt::register_save_extension`](crate::AppSaveableExt::register_save_extension).
+pub trait SerializeExtension: Fn(&World) -> Vec<u8> + Send + Sync {}
+
+impl<T> SerializeExtension for T where T: Fn(&World) -> Vec<u8> + Send + Sync {}
+
+/// A boxed [`SerializeExtension`].
+pub type BoxedSerializeExtension = Box<dyn SerializeExtension>;
+
+/// A [`DeserializeExtension`] restores the state of a save extension from the bytes stored alongside the snapshot.
+///
+/// See [`AppSaveableExt::register_save_extension`](crate::AppSaveableExt::register_save_extension).
+pub trait DeserializeExtension: Fn(&mut World, &[u8]) + Send + Sync {}
+
+impl<T> DeserializeExtension for T where T: Fn(&mut World, &[u8]) + Send + Sync {}
+
+/// A boxed [`DeserializeExtension`].
+pub type BoxedDeserializeExtension = Box<dyn DeserializeExtension>;
+
+struct SaveExtension {
+    serialize: BoxedSerializeExtension,
+    deserialize: BoxedDeserializeExtension,
+}
+
+/// The App's save extensions, side-channel data saved after the snapshot in the same save file, keyed by name.
+///
+/// Register extensions with [`AppSaveableExt::register_save_extension`](crate::AppSaveableExt::register_save_extension).
+#[derive(Resource, Default)]
+pub struct AppSaveExtensions(BTreeMap<String, SaveExtension>);
+
+impl AppSaveExtensions {
+    /// Add a save extension with the given name, replacing any extension with the same name.
+    pub fn insert<S, D>(&mut self, name: &str, serialize: S, deserialize: D)
+    where
+        S: SerializeExtension + 'static,
+        D: DeserializeExtension + 'static,
+    {
+        self.0.insert(name.to_owned(), SaveExtension {
+            serialize: Box::new(serialize),
+            deserialize: Box::new(deserialize),
+        });
+    }
+
+    /// Remove the save extension with the given name, returning true if it existed.
+    pub fn remove(&mut self, name: &str) -> bool {
+        self.0.remove(name).is_some()
+    }
+
+    /// Returns true if a save extension with the given name exists.
+    pub fn contains(&self, name: &str) -> bool {
+        self.0.contains_key(name)
+    }
+
+    /// Returns true if there are no save extensions.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the data of every save extension, keyed by name.
+    pub(crate) fn serialize(&self, world: &World) -> BTreeMap<&str, Vec<u8>> {
+        self.0
+            .iter()
+            .map(|(name, extension)| (name.as_str(), (extension.serialize)(world)))
+            .collect()
+    }
+
+    /// Pass the saved data of each save extension to it, ignoring data without a matching extension.
+    pub(crate) fn deserialize(&self, world: &mut World, data: &BTreeMap<String, Vec<u8>>) {
+        for (name, extension) in &self.0 {
+            if let Some(data) = data.get(name) {
+                (extension.deserialize)(world, data);
+            }
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use bevy::{
+    prelude::*,
+    utils::Duration,
+};
+
+/// The saveable copy of the [`FixedTime`] accumulator, registered by
+/// [`AppSaveableExt::register_saveable_fixed_time`](crate::AppSaveableExt::register_saveable_fixed_time).
+///
+/// [`FixedTime`] does not implement [`Reflect`], so this resource mirrors it and is captured in its place.
+/// It is kept up to date by [`sync_saved_fixed_time`], and when a snapshot overwrites it,
+/// [`restore_saved_fixed_time`] writes the saved accumulator and period back into [`FixedTime`].
+///
+/// Restoring the accumulator only keeps the fixed timestep in phase; the [`Time`] resource itself is not saved,
+/// so replays are only deterministic if the frames after restoring advance by the same deltas.
+#[derive(Resource, Reflect, Default, Debug, Clone, PartialEq, Eq)]
+#[reflect(Resource)]
+pub struct SavedFixedTime {
+    /// The time accumulated towards the next fixed step.
+    pub accumulated: Duration,
+
+    /// The period of the fixed timestep.
+    pub period: Duration,
+
+    /// The number of fixed steps run since this resource was added.
+    pub steps: u64,
+}
+
+/// Counts the fixed steps run in [`SavedFixedTime::steps`].
+///
+/// Added to [`FixedUpdate`], and does not trigger change detection.
+pub fn count_fixed_steps(mut saved: ResMut<SavedFixedTime>) {
+    saved.bypass_change_detection().steps += 1;
+}
+
+/// Copies the current [`FixedTime`] into [`SavedFixedTime`].
+///
+/// Added to [`RunFixedUpdateLoop`](bevy::app::RunFixedUpdateLoop) after the fixed steps have run,
+/// and does not trigger change detection.
+#[allow(clippy::needless_pass_by_value)]
+pub fn sync_saved_fixed_time(fixed: Res<FixedTime>, mut saved: ResMut<SavedFixedTime>) {
+    let saved = saved.bypass_change_detection();
+
+    saved.accumulated = fixed.accumulated();
+    saved.period = fixed.period;
+}
+
+/// Writes [`SavedFixedTime`] back into [`FixedTime`] if it was overwritten, such as by applying a snapshot.
+///
+/// Added to [`PreUpdate`], so the restored accumulator is ticked by the following fixed update loop.
+#[allow(clippy::needless_pass_by_value)]
+pub fn restore_saved_fixed_time(saved: Res<SavedFixedTime>, mut fixed: ResMut<FixedTime>) {
+    if saved.is_changed() && !saved.is_added() {
+        *fixed = FixedTime::new(saved.period);
+        fixed.tick(saved.accumulated);
+    }
+}
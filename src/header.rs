@@ -0,0 +1,326 @@
+use std::{
+    collections::BTreeMap,
+    io::{
+        Cursor,
+        Read,
+        Write,
+    },
+};
+
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use bevy::prelude::*;
+
+use crate::{
+    AppCompression,
+    AppTypeNames,
+    Reader,
+    SaveFormat,
+    SaveableError,
+};
+
+const MAGIC: &[u8; 4] = b"BSAV";
+const VERSION: u8 = 3;
+
+/// The version of the App's save data, stored in the header of every save file.
+///
+/// Increase this whenever the saved data changes incompatibly.
+/// [`World::load`](crate::WorldSaveableExt::load) refuses saves from a newer version with [`SaveableError::UnsupportedVersion`],
+/// and [`peek_version`] reads the version of a save without loading it.
+///
+/// # Example
+/// ```
+/// # use std::{collections::HashMap, io::{Cursor, Write}, sync::Mutex};
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// # static SAVES: Mutex<Option<HashMap<String, Vec<u8>>>> = Mutex::new(None);
+/// # struct Memory;
+/// # struct MemoryWriter(String);
+/// # impl Write for MemoryWriter {
+/// #     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+/// #         let mut saves = SAVES.lock().unwrap();
+/// #         let save = saves.get_or_insert_with(HashMap::new).entry(self.0.clone()).or_default();
+/// #         save.extend_from_slice(buf);
+/// #         Ok(buf.len())
+/// #     }
+/// #     fn flush(&mut self) -> std::io::Result<()> { Ok(()) }
+/// # }
+/// # impl Backend for Memory {
+/// #     type Reader = Cursor<Vec<u8>>;
+/// #     type Writer = MemoryWriter;
+/// #     fn reader(name: &str) -> Result<Self::Reader, SaveableError> {
+/// #         let saves = SAVES.lock().unwrap();
+/// #         let save = saves.as_ref().and_then(|s| s.get(name)).cloned().unwrap_or_default();
+/// #         Ok(Cursor::new(save))
+/// #     }
+/// #     fn writer(name: &str) -> Result<Self::Writer, SaveableError> {
+/// #         SAVES.lock().unwrap().get_or_insert_with(HashMap::new).remove(name);
+/// #         Ok(MemoryWriter(name.to_owned()))
+/// #     }
+/// # }
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// # app.insert_resource(AppBackend::new(Memory));
+/// // Saved by a newer build of the game
+/// app.insert_resource(AppSaveVersion(99));
+/// app.world.save("future").unwrap();
+///
+/// app.insert_resource(AppSaveVersion(3));
+///
+/// let reader = app.world.resource::<AppBackend>().reader("future").unwrap();
+/// assert_eq!(peek_version(reader).unwrap(), 99);
+///
+/// assert!(matches!(
+///     app.world.load("future"),
+///     Err(SaveableError::UnsupportedVersion { found: 99, supported: 3 })
+/// ));
+/// ```
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AppSaveVersion(pub u32);
+
+/// Read the [`AppSaveVersion`] a save was written with, without loading the save.
+///
+/// Saves written without a version are version `0`.
+///
+/// # Errors
+/// - See [`SaveableError`]
+pub fn peek_version<R: Read>(reader: R) -> Result<u32, SaveableError> {
+    SaveHeader::read(reader).map(|(segment, _)| segment.version)
+}
+
+/// Read the [`SaveMetadata`] of a save, without loading the save.
+///
+/// # Errors
+/// - See [`SaveableError`]
+pub fn peek_metadata<R: Read>(reader: R) -> Result<SaveMetadata, SaveableError> {
+    SaveHeader::read(reader).map(|(segment, _)| SaveMetadata {
+        header: segment.header,
+        version: segment.version,
+        format: segment.format,
+        compression: segment.compression,
+    })
+}
+
+/// The metadata of a save, read from its header segment.
+///
+/// See [`WorldSaveableExt::save_metadata`](crate::WorldSaveableExt::save_metadata).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveMetadata {
+    /// The [`SaveHeader`] the save was written with.
+    pub header: SaveHeader,
+
+    /// The [`AppSaveVersion`] the save was written with.
+    pub version: u32,
+
+    /// The [`SaveFormat`] of the save body.
+    pub format: SaveFormat,
+
+    /// The [`AppCompression`] of the save body.
+    pub compression: AppCompression,
+}
+
+/// The header segment of a save file.
+pub(crate) struct HeaderSegment {
+    pub(crate) header: SaveHeader,
+    pub(crate) version: u32,
+    pub(crate) format: SaveFormat,
+    pub(crate) compression: AppCompression,
+    pub(crate) type_names: AppTypeNames,
+}
+
+/// Metadata stored uncompressed at the start of every save file.
+///
+/// The header segment starts with the `BSAV` magic bytes, followed by the header version, the [`SaveFormat`]
+/// [`AppCompression`] and [`AppTypeNames`] ids, so saves are loaded with the right decoder regardless of the
+/// current settings, and the [`AppSaveVersion`].
+///
+/// This can be read with [`WorldSaveableExt::load_header`](crate::WorldSaveableExt::load_header)
+/// without deserializing or decompressing the rest of the save, e.g. to list saves in a menu.
+///
+/// # Example
+/// ```
+/// # use std::io::Write;
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// # let world = &mut app.world;
+/// let memory = MemoryBackend::new();
+/// world.insert_resource(AppBackend::new(memory.clone()));
+/// world.insert_resource(AppCompression::Gzip);
+/// world.spawn(Transform::from_xyz(1.0, 2.0, 3.0));
+///
+/// let header = SaveHeader::new().with("title", "Chapter 1");
+/// world.save_with_header("slot_0", &header).unwrap();
+///
+/// // The header ends with the title, everything after it is the compressed body
+/// let save = memory.get("slot_0").unwrap();
+/// let body = save.windows(9).position(|w| w == b"Chapter 1").unwrap() + 9;
+///
+/// let mut overwrite = |bytes: &[u8]| {
+///     let mut writer = memory.writer("slot_0").unwrap();
+///     writer.write_all(bytes).unwrap();
+/// };
+///
+/// // Corrupt the compressed body
+/// let mut corrupted = save.clone();
+/// corrupted[body..].fill(0xFF);
+/// overwrite(&corrupted);
+///
+/// // Only the header is read, so it loads although the body cannot be decompressed
+/// let header = world.load_header("slot_0").unwrap();
+/// assert_eq!(header.get("title"), Some("Chapter 1"));
+/// assert!(world.load("slot_0").is_err());
+///
+/// // Truncate the save right after the header
+/// overwrite(&save[..body]);
+///
+/// let header = world.load_header("slot_0").unwrap();
+/// assert_eq!(header.get("title"), Some("Chapter 1"));
+/// assert!(world.load("slot_0").is_err());
+/// ```
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+pub struct SaveHeader {
+    /// User-defined metadata, such as a save title or timestamp.
+    pub metadata: BTreeMap<String, String>,
+}
+
+impl SaveHeader {
+    /// Returns a new, empty [`SaveHeader`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set a metadata entry on the [`SaveHeader`].
+    pub fn with<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+
+    /// Returns the metadata entry with the given key.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.metadata.get(key).map(|v| v.as_str())
+    }
+
+    /// Write the header segment to the given writer.
+    pub(crate) fn write<W: Write>(
+        &self,
+        mut writer: W,
+        version: AppSaveVersion,
+        format: SaveFormat,
+        compression: AppCompression,
+        type_names: AppTypeNames,
+    ) -> Result<(), SaveableError> {
+        let bytes = rmp_serde::to_vec(self).map_err(SaveableError::other)?;
+        let len = u32::try_from(bytes.len()).map_err(SaveableError::other)?;
+
+        writer.write_all(MAGIC).map_err(SaveableError::other)?;
+        writer
+            .write_all(&[VERSION, format.id(), compression.id(), type_names.id()])
+            .map_err(SaveableError::other)?;
+        writer
+            .write_all(&version.0.to_le_bytes())
+            .map_err(SaveableError::other)?;
+        writer
+            .write_all(&len.to_le_bytes())
+            .map_err(SaveableError::other)?;
+        writer.write_all(&bytes).map_err(SaveableError::other)?;
+
+        Ok(())
+    }
+
+    /// Read the header segment from the given reader, returning the segment and a reader over the save body.
+    ///
+    /// Saves written without a header are treated as an uncompressed, version `0` [`SaveFormat::Custom`] body
+    /// with an empty [`SaveHeader`].
+    ///
+    /// # Errors
+    /// - [`SaveableError::UnknownFormat`] if the header version, format or compression is not recognized
+    pub(crate) fn read<'r, R: Read + 'r>(
+        mut reader: R,
+    ) -> Result<(HeaderSegment, Reader<'r>), SaveableError> {
+        let mut magic = Vec::with_capacity(MAGIC.len());
+
+        (&mut reader)
+            .take(MAGIC.len() as u64)
+            .read_to_end(&mut magic)
+            .map_err(SaveableError::other)?;
+
+        if magic != MAGIC {
+            let body = Cursor::new(magic).chain(reader);
+
+            let segment = HeaderSegment {
+                header: Self::default(),
+                version: 0,
+                format: SaveFormat::Custom,
+                compression: AppCompression::None,
+                type_names: AppTypeNames::Inline,
+            };
+
+            return Ok((segment, Box::new(body).into()));
+        }
+
+        let mut ids = [0; 3];
+        reader.read_exact(&mut ids).map_err(SaveableError::other)?;
+
+        let [layout, format, compression] = ids;
+
+        // Layout `1` did not store the save version, layout `2` did not store the type names
+        if !(1..=VERSION).contains(&layout) {
+            return Err(SaveableError::UnknownFormat {
+                kind: "header version",
+                id: layout,
+            });
+        }
+
+        let format = SaveFormat::from_id(format).ok_or(SaveableError::UnknownFormat {
+            kind: "format",
+            id: format,
+        })?;
+
+        let compression =
+            AppCompression::from_id(compression).ok_or(SaveableError::UnknownFormat {
+                kind: "compression",
+                id: compression,
+            })?;
+
+        let mut type_names = [AppTypeNames::Inline.id()];
+
+        if layout >= 3 {
+            reader.read_exact(&mut type_names).map_err(SaveableError::other)?;
+        }
+
+        let type_names =
+            AppTypeNames::from_id(type_names[0]).ok_or(SaveableError::UnknownFormat {
+                kind: "type names",
+                id: type_names[0],
+            })?;
+
+        let mut version = [0; 4];
+
+        if layout >= 2 {
+            reader.read_exact(&mut version).map_err(SaveableError::other)?;
+        }
+
+        let mut len = [0; 4];
+        reader.read_exact(&mut len).map_err(SaveableError::other)?;
+
+        let mut bytes = vec![0; u32::from_le_bytes(len) as usize];
+        reader.read_exact(&mut bytes).map_err(SaveableError::other)?;
+
+        let segment = HeaderSegment {
+            header: rmp_serde::from_slice(&bytes).map_err(SaveableError::other)?,
+            version: u32::from_le_bytes(version),
+            format,
+            compression,
+            type_names,
+        };
+
+        Ok((segment, Box::new(reader).into()))
+    }
+}
@@ -10,59 +10,122 @@
 
 pub use bevy_save_erased_serde as erased_serde;
 
+#[cfg(feature = "bevy_asset")]
+pub use crate::asset_path::*;
 pub use crate::{
     app::*,
     applier::*,
+    autosave::*,
     backend::*,
     builder::*,
+    checkpoint::*,
     clone::*,
+    compare::*,
+    compression::*,
+    depth::*,
+    diff::*,
     dir::*,
+    dyn_box::*,
+    encryption::*,
     error::*,
+    extension::*,
+    fixed_time::*,
+    header::*,
     plugins::*,
+    post_load::*,
+    region::*,
     registry::*,
+    relative_time::*,
     rollbacks::*,
+    save_id::*,
     saver::*,
     serde::*,
+    session::*,
     snapshot::*,
+    spawn_tick::*,
+    state::*,
+    watcher::*,
     world::*,
 };
 
 mod app;
 mod applier;
+#[cfg(feature = "bevy_asset")]
+mod asset_path;
+mod autosave;
 mod backend;
 mod builder;
+mod checkpoint;
 mod clone;
+mod compare;
+mod compression;
+mod depth;
+mod diff;
 mod dir;
+mod dyn_box;
+mod encryption;
 mod entity;
 mod error;
+mod extension;
+mod fixed_time;
+mod header;
 mod plugins;
+mod post_load;
+mod region;
 mod registry;
+mod relative_time;
 mod rollbacks;
+mod save_id;
 mod saver;
 mod serde;
+mod session;
 mod snapshot;
+mod spawn_tick;
+mod state;
+mod watcher;
 mod world;
 
 /// Prelude: convenient import for all the user-facing APIs provided by the crate
 pub mod prelude {
+    #[cfg(feature = "bevy_asset")]
+    pub use crate::asset_path::*;
     pub use crate::{
         app::*,
         applier::*,
+        autosave::*,
         backend::*,
         builder::*,
+        checkpoint::*,
         clone::*,
+        compare::*,
+        compression::*,
+        depth::*,
+        diff::*,
         dir::*,
+        dyn_box::*,
+        encryption::*,
         erased_serde::{
             IntoDeserializer,
             IntoSerializer,
         },
         error::*,
+        extension::*,
+        fixed_time::*,
+        header::*,
         plugins::*,
+        post_load::*,
+        region::*,
         registry::*,
+        relative_time::*,
         rollbacks::*,
+        save_id::*,
         saver::*,
         serde::*,
+        session::*,
         snapshot::*,
+        spawn_tick::*,
+        state::*,
+        watcher::*,
         world::*,
     };
 }
@@ -1,6 +1,11 @@
+use std::ops::Range;
+
 use bevy::{
     app::PluginGroupBuilder,
     prelude::*,
+    time::Stopwatch,
+    transform::TransformSystem,
+    utils::Duration,
 };
 
 use crate::prelude::*;
@@ -13,7 +18,7 @@ impl PluginGroup for SavePlugins {
         PluginGroupBuilder::start::<Self>()
             .add(SavePlugin)
             .add(SaverPlugin)
-            .add(SaveablesPlugin)
+            .add(SaveablesPlugin::default())
     }
 }
 
@@ -25,7 +30,15 @@ impl Plugin for SavePlugin {
     fn build(&self, app: &mut App) {
         app
             .init_resource::<SaveableRegistry>()
-            .init_resource::<Rollbacks>();
+            .init_resource::<Rollbacks>()
+            .register_type::<SpawnTick>()
+            .add_event::<LoadCompleted>()
+            .add_event::<LoadFailed>()
+            .add_event::<RequestCheckpoint>()
+            .init_schedule(PostLoad)
+            .configure_set(PostUpdate, CheckpointSet.after(TransformSystem::TransformPropagate))
+            .add_systems(PreUpdate, apply_load_session.run_if(resource_exists::<LoadSession>()))
+            .add_systems(PostUpdate, checkpoint_system.run_if(checkpoint_requested).in_set(CheckpointSet));
     }
 }
 
@@ -37,40 +50,382 @@ impl Plugin for SaverPlugin {
     fn build(&self, app: &mut App) {
         app
             .init_resource::<AppBackend>()
+            .init_resource::<AppCompression>()
+            .init_resource::<AppSaveVersion>()
+            .init_resource::<AppTypeNames>()
+            .init_resource::<AppSelfCheck>()
+            .init_resource::<AppSaveExtensions>()
+            .init_resource::<AppEntityRefs>()
             .init_resource::<AppSaver>()
             .init_resource::<AppLoader>();
     }
 }
 
 /// Saveable registrations for common types.
-pub struct SaveablesPlugin;
+///
+/// [`Name`], [`SaveId`], [`Transform`] and [`GlobalTransform`] are always registered.
+/// Common value types held by components, such as [`Timer`], [`Stopwatch`] and [`Range<f32>`], are registered as well,
+/// so components containing them can be deserialized without registering their fields by hand.
+/// Each optional integration is enabled by default, and only registers its types if the matching feature is enabled.
+///
+/// # Examples
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// # let world = &mut app.world;
+/// let entity = world.spawn(Name::new("Player")).id();
+/// let snapshot = Snapshot::from_world(world);
+///
+/// world.clear_entities();
+///
+/// let map = snapshot.applier(world).apply_and_return_map().unwrap();
+/// let entity = map.get(Entity::from_raw(entity.index())).unwrap();
+///
+/// assert_eq!(world.get::<Name>(entity).unwrap().as_str(), "Player");
+/// assert_eq!(world.get::<Name>(entity), Some(&Name::new("Player")));
+/// ```
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// let mut app = App::new();
+///
+/// app.add_plugins(MinimalPlugins)
+///     .add_plugins(SavePlugins.build().set(SaveablesPlugin::default().sprite(false).tilemap(false)));
+///
+/// let saveables = app.world.resource::<SaveableRegistry>();
+///
+/// assert!(saveables.contains(std::any::type_name::<Transform>()));
+/// assert!(!saveables.contains(std::any::type_name::<Sprite>()));
+///
+/// #[cfg(feature = "bevy_ecs_tilemap")]
+/// assert!(!saveables.contains(std::any::type_name::<bevy_ecs_tilemap::prelude::TilePos>()));
+/// ```
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// # use serde::{de::DeserializeSeed, Serialize};
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// # let world = &mut app.world;
+/// #[cfg(feature = "bevy_render")]
+/// {
+///     use bevy::render::camera::ScalingMode;
+///
+///     let projection = OrthographicProjection {
+///         scale: 2.5,
+///         scaling_mode: ScalingMode::FixedVertical(10.0),
+///         ..default()
+///     };
+///
+///     world.spawn((Camera { order: 1, ..default() }, projection, Transform::from_xyz(4.0, 2.0, 0.0)));
+///
+///     let snapshot = Snapshot::from_world(world);
+///
+///     let registry = world.resource::<AppTypeRegistry>().read();
+///     let mut bytes = Vec::new();
+///     SnapshotSerializer::new(&snapshot, &*registry)
+///         .serialize(&mut rmp_serde::Serializer::new(&mut bytes))
+///         .unwrap();
+///
+///     let loaded = SnapshotDeserializer::new(&registry)
+///         .deserialize(&mut rmp_serde::Deserializer::new(&bytes[..]))
+///         .unwrap();
+///     drop(registry);
+///
+///     world.clear_entities();
+///     loaded.apply(world).unwrap();
+///
+///     let (camera, projection, transform) = world
+///         .query::<(&Camera, &OrthographicProjection, &Transform)>()
+///         .single(world);
+///
+///     assert_eq!(camera.order, 1);
+///     assert_eq!(projection.scale, 2.5);
+///     assert!(matches!(projection.scaling_mode, ScalingMode::FixedVertical(height) if height == 10.0));
+///     assert_eq!(transform.translation, Vec3::new(4.0, 2.0, 0.0));
+///
+///     world.clear_entities();
+/// }
+///
+/// #[cfg(feature = "bevy_ui")]
+/// {
+///     let style = Style {
+///         width: Val::Px(120.0),
+///         padding: UiRect::all(Val::Percent(5.0)),
+///         aspect_ratio: Some(2.0),
+///         grid_template_columns: RepeatedGridTrack::flex::<Vec<_>>(3, 1.0),
+///         ..default()
+///     };
+///
+///     world.spawn((Node::default(), style, BackgroundColor(Color::RED)));
+///
+///     let snapshot = Snapshot::from_world(world);
+///
+///     let registry = world.resource::<AppTypeRegistry>().read();
+///     let mut json = Vec::new();
+///     SnapshotSerializer::new(&snapshot, &*registry)
+///         .serialize(&mut serde_json::Serializer::new(&mut json))
+///         .unwrap();
+///
+///     let loaded = SnapshotDeserializer::new(&registry)
+///         .deserialize(&mut serde_json::Deserializer::from_slice(&json))
+///         .unwrap();
+///     drop(registry);
+///
+///     world.clear_entities();
+///     loaded.apply(world).unwrap();
+///
+///     let (style, color) = world
+///         .query_filtered::<(&Style, &BackgroundColor), With<Node>>()
+///         .single(world);
+///
+///     assert_eq!(style.width, Val::Px(120.0));
+///     assert_eq!(style.padding.left, Val::Percent(5.0));
+///     assert_eq!(style.aspect_ratio, Some(2.0));
+///     assert_eq!(style.grid_template_columns, RepeatedGridTrack::flex::<Vec<_>>(3, 1.0));
+///     assert_eq!(color.0, Color::RED);
+/// }
+/// ```
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// # use serde::{de::DeserializeSeed, Serialize};
+/// #[derive(Component, Reflect, Default)]
+/// #[reflect(Component)]
+/// struct Cooldown {
+///     timer: Timer,
+///     spread: std::ops::Range<f32>,
+/// }
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// app.register_saveable::<Cooldown>();
+///
+/// let world = &mut app.world;
+///
+/// let mut timer = Timer::from_seconds(2.0, TimerMode::Repeating);
+/// timer.tick(bevy::utils::Duration::from_millis(500));
+///
+/// world.spawn(Cooldown { timer, spread: 0.5..1.5 });
+///
+/// let snapshot = Snapshot::from_world(world);
+///
+/// let registry = world.resource::<AppTypeRegistry>().read();
+/// let mut json = Vec::new();
+/// SnapshotSerializer::new(&snapshot, &*registry)
+///     .serialize(&mut serde_json::Serializer::new(&mut json))
+///     .unwrap();
+///
+/// let loaded = SnapshotDeserializer::new(&registry)
+///     .deserialize(&mut serde_json::Deserializer::from_slice(&json))
+///     .unwrap();
+/// drop(registry);
+///
+/// world.clear_entities();
+/// loaded.apply(world).unwrap();
+///
+/// let cooldown = world.query::<&Cooldown>().single(world);
+///
+/// assert_eq!(cooldown.timer.mode(), TimerMode::Repeating);
+/// assert_eq!(cooldown.timer.elapsed_secs(), 0.5);
+/// assert_eq!(cooldown.spread, 0.5..1.5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct SaveablesPlugin {
+    #[cfg_attr(not(feature = "bevy_render"), allow(dead_code))]
+    render: bool,
+
+    #[cfg_attr(not(feature = "bevy_sprite"), allow(dead_code))]
+    sprite: bool,
+
+    #[cfg_attr(not(feature = "bevy_text"), allow(dead_code))]
+    text: bool,
+
+    #[cfg_attr(not(feature = "bevy_ui"), allow(dead_code))]
+    ui: bool,
+
+    #[cfg_attr(not(feature = "bevy_ecs_tilemap"), allow(dead_code))]
+    tilemap: bool,
+}
+
+impl Default for SaveablesPlugin {
+    fn default() -> Self {
+        Self {
+            render: true,
+            sprite: true,
+            text: true,
+            ui: true,
+            tilemap: true,
+        }
+    }
+}
+
+impl SaveablesPlugin {
+    /// Set whether `bevy_render` types such as [`Visibility`] and [`Camera`] are registered as saveable.
+    ///
+    /// This includes the camera projections. The render target of a [`Camera`] is not reflected,
+    /// so cameras are restored rendering to the primary window.
+    pub fn render(mut self, enabled: bool) -> Self {
+        self.render = enabled;
+        self
+    }
+
+    /// Set whether `bevy_sprite` types such as [`Sprite`] are registered as saveable.
+    pub fn sprite(mut self, enabled: bool) -> Self {
+        self.sprite = enabled;
+        self
+    }
+
+    /// Set whether `bevy_text` types such as [`Text`] are registered as saveable.
+    pub fn text(mut self, enabled: bool) -> Self {
+        self.text = enabled;
+        self
+    }
+
+    /// Set whether `bevy_ui` types such as [`Node`] and [`Style`] are registered as saveable.
+    pub fn ui(mut self, enabled: bool) -> Self {
+        self.ui = enabled;
+        self
+    }
+
+    /// Set whether `bevy_ecs_tilemap` types are registered as saveable.
+    pub fn tilemap(mut self, enabled: bool) -> Self {
+        self.tilemap = enabled;
+        self
+    }
+}
 
 #[rustfmt::skip]
 impl Plugin for SaveablesPlugin {
     fn build(&self, app: &mut App) {
         app
             .register_saveable::<GlobalTransform>()
-            .register_saveable::<Transform>();
+            .register_saveable::<Name>()
+            .register_saveable::<SaveId>()
+            .register_saveable::<Transform>()
+            .register_coupled_types::<Transform, GlobalTransform>()
+
+            // Common value types held by saveable components
+            .register_type::<Duration>()
+            .register_type::<Option<Duration>>()
+            .register_type::<Range<f32>>()
+            .register_type::<Range<u32>>()
+            .register_type::<Range<usize>>()
+            .register_type::<Stopwatch>()
+            .register_type::<Timer>()
+            .register_type::<TimerMode>();
         
         #[cfg(feature = "bevy_render")]
-        app
-            .register_saveable::<Visibility>();
+        if self.render {
+            use bevy::render::camera::{
+                ScalingMode,
+                Viewport,
+            };
+
+            app
+                .register_saveable::<Camera>()
+                .register_saveable::<OrthographicProjection>()
+                .register_saveable::<PerspectiveProjection>()
+                .register_saveable::<Projection>()
+                .register_saveable::<Visibility>()
+
+                // Normally registered by `CameraPlugin`
+                .register_type::<Option<Viewport>>()
+                .register_type::<ScalingMode>()
+                .register_type::<Viewport>();
+        }
 
         #[cfg(all(feature = "bevy_render", feature = "bevy_asset"))]
-        app
-            .register_saveable::<Handle<Image>>();
+        if self.render {
+            app
+                .register_saveable::<Handle<Image>>();
+        }
 
         #[cfg(feature = "bevy_sprite")]
-        app
-            .register_saveable::<Sprite>()
+        if self.sprite {
+            app
+                .register_saveable::<Sprite>()
+
+                // Fix `bevy_reflect: Add ReflectComponent registration for Sprite #8206`
+                .register_type_data::<Sprite, ReflectComponent>()
+                .register_type::<Option<Vec2>>()
+                .register_type::<Option<Rect>>();
+        }
+
+        #[cfg(feature = "bevy_text")]
+        if self.text {
+            use bevy::text::BreakLineOn;
+
+            app
+                .register_saveable::<Text>()
+
+                // Normally registered by `TextPlugin` and `RenderPlugin`
+                .register_type::<BreakLineOn>()
+                .register_type::<Color>()
+                .register_type::<TextAlignment>()
+                .register_type::<TextSection>()
+                .register_type::<TextStyle>()
+                .register_type::<Vec<TextSection>>();
+        }
 
-            // Fix `bevy_reflect: Add ReflectComponent registration for Sprite #8206`
-            .register_type_data::<Sprite, ReflectComponent>()
-            .register_type::<Option<Vec2>>()
-            .register_type::<Option<Rect>>();
+        #[cfg(feature = "bevy_ui")]
+        if self.ui {
+            use bevy::ui::{
+                GridAutoFlow,
+                GridPlacement,
+                GridTrack,
+                GridTrackRepetition,
+                MaxTrackSizingFunction,
+                MinTrackSizingFunction,
+                OverflowAxis,
+                RepeatedGridTrack,
+            };
+
+            app
+                .register_saveable::<BackgroundColor>()
+                .register_saveable::<Node>()
+                .register_saveable::<Style>()
+
+                // Normally registered by `UiPlugin` and `RenderPlugin`
+                .register_type::<AlignContent>()
+                .register_type::<AlignItems>()
+                .register_type::<AlignSelf>()
+                .register_type::<Color>()
+                .register_type::<Direction>()
+                .register_type::<Display>()
+                .register_type::<FlexDirection>()
+                .register_type::<FlexWrap>()
+                .register_type::<GridAutoFlow>()
+                .register_type::<GridPlacement>()
+                .register_type::<GridTrack>()
+                .register_type::<GridTrackRepetition>()
+                .register_type::<JustifyContent>()
+                .register_type::<JustifyItems>()
+                .register_type::<JustifySelf>()
+                .register_type::<MaxTrackSizingFunction>()
+                .register_type::<MinTrackSizingFunction>()
+                .register_type::<Option<f32>>()
+                .register_type::<Overflow>()
+                .register_type::<OverflowAxis>()
+                .register_type::<PositionType>()
+                .register_type::<RepeatedGridTrack>()
+                .register_type::<UiRect>()
+                .register_type::<Val>()
+                .register_type::<Vec<GridTrack>>()
+                .register_type::<Vec<RepeatedGridTrack>>();
+        }
 
         #[cfg(feature = "bevy_ecs_tilemap")]
-        {
+        if self.tilemap {
             use bevy_ecs_tilemap::{
                 FrustumCulling,
                 prelude::*
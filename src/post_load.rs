@@ -0,0 +1,9 @@
+use bevy::ecs::schedule::ScheduleLabel;
+
+/// The schedule run once by the [`Applier`](crate::Applier) after it finishes applying a snapshot or rollback.
+///
+/// Add systems to it with [`AppSaveableExt::add_post_load_system`](crate::AppSaveableExt::add_post_load_system),
+/// e.g. to rebuild spatial indices or caches derived from the loaded state.
+/// It runs after the [`Hook`](crate::Hook), once per apply, including after a [`LoadSession`](crate::LoadSession) completes.
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PostLoad;
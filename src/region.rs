@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+
+/// A spatial region used by [`Snapshot::from_world_in_region`](crate::Snapshot::from_world_in_region).
+///
+/// Implemented for [`Rect`], which checks the `x` and `y` coordinates,
+/// and for [`Aabb`](bevy::render::primitives::Aabb) with the `bevy_render` feature.
+pub trait Region {
+    /// Returns true if the point lies inside the region, including its boundary.
+    fn contains(&self, point: Vec3) -> bool;
+}
+
+impl Region for Rect {
+    fn contains(&self, point: Vec3) -> bool {
+        Rect::contains(self, point.truncate())
+    }
+}
+
+#[cfg(feature = "bevy_render")]
+impl Region for bevy::render::primitives::Aabb {
+    fn contains(&self, point: Vec3) -> bool {
+        (bevy::math::Vec3A::from(point) - self.center).abs().cmple(self.half_extents).all()
+    }
+}
@@ -1,16 +1,29 @@
-use std::collections::HashMap;
+use std::{
+    any::TypeId,
+    collections::{
+        BTreeSet,
+        HashMap,
+        HashSet,
+    },
+};
 
 use bevy::{
     prelude::*,
-    reflect::GetTypeRegistration,
+    reflect::{
+        GetTypeRegistration,
+        TypeInfo,
+        TypeRegistryInternal,
+        VariantInfo,
+    },
 };
 
 /// The global registry of types that should be tracked by `bevy_save`.
 /// 
 /// Only types that are registered in here and [`AppTypeRegistry`] are included in save/load and rollback.
-#[derive(Resource, Default)]
+#[derive(Resource, Default, Clone)]
 pub struct SaveableRegistry {
     types: HashMap<String, bool>,
+    coupled: Vec<(String, String)>,
 }
 
 impl SaveableRegistry {
@@ -20,6 +33,48 @@ impl SaveableRegistry {
         self.types.insert(type_reg.type_name().into(), true);
     }
 
+    /// Remove a type from the registry, excluding it from saves and rollback.
+    ///
+    /// Any [`coupled`](Self::couple) pair that includes the type is removed as well.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let world = &mut app.world;
+    /// let entity = world.spawn(Transform::default()).id();
+    ///
+    /// world.resource_mut::<SaveableRegistry>().couple::<Transform, GlobalTransform>();
+    ///
+    /// let snapshot = Snapshot::from_world(world);
+    ///
+    /// world.entity_mut(entity).remove::<Transform>();
+    /// snapshot.apply(world).unwrap();
+    ///
+    /// // `Transform` was included in the first snapshot
+    /// assert!(world.entity(entity).contains::<Transform>());
+    ///
+    /// let mut registry = world.resource_mut::<SaveableRegistry>();
+    /// registry.unregister(std::any::type_name::<Transform>());
+    ///
+    /// assert_eq!(registry.coupled().count(), 0);
+    ///
+    /// let snapshot = Snapshot::from_world(world);
+    ///
+    /// world.entity_mut(entity).remove::<Transform>();
+    /// snapshot.apply(world).unwrap();
+    ///
+    /// // `Transform` was not included in the second snapshot
+    /// assert!(!world.entity(entity).contains::<Transform>());
+    /// ```
+    pub fn unregister(&mut self, type_name: &str) {
+        self.types.remove(type_name);
+        self.coupled.retain(|(a, b)| a != type_name && b != type_name);
+    }
+
     /// Exclude a type from rollback.
     ///
     /// The type is still included in saves.
@@ -57,4 +112,104 @@ impl SaveableRegistry {
     pub fn types(&self) -> impl Iterator<Item = &String> {
         self.types.keys()
     }
+
+    /// Couple two component types, so snapshots always include either both or neither of them.
+    pub fn couple<A: GetTypeRegistration, B: GetTypeRegistration>(&mut self) {
+        let a = A::get_type_registration().type_name().to_string();
+        let b = B::get_type_registration().type_name().to_string();
+
+        if !self.coupled.contains(&(a.clone(), b.clone())) {
+            self.coupled.push((a, b));
+        }
+    }
+
+    /// Returns an iterator over the type names of coupled component types.
+    pub fn coupled(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.coupled.iter().map(|(a, b)| (a.as_str(), b.as_str()))
+    }
+
+    /// Returns the sorted names of types reachable from the fields of saveable types
+    /// that are missing from the given [`TypeRegistryInternal`].
+    ///
+    /// Saveable types that are themselves missing from the registry are reported as well.
+    pub(crate) fn missing_registrations(&self, registry: &TypeRegistryInternal) -> Vec<String> {
+        let mut missing = BTreeSet::new();
+        let mut visited = HashSet::new();
+        let mut pending = Vec::new();
+
+        for name in self.types() {
+            match registry.get_with_name(name) {
+                Some(reg) => pending.push(reg.type_id()),
+                None => {
+                    missing.insert(name.clone());
+                }
+            }
+        }
+
+        while let Some(type_id) = pending.pop() {
+            if !visited.insert(type_id) {
+                continue;
+            }
+
+            let Some(reg) = registry.get(type_id) else {
+                continue;
+            };
+
+            for (field_id, field_name) in field_types(reg.type_info()) {
+                if registry.get(field_id).is_some() {
+                    pending.push(field_id);
+                } else {
+                    missing.insert(field_name.to_string());
+                }
+            }
+        }
+
+        missing.into_iter().collect()
+    }
+}
+
+/// Returns true if the type is [`Entity`] or contains an [`Entity`] anywhere in its registered fields.
+pub(crate) fn contains_entity(registry: &TypeRegistryInternal, type_id: TypeId) -> bool {
+    let mut visited = HashSet::new();
+    let mut pending = vec![type_id];
+
+    while let Some(type_id) = pending.pop() {
+        if type_id == TypeId::of::<Entity>() {
+            return true;
+        }
+
+        if !visited.insert(type_id) {
+            continue;
+        }
+
+        if let Some(reg) = registry.get(type_id) {
+            pending.extend(field_types(reg.type_info()).into_iter().map(|(id, _)| id));
+        }
+    }
+
+    false
+}
+
+/// Returns the types directly contained by a type.
+fn field_types(info: &TypeInfo) -> Vec<(TypeId, &'static str)> {
+    match info {
+        TypeInfo::Struct(info) => info.iter().map(|f| (f.type_id(), f.type_name())).collect(),
+        TypeInfo::TupleStruct(info) => info.iter().map(|f| (f.type_id(), f.type_name())).collect(),
+        TypeInfo::Tuple(info) => info.iter().map(|f| (f.type_id(), f.type_name())).collect(),
+        TypeInfo::List(info) => vec![(info.item_type_id(), info.item_type_name())],
+        TypeInfo::Array(info) => vec![(info.item_type_id(), info.item_type_name())],
+        TypeInfo::Map(info) => vec![
+            (info.key_type_id(), info.key_type_name()),
+            (info.value_type_id(), info.value_type_name()),
+        ],
+        TypeInfo::Enum(info) => info
+            .iter()
+            .flat_map(|variant| match variant {
+                VariantInfo::Struct(v) => v.iter().map(|f| (f.type_id(), f.type_name())).collect(),
+                VariantInfo::Tuple(v) => v.iter().map(|f| (f.type_id(), f.type_name())).collect(),
+                VariantInfo::Unit(_) => Vec::new(),
+            })
+            .collect(),
+        TypeInfo::Value(_) => Vec::new(),
+    }
 }
@@ -0,0 +1,145 @@
+use std::any::TypeId;
+
+use bevy::{
+    prelude::*,
+    reflect::{
+        DynamicStruct,
+        ReflectMut,
+        TypeInfo,
+    },
+    utils::{
+        Duration,
+        Instant,
+    },
+};
+
+/// Type data listing the reflect paths of time fields that are saved relative to the current time.
+///
+/// Register with [`AppSaveableExt::register_relative_time`](crate::AppSaveableExt::register_relative_time).
+/// Paths are dot-separated struct field names, such as `cooldown.ready_at`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RelativeTime(Vec<String>);
+
+impl RelativeTime {
+    /// Create a new [`RelativeTime`] for the given reflect paths.
+    pub fn new<S: Into<String>>(paths: impl IntoIterator<Item = S>) -> Self {
+        Self(paths.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns the reflect paths of the relative time fields.
+    pub fn paths(&self) -> &[String] {
+        &self.0
+    }
+
+    pub(crate) fn relative(&self) -> Vec<&str> {
+        self.0.iter().map(String::as_str).collect()
+    }
+
+    pub(crate) fn push(&mut self, path: &str) {
+        if !self.0.iter().any(|p| p == path) {
+            self.0.push(path.to_string());
+        }
+    }
+}
+
+/// The current time, used to convert time fields to and from their saved relative form.
+#[derive(Clone, Copy)]
+pub(crate) struct Clock {
+    now: Instant,
+    elapsed: Duration,
+}
+
+impl Clock {
+    pub(crate) fn new(world: &World) -> Self {
+        Self {
+            now: Instant::now(),
+            elapsed: world
+                .get_resource::<Time>()
+                .map_or(Duration::ZERO, Time::elapsed),
+        }
+    }
+
+    /// Replace each time field of a captured value with the [`Duration`] remaining until it.
+    ///
+    /// Times in the past are saved as [`Duration::ZERO`].
+    pub(crate) fn relativize(&self, value: &mut dyn Reflect, time: &RelativeTime) {
+        for path in time.paths() {
+            let Some(field) = field_at(value, path) else {
+                continue;
+            };
+
+            let remaining = if let Some(instant) = field.downcast_ref::<Instant>() {
+                instant.saturating_duration_since(self.now)
+            } else if let Some(duration) = field.downcast_ref::<Duration>() {
+                duration.saturating_sub(self.elapsed)
+            } else {
+                continue;
+            };
+
+            replace(value, path, Box::new(remaining));
+        }
+    }
+
+    /// Rebuild each time field of a saved value from the [`Duration`] remaining until it.
+    pub(crate) fn restore(&self, value: &dyn Reflect, time: &RelativeTime) -> Box<dyn Reflect> {
+        let mut value = value.clone_value();
+
+        for path in time.paths() {
+            let remaining = field_at(&mut *value, path).and_then(|f| f.downcast_ref::<Duration>());
+
+            let Some(&remaining) = remaining else {
+                continue;
+            };
+
+            let restored: Box<dyn Reflect> =
+                if field_type(&mut *value, path) == Some(TypeId::of::<Instant>()) {
+                    Box::new(self.now + remaining)
+                } else {
+                    Box::new(self.elapsed + remaining)
+                };
+
+            replace(&mut *value, path, restored);
+        }
+
+        value
+    }
+}
+
+/// Returns the struct field at the dot-separated path.
+fn field_at<'a>(value: &'a mut dyn Reflect, path: &str) -> Option<&'a mut dyn Reflect> {
+    path.split('.').try_fold(value, |value, name| match value.reflect_mut() {
+        ReflectMut::Struct(value) => value.field_mut(name),
+        _ => None,
+    })
+}
+
+/// Returns the declared type of the struct field at the dot-separated path.
+fn field_type(value: &mut dyn Reflect, path: &str) -> Option<TypeId> {
+    let (parent, name) = split(value, path)?;
+
+    let Some(TypeInfo::Struct(info)) = parent.get_represented_type_info() else {
+        return None;
+    };
+
+    info.field(name).map(|field| field.type_id())
+}
+
+/// Replace the struct field at the dot-separated path, which may change its type.
+fn replace(value: &mut dyn Reflect, path: &str, field: Box<dyn Reflect>) {
+    if let Some((parent, name)) = split(value, path) {
+        parent.insert_boxed(name, field);
+    }
+}
+
+/// Returns the dynamic struct containing the field at the dot-separated path, and the name of that field.
+fn split<'a, 'p>(
+    value: &'a mut dyn Reflect,
+    path: &'p str,
+) -> Option<(&'a mut DynamicStruct, &'p str)> {
+    let (parent, name) = match path.rsplit_once('.') {
+        Some((parent, name)) => (field_at(value, parent)?, name),
+        None => (value, path),
+    };
+
+    Some((parent.downcast_mut::<DynamicStruct>()?, name))
+}
@@ -1,12 +1,29 @@
-use bevy::prelude::*;
+use std::collections::BTreeSet;
+
+use bevy::{
+    ecs::component::{
+        ComponentId,
+        ComponentTicks,
+        Tick,
+    },
+    prelude::*,
+};
 
 use crate::prelude::*;
 
 /// The global registry of snapshots used for rollback / rollforward.
+///
+/// Checkpoints form a tree: each checkpoint records the checkpoint that was active when it was created as its parent.
+/// [`Rollbacks::checkpoint`] keeps a linear history by erasing the redo branch,
+/// while [`Rollbacks::checkpoint_branch`] keeps it and starts a new branch.
 #[derive(Resource, Default)]
 pub struct Rollbacks {
     pub(crate) checkpoints: Vec<Rollback>,
+    pub(crate) parents: Vec<Option<usize>>,
     pub(crate) active: Option<usize>,
+    pub(crate) baseline: Option<ChangeBaseline>,
+    pub(crate) pinned: BTreeSet<usize>,
+    pub(crate) capacity: Option<usize>,
 }
 
 impl Rollbacks {
@@ -15,36 +32,478 @@ impl Rollbacks {
         self.checkpoints.is_empty()
     }
 
+    /// Returns the number of checkpoints.
+    pub fn len(&self) -> usize {
+        self.checkpoints.len()
+    }
+
+    /// Returns the index of the active checkpoint, if any checkpoints have been created.
+    ///
+    /// The active checkpoint is stored alongside the checkpoints when [`Rollbacks`] are serialized,
+    /// so the undo / redo position survives a save and load.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let mut other = App::new();
+    /// # other.add_plugins(MinimalPlugins);
+    /// # other.add_plugins(SavePlugins);
+    /// # let world = &mut app.world;
+    /// for _ in 0..3 {
+    ///     world.checkpoint();
+    /// }
+    ///
+    /// world.rollback(1).unwrap();
+    ///
+    /// let mut save = Vec::new();
+    /// world.serialize(&mut serde_json::Serializer::new(&mut save)).unwrap();
+    ///
+    /// let world = &mut other.world;
+    /// world.deserialize(&mut serde_json::Deserializer::from_slice(&save)).unwrap();
+    ///
+    /// let rollbacks = world.resource::<Rollbacks>();
+    ///
+    /// assert_eq!(rollbacks.len(), 3);
+    /// assert_eq!(rollbacks.active(), Some(1));
+    /// assert!(rollbacks.can_rollforward());
+    /// ```
+    pub fn active(&self) -> Option<usize> {
+        self.active
+    }
+
+    /// Returns true if there is an older checkpoint to roll back to.
+    pub fn can_rollback(&self) -> bool {
+        self.active.is_some_and(|active| self.parent(active).is_some())
+    }
+
+    /// Returns true if there is a newer checkpoint to roll forward to.
+    pub fn can_rollforward(&self) -> bool {
+        self.active
+            .is_some_and(|active| self.children(active).next().is_some())
+    }
+
+    /// Returns the index of the checkpoint that was active when the checkpoint at the given index was created.
+    ///
+    /// Returns `None` for the first checkpoint, or if there is no checkpoint at the index.
+    pub fn parent(&self, index: usize) -> Option<usize> {
+        self.parents.get(index).copied().flatten()
+    }
+
+    /// Returns the indices of the checkpoints created from the checkpoint at the given index, oldest first.
+    ///
+    /// A checkpoint has several children if branches were created from it with [`Rollbacks::checkpoint_branch`].
+    pub fn children(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        self.parents
+            .iter()
+            .enumerate()
+            .filter(move |(_, &parent)| parent == Some(index))
+            .map(|(child, _)| child)
+    }
+
+    /// Returns the indices of the checkpoints without children, the tip of every branch, oldest first.
+    pub fn leaves(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.checkpoints.len()).filter(|&index| self.children(index).next().is_none())
+    }
+
+    /// Returns the indices of the checkpoints from the first checkpoint of the branch to the given index.
+    ///
+    /// Returns an empty list if there is no checkpoint at the index.
+    pub fn branch(&self, index: usize) -> Vec<usize> {
+        if index >= self.checkpoints.len() {
+            return Vec::new();
+        }
+
+        let mut branch = vec![index];
+
+        while let Some(parent) = self.parent(branch[branch.len() - 1]) {
+            branch.push(parent);
+        }
+
+        branch.reverse();
+        branch
+    }
+
+    /// Returns the maximum number of checkpoints kept, if the history is bounded.
+    pub fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+
+    /// Bound the number of checkpoints kept, or remove the bound with `None`.
+    ///
+    /// When a checkpoint is inserted past the capacity, the oldest checkpoint that is neither pinned nor active is dropped.
+    /// The capacity is kept when the [`Rollbacks`] are replaced by loading a [`Snapshot`].
+    pub fn set_capacity(&mut self, capacity: Option<usize>) {
+        self.capacity = capacity;
+        self.evict();
+    }
+
+    /// Pin the checkpoint at the given index, so it is never dropped to stay within the capacity.
+    ///
+    /// Indices of later checkpoints shift down as older ones are dropped, the pin follows its checkpoint.
+    /// Returns false if there is no checkpoint at the index.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Resource, Reflect, Default)]
+    /// #[reflect(Resource)]
+    /// struct Frame(u32);
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// app.register_saveable::<Frame>();
+    ///
+    /// let world = &mut app.world;
+    /// world.resource_mut::<Rollbacks>().set_capacity(Some(3));
+    ///
+    /// // The level start
+    /// world.insert_resource(Frame(0));
+    /// world.checkpoint();
+    /// world.resource_mut::<Rollbacks>().pin(0);
+    ///
+    /// for frame in 1..=5 {
+    ///     world.insert_resource(Frame(frame));
+    ///     world.checkpoint();
+    /// }
+    ///
+    /// let rollbacks = world.resource::<Rollbacks>();
+    ///
+    /// assert_eq!(rollbacks.len(), 3);
+    /// assert!(rollbacks.is_pinned(0));
+    /// assert_eq!(rollbacks.active(), Some(2));
+    ///
+    /// // Only the pinned checkpoint and the two latest remain
+    /// world.rollback(2).unwrap();
+    /// assert_eq!(world.resource::<Frame>().0, 0);
+    ///
+    /// world.rollback(-1).unwrap();
+    /// assert_eq!(world.resource::<Frame>().0, 4);
+    /// ```
+    pub fn pin(&mut self, index: usize) -> bool {
+        if index >= self.checkpoints.len() {
+            return false;
+        }
+
+        self.pinned.insert(index);
+
+        true
+    }
+
+    /// Unpin the checkpoint at the given index, which may immediately drop checkpoints past the capacity.
+    ///
+    /// Returns false if the checkpoint was not pinned.
+    pub fn unpin(&mut self, index: usize) -> bool {
+        let unpinned = self.pinned.remove(&index);
+
+        self.evict();
+
+        unpinned
+    }
+
+    /// Returns true if the checkpoint at the given index is pinned.
+    pub fn is_pinned(&self, index: usize) -> bool {
+        self.pinned.contains(&index)
+    }
+
     /// Given a new [`Rollback`], insert it and set it as the currently active rollback.
     ///
     /// If you rollback and then insert a checkpoint, it will erase all rollforward snapshots.
+    /// If this exceeds the [capacity](Self::set_capacity), the oldest unpinned checkpoint is dropped.
     pub fn checkpoint(&mut self, rollback: Rollback) {
-        let active = self.active.unwrap_or(0);
+        if let Some(active) = self.active {
+            for index in self.descendants(active).into_iter().rev() {
+                self.remove(index);
+            }
+        }
+
+        self.checkpoint_branch(rollback);
+    }
+
+    /// Given a new [`Rollback`], insert it as a child of the active checkpoint and set it as the active rollback.
+    ///
+    /// Unlike [`Rollbacks::checkpoint`], rollforward snapshots are kept on their own branch,
+    /// which can be restored later with [`Rollbacks::switch_branch`].
+    /// Rolling forward follows the most recently created branch.
+    ///
+    /// Returns the index of the new checkpoint.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Resource, Reflect, Default)]
+    /// #[reflect(Resource)]
+    /// struct Tool(String);
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let mut other = App::new();
+    /// # other.add_plugins(MinimalPlugins);
+    /// # other.add_plugins(SavePlugins);
+    /// app.register_saveable::<Tool>();
+    /// other.register_saveable::<Tool>();
+    ///
+    /// let world = &mut app.world;
+    ///
+    /// world.insert_resource(Tool("base".into()));
+    /// let base = world.checkpoint_branch();
+    ///
+    /// world.insert_resource(Tool("brush".into()));
+    /// let brush = world.checkpoint_branch();
+    ///
+    /// // Undo, then make a different change
+    /// world.rollback(1).unwrap();
+    /// world.insert_resource(Tool("eraser".into()));
+    /// let eraser = world.checkpoint_branch();
+    ///
+    /// let rollbacks = world.resource::<Rollbacks>();
+    ///
+    /// assert_eq!(rollbacks.parent(brush), Some(base));
+    /// assert_eq!(rollbacks.parent(eraser), Some(base));
+    /// assert_eq!(rollbacks.children(base).collect::<Vec<_>>(), vec![brush, eraser]);
+    /// assert_eq!(rollbacks.leaves().collect::<Vec<_>>(), vec![brush, eraser]);
+    /// assert_eq!(rollbacks.branch(eraser), vec![base, eraser]);
+    ///
+    /// // Both branches are kept
+    /// world.switch_branch(brush).unwrap();
+    /// assert_eq!(world.resource::<Tool>().0, "brush");
+    ///
+    /// world.switch_branch(eraser).unwrap();
+    /// assert_eq!(world.resource::<Tool>().0, "eraser");
+    ///
+    /// world.rollback(1).unwrap();
+    /// assert_eq!(world.resource::<Tool>().0, "base");
+    ///
+    /// // Rolling forward follows the newest branch
+    /// world.rollback(-1).unwrap();
+    /// assert_eq!(world.resource::<Tool>().0, "eraser");
+    ///
+    /// // The tree survives a save and load
+    /// let mut save = Vec::new();
+    /// world.serialize(&mut serde_json::Serializer::new(&mut save)).unwrap();
+    ///
+    /// let world = &mut other.world;
+    /// world.deserialize(&mut serde_json::Deserializer::from_slice(&save)).unwrap();
+    ///
+    /// let rollbacks = world.resource::<Rollbacks>();
+    /// assert_eq!(rollbacks.children(base).collect::<Vec<_>>(), vec![brush, eraser]);
+    /// ```
+    pub fn checkpoint_branch(&mut self, rollback: Rollback) -> usize {
+        self.baseline = None;
 
-        self.checkpoints.truncate(active + 1);
         self.checkpoints.push(rollback);
+        self.parents.push(self.active);
 
         self.active = Some(self.checkpoints.len() - 1);
+
+        self.evict();
+
+        self.checkpoints.len() - 1
+    }
+
+    /// Set the checkpoint at the given index as the active checkpoint, returning it.
+    ///
+    /// Returns `None` if there is no checkpoint at the index.
+    /// See [`Rollbacks::checkpoint_branch`] for an example.
+    pub fn switch_branch(&mut self, index: usize) -> Option<&Rollback> {
+        let rollback = self.checkpoints.get(index)?;
+
+        self.active = Some(index);
+
+        Some(rollback)
+    }
+
+    /// Returns the indices of every checkpoint created from the checkpoint at the given index, in index order.
+    fn descendants(&self, index: usize) -> Vec<usize> {
+        let mut descendants = Vec::new();
+
+        for child in index + 1..self.parents.len() {
+            let parent = self.parents[child];
+
+            if parent.is_some_and(|parent| parent == index || descendants.contains(&parent)) {
+                descendants.push(child);
+            }
+        }
+
+        descendants
+    }
+
+    /// Remove the checkpoint at the given index, which must not be active.
+    ///
+    /// Its children are reparented to its own parent, and later indices shift down.
+    fn remove(&mut self, index: usize) {
+        let parent = self.parents[index];
+
+        self.checkpoints.remove(index);
+        self.parents.remove(index);
+
+        let shift = |i: usize| if i > index { i - 1 } else { i };
+
+        for child in &mut self.parents {
+            if *child == Some(index) {
+                *child = parent;
+            }
+
+            *child = child.map(shift);
+        }
+
+        self.pinned = self
+            .pinned
+            .iter()
+            .filter(|&&pinned| pinned != index)
+            .map(|&pinned| shift(pinned))
+            .collect();
+
+        self.active = self.active.map(shift);
+    }
+
+    /// Returns true if every checkpoint is the child of the one before it.
+    pub(crate) fn is_linear(&self) -> bool {
+        self.parents
+            .iter()
+            .enumerate()
+            .all(|(index, &parent)| parent == index.checked_sub(1))
+    }
+
+    /// Drop the oldest checkpoints that are neither pinned nor active until the capacity is met.
+    fn evict(&mut self) {
+        let Some(capacity) = self.capacity else {
+            return;
+        };
+
+        while self.checkpoints.len() > capacity {
+            let index = (0..self.checkpoints.len())
+                .find(|&index| !self.pinned.contains(&index) && Some(index) != self.active);
+
+            let Some(index) = index else {
+                return;
+            };
+
+            self.remove(index);
+        }
+    }
+
+    /// Insert a clone of these [`Rollbacks`] into the [`World`], keeping the capacity of the current ones.
+    pub(crate) fn insert_into(&self, world: &mut World) {
+        let mut rollbacks = self.clone_value();
+
+        rollbacks.capacity = world.get_resource::<Self>().and_then(Self::capacity);
+        rollbacks.evict();
+
+        world.insert_resource(rollbacks);
+    }
+
+    /// Insert a [`Rollback`] of the current [`World`] state, unless no rollback state changed since the last checkpoint.
+    ///
+    /// Changes are detected with Bevy's change ticks and by counting entities and rollback components,
+    /// without extracting a [`Rollback`], so this is cheap to call every frame while the game is paused.
+    /// The checkpoint is always inserted if the previous one was not created by this method or [`World::checkpoint`](crate::WorldSaveableExt::checkpoint).
+    ///
+    /// Returns true if a checkpoint was inserted.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let world = &mut app.world;
+    /// let entity = world.spawn(Transform::default()).id();
+    ///
+    /// world.checkpoint();
+    ///
+    /// let checkpoint = |world: &mut World| {
+    ///     world.resource_scope(|world, mut rollbacks: Mut<Rollbacks>| {
+    ///         rollbacks.checkpoint_if_changed(world)
+    ///     })
+    /// };
+    ///
+    /// // Nothing changed
+    /// assert!(!checkpoint(world));
+    /// assert_eq!(world.resource::<Rollbacks>().len(), 1);
+    ///
+    /// world.entity_mut(entity).insert(Transform::from_xyz(1.0, 0.0, 0.0));
+    ///
+    /// assert!(checkpoint(world));
+    /// assert_eq!(world.resource::<Rollbacks>().len(), 2);
+    /// ```
+    pub fn checkpoint_if_changed(&mut self, world: &World) -> bool {
+        if self.baseline.is_some_and(|baseline| !baseline.changed(world)) {
+            return false;
+        }
+
+        self.checkpoint(Rollback::from_world(world));
+        self.baseline = Some(ChangeBaseline::capture(world));
+
+        true
+    }
+
+    /// Returns a best-effort estimate of the memory used by the stored checkpoints, in bytes.
+    ///
+    /// The estimate is computed by walking the reflected values of each checkpoint, without serializing them.
+    /// It is meant for relative comparisons, such as deciding when to drop old checkpoints.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let world = &mut app.world;
+    /// let mut estimates = vec![world.resource::<Rollbacks>().estimated_bytes()];
+    ///
+    /// for i in 0..5 {
+    ///     world.spawn(Transform::from_xyz(i as f32, 0.0, 0.0));
+    ///     world.checkpoint();
+    ///
+    ///     estimates.push(world.resource::<Rollbacks>().estimated_bytes());
+    /// }
+    ///
+    /// assert!(estimates.windows(2).all(|w| w[0] < w[1]));
+    /// ```
+    pub fn estimated_bytes(&self) -> usize {
+        self.checkpoints
+            .iter()
+            .map(|rollback| rollback.snapshot.estimated_bytes())
+            .sum()
     }
 
     /// Rolls back the given number of checkpoints.
     ///
     /// If checkpoints is negative, it rolls forward.
+    /// Rolling back follows the parents of the active checkpoint,
+    /// and rolling forward follows its most recently created child.
     ///
     /// This function will always clamp itself to valid rollbacks.
     /// Rolling back or further farther than what is valid will just return the oldest / newest snapshot.
-    #[allow(clippy::cast_possible_wrap)]
-    #[allow(clippy::cast_sign_loss)]
     pub fn rollback(&mut self, checkpoints: isize) -> Option<&Rollback> {
-        if let Some(active) = self.active {
-            let raw = active as isize - checkpoints;
-            let new = raw.clamp(0, self.checkpoints.len() as isize - 1) as usize;
+        let mut active = self.active?;
+
+        for _ in 0..checkpoints.unsigned_abs() {
+            let next = if checkpoints > 0 {
+                self.parent(active)
+            } else {
+                self.children(active).last()
+            };
 
-            self.active = Some(new);
-            Some(&self.checkpoints[new])
-        } else {
-            None
+            let Some(next) = next else {
+                break;
+            };
+
+            active = next;
         }
+
+        self.active = Some(active);
+        Some(&self.checkpoints[active])
     }
 }
 
@@ -52,7 +511,87 @@ impl CloneReflect for Rollbacks {
     fn clone_value(&self) -> Self {
         Self {
             checkpoints: self.checkpoints.iter().map(|r| r.clone_value()).collect(),
+            parents: self.parents.clone(),
             active: self.active,
+            baseline: None,
+            pinned: self.pinned.clone(),
+            capacity: self.capacity,
+        }
+    }
+}
+
+/// The rollback state of a [`World`] at a checkpoint, used to cheaply detect changes since then.
+#[derive(Clone, Copy)]
+pub(crate) struct ChangeBaseline {
+    tick: Tick,
+    entities: u32,
+    components: usize,
+}
+
+impl ChangeBaseline {
+    /// Capture the baseline of the [`World`], advancing its change tick so later changes are newer.
+    pub(crate) fn capture(world: &World) -> Self {
+        let ids = Self::rollback_ids(world);
+
+        Self {
+            tick: world.increment_change_tick(),
+            entities: world.entities().len(),
+            components: Self::count(world, &ids),
         }
     }
+
+    /// Returns true if any rollback component or resource was added, changed or removed, or entities were spawned or despawned.
+    fn changed(self, world: &World) -> bool {
+        let ids = Self::rollback_ids(world);
+
+        if world.entities().len() != self.entities || Self::count(world, &ids) != self.components {
+            return true;
+        }
+
+        let this_run = world.read_change_tick();
+        let changed = |ticks: Option<ComponentTicks>| {
+            ticks.is_some_and(|ticks| ticks.is_changed(self.tick, this_run))
+        };
+
+        let resources = &world.storages().resources;
+
+        ids.iter()
+            .any(|&id| changed(resources.get(id).and_then(|data| data.get_ticks())))
+            || world.iter_entities().any(|entity| {
+                ids.iter()
+                    .any(|&id| changed(entity.get_change_ticks_by_id(id)))
+            })
+    }
+
+    fn rollback_ids(world: &World) -> Vec<ComponentId> {
+        let saveables = world.resource::<SaveableRegistry>();
+
+        world
+            .components()
+            .iter()
+            .filter(|info| saveables.contains(info.name()) && saveables.can_rollback(info.name()))
+            .map(|info| info.id())
+            .collect()
+    }
+
+    /// Count the rollback resources and rollback components of every entity.
+    fn count(world: &World, ids: &[ComponentId]) -> usize {
+        let resources = &world.storages().resources;
+
+        let resources = ids
+            .iter()
+            .filter(|&&id| resources.get(id).is_some_and(|data| data.is_present()))
+            .count();
+
+        let components = world
+            .archetypes()
+            .iter()
+            .map(|archetype| {
+                let contained = ids.iter().filter(|&&id| archetype.contains(id)).count();
+                contained * archetype.len()
+            })
+            .sum::<usize>();
+
+        resources + components
+    }
 }
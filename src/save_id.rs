@@ -0,0 +1,226 @@
+use bevy::{
+    prelude::*,
+    reflect::ReflectMut,
+};
+
+/// A stable identifier for an entity that persists across save files and sessions.
+///
+/// With [`AppEntityRefs::SaveId`], [`Entity`] fields that reference an entity with a [`SaveId`]
+/// are stored as that [`SaveId`] and re-linked to the matching entity when applied.
+///
+/// The `SaveId` of each entity must be unique.
+#[derive(Component, Reflect, Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[reflect(Component)]
+pub struct SaveId(pub u32);
+
+impl SaveId {
+    /// Encode the [`SaveId`] as a placeholder [`Entity`] stored in place of an entity reference.
+    ///
+    /// The placeholder uses the maximum generation, which live entities never reach in practice.
+    pub(crate) fn to_entity(self) -> Entity {
+        Entity::from_bits((u64::from(u32::MAX) << 32) | u64::from(self.0))
+    }
+
+    /// Decode a placeholder [`Entity`] created by [`SaveId::to_entity`].
+    pub(crate) fn from_entity(entity: Entity) -> Option<Self> {
+        (entity.generation() == u32::MAX).then(|| Self(entity.index()))
+    }
+}
+
+/// Determines how [`Entity`] fields of saveable components are stored in snapshots.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// #[derive(Component, Reflect)]
+/// #[reflect(Component)]
+/// struct Partner(Entity);
+///
+/// impl FromWorld for Partner {
+///     fn from_world(_: &mut World) -> Self {
+///         Self(Entity::PLACEHOLDER)
+///     }
+/// }
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// # let mut other = App::new();
+/// # other.add_plugins(MinimalPlugins);
+/// # other.add_plugins(SavePlugins);
+/// for app in [&mut app, &mut other] {
+///     app.register_saveable::<Partner>()
+///         .insert_resource(AppEntityRefs::SaveId);
+/// }
+///
+/// let world = &mut app.world;
+///
+/// let a = world.spawn(SaveId(1)).id();
+/// let b = world.spawn((SaveId(2), Partner(a))).id();
+/// world.entity_mut(a).insert(Partner(b));
+///
+/// let mut save = Vec::new();
+/// world.serialize(&mut serde_json::Serializer::new(&mut save)).unwrap();
+///
+/// // Load into a fresh session, where the entities get different ids
+/// other.insert_resource(AppDespawnMode::new(DespawnMode::None));
+/// other.insert_resource(AppMappingMode::new(MappingMode::Strict));
+///
+/// let world = &mut other.world;
+/// world.spawn_batch((0..5).map(|_| ()));
+/// world.deserialize(&mut serde_json::Deserializer::from_slice(&save)).unwrap();
+///
+/// let mut query = world.query::<(Entity, &SaveId, &Partner)>();
+/// let linked = query.iter(world).collect::<Vec<_>>();
+///
+/// assert_eq!(linked.len(), 2);
+///
+/// for (entity, _, partner) in &linked {
+///     let (target, _, back) = linked.iter().find(|(e, ..)| *e == partner.0).unwrap();
+///     assert_ne!(entity, target);
+///     assert_eq!(back.0, *entity);
+/// }
+/// ```
+///
+/// Components implementing [`MapEntities`](bevy::ecs::entity::MapEntities) are re-linked the same way:
+/// ```
+/// # use bevy::{
+/// #     ecs::{
+/// #         entity::{EntityMapper, MapEntities},
+/// #         reflect::ReflectMapEntities,
+/// #     },
+/// #     prelude::*,
+/// # };
+/// # use bevy_save::prelude::*;
+/// #[derive(Component, Reflect)]
+/// #[reflect(Component, MapEntities)]
+/// struct Leader(Entity);
+///
+/// impl FromWorld for Leader {
+///     fn from_world(_: &mut World) -> Self {
+///         Self(Entity::PLACEHOLDER)
+///     }
+/// }
+///
+/// impl MapEntities for Leader {
+///     fn map_entities(&mut self, mapper: &mut EntityMapper) {
+///         self.0 = mapper.get_or_reserve(self.0);
+///     }
+/// }
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// # let mut other = App::new();
+/// # other.add_plugins(MinimalPlugins);
+/// # other.add_plugins(SavePlugins);
+/// for app in [&mut app, &mut other] {
+///     app.register_saveable::<Leader>()
+///         .insert_resource(AppEntityRefs::SaveId);
+/// }
+///
+/// let world = &mut app.world;
+///
+/// let captain = world.spawn((SaveId(1), Name::new("Captain"))).id();
+/// world.spawn((SaveId(2), Leader(captain)));
+/// world.entity_mut(captain).insert(Leader(captain));
+///
+/// let mut save = Vec::new();
+/// world.serialize(&mut serde_json::Serializer::new(&mut save)).unwrap();
+///
+/// other.insert_resource(AppDespawnMode::new(DespawnMode::None));
+/// other.insert_resource(AppMappingMode::new(MappingMode::Strict));
+///
+/// let world = &mut other.world;
+/// world.spawn_batch((0..5).map(|_| ()));
+/// world.deserialize(&mut serde_json::Deserializer::from_slice(&save)).unwrap();
+///
+/// let leaders = world.query::<&Leader>().iter(world).map(|l| l.0).collect::<Vec<_>>();
+///
+/// assert_eq!(leaders.len(), 2);
+///
+/// for leader in leaders {
+///     assert_eq!(world.get::<Name>(leader).unwrap().as_str(), "Captain");
+/// }
+/// ```
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AppEntityRefs {
+    /// Store the raw [`Entity`] bits, which are only meaningful within the current session.
+    ///
+    /// `bevy_save` default
+    #[default]
+    Raw,
+
+    /// Store references to entities with a [`SaveId`] as that [`SaveId`], re-linking them when applied.
+    SaveId,
+}
+
+/// Type data marking a component whose [`Entity`] fields are remapped by walking its reflected value when applied.
+///
+/// Register with [`AppSaveableExt::register_entity_fields`](crate::AppSaveableExt::register_entity_fields).
+/// Unlike [`MapEntities`](bevy::ecs::entity::MapEntities), this reaches entities nested anywhere in the value,
+/// such as in a `Vec<Entity>`, without a manual implementation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EntityFields;
+
+/// Call `f` on every [`Entity`] reachable from the reflected value.
+pub(crate) fn visit_entities(value: &mut dyn Reflect, f: &mut dyn FnMut(&mut Entity)) {
+    if let Some(entity) = value.downcast_mut::<Entity>() {
+        f(entity);
+        return;
+    }
+
+    match value.reflect_mut() {
+        ReflectMut::Struct(value) => {
+            for i in 0..value.field_len() {
+                if let Some(field) = value.field_at_mut(i) {
+                    visit_entities(field, f);
+                }
+            }
+        }
+        ReflectMut::TupleStruct(value) => {
+            for i in 0..value.field_len() {
+                if let Some(field) = value.field_mut(i) {
+                    visit_entities(field, f);
+                }
+            }
+        }
+        ReflectMut::Tuple(value) => {
+            for i in 0..value.field_len() {
+                if let Some(field) = value.field_mut(i) {
+                    visit_entities(field, f);
+                }
+            }
+        }
+        ReflectMut::List(value) => {
+            for i in 0..value.len() {
+                if let Some(item) = value.get_mut(i) {
+                    visit_entities(item, f);
+                }
+            }
+        }
+        ReflectMut::Array(value) => {
+            for i in 0..value.len() {
+                if let Some(item) = value.get_mut(i) {
+                    visit_entities(item, f);
+                }
+            }
+        }
+        ReflectMut::Map(value) => {
+            for i in 0..value.len() {
+                if let Some((_, item)) = value.get_at_mut(i) {
+                    visit_entities(item, f);
+                }
+            }
+        }
+        ReflectMut::Enum(value) => {
+            for i in 0..value.field_len() {
+                if let Some(field) = value.field_at_mut(i) {
+                    visit_entities(field, f);
+                }
+            }
+        }
+        ReflectMut::Value(_) => {}
+    }
+}
@@ -164,6 +164,14 @@ pub trait Saver: Send + Sync + 'static {
     /// }
     /// ```
     fn serializer<'w>(&self, writer: Writer<'w>) -> IntoSerializer<'w>;
+
+    /// The [`SaveFormat`] recorded in the header of save files written by this [`Saver`].
+    ///
+    /// Save files with a known format are always loaded with the matching built-in [`Loader`],
+    /// while [`SaveFormat::Custom`] save files are loaded with the [`AppLoader`].
+    fn format(&self) -> SaveFormat {
+        SaveFormat::Custom
+    }
 }
 
 /// Handles deserialization of save data.
@@ -186,6 +194,111 @@ pub trait Loader: Send + Sync + 'static {
     fn deserializer<'r, 'de>(&self, reader: Reader<'r>) -> IntoDeserializer<'r, 'de>;
 }
 
+/// The serialization format of a save file body.
+///
+/// [`World::save`](crate::WorldSaveableExt::save) records the format of the current [`Saver`] and the [`AppCompression`](crate::AppCompression)
+/// in the save file header, and [`World::load`](crate::WorldSaveableExt::load) uses them to pick the matching decoder.
+///
+/// # Example
+/// ```
+/// # use std::{collections::HashMap, io::{Cursor, Write}, sync::Mutex};
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// # static SAVES: Mutex<Option<HashMap<String, Vec<u8>>>> = Mutex::new(None);
+/// # struct Memory;
+/// # struct MemoryWriter(String);
+/// # impl Write for MemoryWriter {
+/// #     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+/// #         let mut saves = SAVES.lock().unwrap();
+/// #         let save = saves.get_or_insert_with(HashMap::new).entry(self.0.clone()).or_default();
+/// #         save.extend_from_slice(buf);
+/// #         Ok(buf.len())
+/// #     }
+/// #     fn flush(&mut self) -> std::io::Result<()> { Ok(()) }
+/// # }
+/// # impl Backend for Memory {
+/// #     type Reader = Cursor<Vec<u8>>;
+/// #     type Writer = MemoryWriter;
+/// #     fn reader(name: &str) -> Result<Self::Reader, SaveableError> {
+/// #         let saves = SAVES.lock().unwrap();
+/// #         let save = saves.as_ref().and_then(|s| s.get(name)).cloned().unwrap_or_default();
+/// #         Ok(Cursor::new(save))
+/// #     }
+/// #     fn writer(name: &str) -> Result<Self::Writer, SaveableError> {
+/// #         SAVES.lock().unwrap().get_or_insert_with(HashMap::new).remove(name);
+/// #         Ok(MemoryWriter(name.to_owned()))
+/// #     }
+/// # }
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// # app.insert_resource(AppBackend::new(Memory));
+/// app.world.spawn(Transform::from_xyz(1.0, 2.0, 3.0));
+///
+/// app.insert_resource(AppSaver::new(JSONSaver::default()));
+/// app.insert_resource(AppCompression::GzipLevel(9));
+/// app.world.save("json").unwrap();
+///
+/// app.insert_resource(AppSaver::new(RMPSaver));
+/// app.insert_resource(AppCompression::None);
+/// app.world.save("rmp").unwrap();
+///
+/// // The default `AppLoader` reads MessagePack, but both saves load regardless
+/// app.insert_resource(AppLoader::default());
+///
+/// for name in ["json", "rmp"] {
+///     app.world.clear_entities();
+///     app.world.load(name).unwrap();
+///
+///     let transform = app.world.query::<&Transform>().single(&app.world);
+///     assert_eq!(transform.translation, Vec3::new(1.0, 2.0, 3.0));
+/// }
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveFormat {
+    /// A format unknown to `bevy_save`, loaded with the [`AppLoader`].
+    #[default]
+    Custom,
+
+    /// [MessagePack](https://msgpack.org), written by [`RMPSaver`].
+    MessagePack,
+
+    /// JSON, written by [`JSONSaver`].
+    JSON,
+}
+
+impl SaveFormat {
+    pub(crate) fn id(self) -> u8 {
+        match self {
+            Self::Custom => 0,
+            Self::MessagePack => 1,
+            Self::JSON => 2,
+        }
+    }
+
+    pub(crate) fn from_id(id: u8) -> Option<Self> {
+        match id {
+            0 => Some(Self::Custom),
+            1 => Some(Self::MessagePack),
+            2 => Some(Self::JSON),
+            _ => None,
+        }
+    }
+
+    /// Returns the deserializer for this format, falling back to the [`AppLoader`] for [`SaveFormat::Custom`].
+    pub(crate) fn deserializer<'r, 'de: 'r>(
+        self,
+        loader: &AppLoader,
+        reader: Reader<'r>,
+    ) -> IntoDeserializer<'r, 'de> {
+        match self {
+            Self::Custom => loader.deserializer(reader),
+            Self::MessagePack => RMPLoader.deserializer(reader),
+            Self::JSON => JSONLoader.deserializer(reader),
+        }
+    }
+}
+
 // Saver / Loader Implementations |------------------------------------------------------------------------------------
 
 /// An implementation of [`Saver`] that uses [`rmp_serde::Serializer`].
@@ -195,6 +308,10 @@ impl Saver for RMPSaver {
     fn serializer<'w>(&self, writer: Writer<'w>) -> IntoSerializer<'w> {
         IntoSerializer::erase(rmp_serde::Serializer::new(writer))
     }
+
+    fn format(&self) -> SaveFormat {
+        SaveFormat::MessagePack
+    }
 }
 
 /// An implementation of [`Loader`] that uses [`rmp_serde::Deserializer`].
@@ -206,6 +323,88 @@ impl Loader for RMPLoader {
     }
 }
 
+/// An implementation of [`Saver`] that uses [`serde_json::Serializer`].
+///
+/// Output is compact by default, use [`JSONSaver::pretty`] for human-readable output.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// # let world = &mut app.world;
+/// world.spawn(Transform::from_xyz(1.0, 2.0, 3.0));
+///
+/// let mut pretty = Vec::new();
+/// let mut compact = Vec::new();
+///
+/// world.serialize(&mut AppSaver::new(JSONSaver::new().pretty(true)).serializer(&mut pretty)).unwrap();
+/// world.serialize(&mut AppSaver::new(JSONSaver::new()).serializer(&mut compact)).unwrap();
+///
+/// assert!(pretty.contains(&b'\n'));
+/// assert!(!compact.contains(&b'\n'));
+///
+/// // Both formats load identically
+/// let loader = AppLoader::new(JSONLoader);
+///
+/// for save in [&pretty, &compact] {
+///     world.clear_entities();
+///     world.deserialize(&mut loader.deserializer(&mut save.as_slice())).unwrap();
+///
+///     let transform = world.query::<&Transform>().single(world);
+///     assert_eq!(*transform, Transform::from_xyz(1.0, 2.0, 3.0));
+/// }
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JSONSaver {
+    pretty: bool,
+}
+
+impl JSONSaver {
+    /// Create a new [`JSONSaver`] with compact output.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether the output should be pretty-printed.
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.pretty = pretty;
+        self
+    }
+
+    /// Returns `true` if the output is pretty-printed.
+    pub fn is_pretty(&self) -> bool {
+        self.pretty
+    }
+}
+
+impl Saver for JSONSaver {
+    fn serializer<'w>(&self, writer: Writer<'w>) -> IntoSerializer<'w> {
+        if self.pretty {
+            IntoSerializer::erase(serde_json::Serializer::pretty(writer))
+        } else {
+            IntoSerializer::erase(serde_json::Serializer::new(writer))
+        }
+    }
+
+    fn format(&self) -> SaveFormat {
+        SaveFormat::JSON
+    }
+}
+
+/// An implementation of [`Loader`] that uses [`serde_json::Deserializer`].
+///
+/// Loads both pretty and compact [`JSONSaver`] output.
+pub struct JSONLoader;
+
+impl Loader for JSONLoader {
+    fn deserializer<'r, 'de>(&self, reader: Reader<'r>) -> IntoDeserializer<'r, 'de> {
+        IntoDeserializer::erase(serde_json::Deserializer::from_reader(reader))
+    }
+}
+
 // Resources |---------------------------------------------------------------------------------------------------------
 
 /// The App's [`Saver`].
@@ -233,6 +432,11 @@ impl AppSaver {
         self.0.serializer(writer.into())
     }
 
+    /// Returns the [`SaveFormat`] of the current [`Saver`].
+    pub fn format(&self) -> SaveFormat {
+        self.0.format()
+    }
+
     /// Serialize the value to the given writer using the current [`Saver`].
     ///
     /// # Errors
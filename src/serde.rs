@@ -1,6 +1,11 @@
 use std::{
+    any::TypeId,
     borrow::Cow,
-    collections::HashSet,
+    collections::{
+        BTreeSet,
+        HashMap,
+        HashSet,
+    },
 };
 
 use bevy::{
@@ -11,20 +16,31 @@ use bevy::{
             TypedReflectSerializer,
             UntypedReflectDeserializer,
         },
+        DynamicStruct,
+        DynamicTupleStruct,
+        ReflectRef,
+        StructInfo,
+        TupleStructInfo,
+        TypeInfo,
+        TypeRegistration,
         TypeRegistryArc,
         TypeRegistryInternal,
     },
+    utils::Duration,
 };
 use serde::{
     de::{
         self,
         DeserializeSeed,
+        EnumAccess,
         Error,
         MapAccess,
         SeqAccess,
+        VariantAccess,
         Visitor,
     },
     ser::{
+        self,
         SerializeMap,
         SerializeSeq,
         SerializeStruct,
@@ -36,10 +52,13 @@ use serde::{
 };
 
 use crate::{
+    depth::DepthLimit,
     entity::SaveableEntity,
     snapshot::RawSnapshot,
+    RelativeTime,
     Rollback,
     Rollbacks,
+    SaveableError,
     Snapshot,
 };
 
@@ -49,18 +68,489 @@ use crate::{
 #[serde(transparent)]
 struct BorrowableCowStr<'a>(#[serde(borrow)] Cow<'a, str>);
 
+// Serialized names |------------------------------------------------------------------------------------------------
+
+/// Type data storing the stable name a type is serialized under, in place of its Rust type path.
+///
+/// Register with [`AppSaveableExt::register_serialized_name`](crate::AppSaveableExt::register_serialized_name).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SerializedName(String);
+
+impl SerializedName {
+    /// Create a new [`SerializedName`].
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        Self(name.into())
+    }
+
+    /// Returns the stable name.
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Type data listing the reflect paths of fields that are never serialized.
+///
+/// Register with [`AppSaveableExt::register_field_filter`](crate::AppSaveableExt::register_field_filter).
+/// Paths are dot-separated struct field names, such as `translation.z`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldFilter(Vec<String>);
+
+impl FieldFilter {
+    /// Create a new [`FieldFilter`] excluding the given reflect paths.
+    pub fn new<S: Into<String>>(paths: impl IntoIterator<Item = S>) -> Self {
+        Self(paths.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns the excluded reflect paths.
+    pub fn paths(&self) -> &[String] {
+        &self.0
+    }
+
+    fn excluded(&self) -> Vec<&str> {
+        self.0.iter().map(String::as_str).collect()
+    }
+}
+
+/// Type data marking a tuple struct whose fields are serialized as a map keyed by field index.
+///
+/// Register with [`AppSaveableExt::register_indexed_fields`](crate::AppSaveableExt::register_indexed_fields).
+/// Unlike positional fields, saves keep loading after fields are added to or removed from the end of the type.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct IndexedFields;
+
+/// Returns true if values of the type contain a tuple struct with [`IndexedFields`], directly or in struct fields.
+fn has_indexed_fields(registry: &TypeRegistryInternal, type_id: TypeId) -> bool {
+    let Some(reg) = registry.get(type_id) else {
+        return false;
+    };
+
+    if reg.data::<IndexedFields>().is_some() {
+        return true;
+    }
+
+    match reg.type_info() {
+        TypeInfo::Struct(info) => info
+            .iter()
+            .any(|field| has_indexed_fields(registry, field.type_id())),
+        _ => false,
+    }
+}
+
+/// Returns true if the type is a tuple struct with [`IndexedFields`].
+fn is_indexed(registry: &TypeRegistryInternal, type_id: Option<TypeId>) -> bool {
+    type_id
+        .and_then(|type_id| registry.get(type_id))
+        .is_some_and(|reg| reg.data::<IndexedFields>().is_some())
+}
+
+/// Returns the type a reflected value represents, which may be a dynamic value.
+fn type_id_of(value: &dyn Reflect) -> Option<TypeId> {
+    value.get_represented_type_info().map(TypeInfo::type_id)
+}
+
+/// Returns true if the value must be serialized by [`FilteredSerializer`] rather than Bevy's serializer.
+fn is_filtered(
+    registry: &TypeRegistryInternal,
+    type_id: Option<TypeId>,
+    excluded: &[&str],
+    relative: &[&str],
+) -> bool {
+    !excluded.is_empty()
+        || !relative.is_empty()
+        || type_id.is_some_and(|type_id| has_indexed_fields(registry, type_id))
+}
+
+/// Returns the paths below the given field, relative to that field.
+fn excluded_fields<'a>(excluded: &[&'a str], field: &str) -> Vec<&'a str> {
+    excluded
+        .iter()
+        .filter_map(|path| path.strip_prefix(field)?.strip_prefix('.'))
+        .collect()
+}
+
+/// Returns the excluded and [`RelativeTime`] paths of a type serialized by [`FilteredSerializer`],
+/// if it is serialized that way.
+fn filtered_paths<'a>(
+    registry: &TypeRegistryInternal,
+    reg: &'a TypeRegistration,
+) -> Option<(Vec<&'a str>, Vec<&'a str>)> {
+    let filter = reg.data::<FieldFilter>();
+    let time = reg.data::<RelativeTime>();
+
+    if filter.is_none() && time.is_none() && !has_indexed_fields(registry, reg.type_id()) {
+        return None;
+    }
+
+    Some((
+        filter.map(FieldFilter::excluded).unwrap_or_default(),
+        time.map(RelativeTime::relative).unwrap_or_default(),
+    ))
+}
+
+/// The type registry used by the reflection serializers.
+///
+/// Serializers can be created from an [`AppTypeRegistry`], a [`TypeRegistryArc`], or a plain
+/// [`TypeRegistryInternal`] built independently of any [`App`].
+#[derive(Clone, Copy)]
+pub enum SerializerRegistry<'a> {
+    /// A shared registry, locked for reading for the duration of serialization.
+    Arc(&'a TypeRegistryArc),
+
+    /// A registry that is already accessible.
+    Internal(&'a TypeRegistryInternal),
+}
+
+impl SerializerRegistry<'_> {
+    fn read<R>(self, f: impl FnOnce(&TypeRegistryInternal) -> R) -> R {
+        match self {
+            Self::Arc(registry) => f(&registry.read()),
+            Self::Internal(registry) => f(registry),
+        }
+    }
+}
+
+impl<'a> From<&'a TypeRegistryArc> for SerializerRegistry<'a> {
+    fn from(registry: &'a TypeRegistryArc) -> Self {
+        Self::Arc(registry)
+    }
+}
+
+impl<'a> From<&'a AppTypeRegistry> for SerializerRegistry<'a> {
+    fn from(registry: &'a AppTypeRegistry) -> Self {
+        Self::Arc(registry)
+    }
+}
+
+impl<'a> From<&'a TypeRegistryInternal> for SerializerRegistry<'a> {
+    fn from(registry: &'a TypeRegistryInternal) -> Self {
+        Self::Internal(registry)
+    }
+}
+
+/// Returns the name the type should be serialized under.
+fn serialized_name<'a>(registry: &'a TypeRegistryInternal, type_name: &'a str) -> &'a str {
+    registry
+        .get_with_name(type_name)
+        .and_then(|reg| reg.data::<SerializedName>())
+        .map_or(type_name, |name| name.name())
+}
+
+/// The types registered under a [`SerializedName`], collected once per deserializer.
+struct SerializedNames<'a>(HashMap<&'a str, TypeId>);
+
+impl<'a> SerializedNames<'a> {
+    fn new(registry: &'a TypeRegistryInternal) -> Self {
+        let names = registry
+            .iter()
+            .filter_map(|reg| Some((reg.data::<SerializedName>()?.name(), reg.type_id())))
+            .collect();
+
+        Self(names)
+    }
+
+    /// Returns the registration for a serialized name, preferring stable names over Rust type paths.
+    fn registration<'r>(
+        &self,
+        registry: &'r TypeRegistryInternal,
+        name: &str,
+    ) -> Option<&'r TypeRegistration> {
+        self.0
+            .get(name)
+            .and_then(|&type_id| registry.get(type_id))
+            .or_else(|| registry.get_with_name(name))
+    }
+}
+
+/// Returns the registration for a serialized name, using the [`SerializedNames`] of the context.
+fn registration<'a>(
+    registry: &'a TypeRegistryInternal,
+    context: Context,
+    name: &str,
+) -> Option<&'a TypeRegistration> {
+    match context.names {
+        Some(names) => names.registration(registry, name),
+        None => SerializedNames::new(registry).registration(registry, name),
+    }
+}
+
+/// A [`TransformValue`] rewrites the serialized bytes of a single reflected value, given its type name.
+///
+/// Used by [`SnapshotSerializer::wrap_values`] and [`SnapshotDeserializer::unwrap_values`]
+/// to obfuscate or encrypt each component and resource while keeping the structure of the snapshot.
+pub trait TransformValue: Fn(&str, &[u8]) -> Vec<u8> + Send + Sync {}
+
+impl<T> TransformValue for T where T: Fn(&str, &[u8]) -> Vec<u8> + Send + Sync {}
+
+/// A boxed [`TransformValue`].
+pub type BoxedTransformValue = Box<dyn TransformValue>;
+
+/// Options shared by the nested serializers and deserializers of a snapshot.
+#[derive(Clone, Copy, Default)]
+struct Context<'a> {
+    transform: Option<&'a dyn TransformValue>,
+    types: Option<&'a TypeTable<'a>>,
+    records: bool,
+    depth: Option<&'a DepthLimit>,
+    names: Option<&'a SerializedNames<'a>>,
+}
+
+impl Context<'_> {
+    /// Calls `f` with this context, collecting the [`SerializedNames`] first if no outer deserializer did.
+    fn with_names<R>(
+        self,
+        registry: &TypeRegistryInternal,
+        f: impl for<'c> FnOnce(Context<'c>) -> R,
+    ) -> R {
+        if self.names.is_some() {
+            return f(self);
+        }
+
+        let names = SerializedNames::new(registry);
+
+        f(Context {
+            names: Some(&names),
+            ..self
+        })
+    }
+}
+
+/// The type names of a snapshot, written once so components and resources can refer to them by index.
+///
+/// When serializing, the names are Rust type paths sorted for lookup.
+struct TypeTable<'a>(Vec<&'a str>);
+
+impl<'a> TypeTable<'a> {
+    /// Collects the type names of every component and resource in the snapshot, including rollbacks.
+    fn new(snapshot: &'a Snapshot) -> Self {
+        let mut names = BTreeSet::new();
+
+        let rollbacks = snapshot.rollbacks.iter().flat_map(|r| &r.checkpoints);
+
+        for raw in std::iter::once(&snapshot.snapshot).chain(rollbacks.map(|r| &r.snapshot)) {
+            let entities = raw.entities.iter();
+
+            names.extend(raw.resources.iter().map(|r| r.type_name()));
+            names.extend(entities.clone().flat_map(|e| &e.components).map(|c| c.type_name()));
+            names.extend(entities.flat_map(|e| &e.defaults).map(String::as_str));
+        }
+
+        Self(names.into_iter().collect())
+    }
+
+    fn id<E: ser::Error>(&self, type_name: &str) -> Result<u32, E> {
+        self.0
+            .binary_search(&type_name)
+            .map_err(|_| E::custom(format!("`{type_name}` is missing from the type table")))
+            .and_then(|id| u32::try_from(id).map_err(E::custom))
+    }
+
+    fn name<E: de::Error>(&self, id: u32) -> Result<&'a str, E> {
+        self.0
+            .get(id as usize)
+            .copied()
+            .ok_or_else(|| E::custom(format!("type id `{id}` is missing from the type table")))
+    }
+}
+
+/// The key of a reflected value, its serialized name or its index in the [`TypeTable`].
+enum TypeKey<'a> {
+    Name(&'a str),
+    Id(u32),
+}
+
+impl TypeKey<'_> {
+    fn new<'a, E: ser::Error>(
+        context: Context,
+        name: &'a str,
+        type_name: &str,
+    ) -> Result<TypeKey<'a>, E> {
+        match context.types {
+            Some(types) => types.id(type_name).map(TypeKey::Id),
+            None => Ok(TypeKey::Name(name)),
+        }
+    }
+}
+
+impl Serialize for TypeKey<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Name(name) => serializer.serialize_str(name),
+            Self::Id(id) => serializer.serialize_u32(*id),
+        }
+    }
+}
+
+/// The transformed bytes of a reflected value, written with [`Serializer::serialize_bytes`].
+struct ValueBytes(Vec<u8>);
+
+impl Serialize for ValueBytes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for ValueBytes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ValueBytesVisitor;
+
+        impl<'de> Visitor<'de> for ValueBytesVisitor {
+            type Value = ValueBytes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("transformed value bytes")
+            }
+
+            fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+                Ok(ValueBytes(v.to_vec()))
+            }
+
+            fn visit_byte_buf<E: Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+                Ok(ValueBytes(v))
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+
+                while let Some(byte) = seq.next_element()? {
+                    bytes.push(byte);
+                }
+
+                Ok(ValueBytes(bytes))
+            }
+        }
+
+        deserializer.deserialize_byte_buf(ValueBytesVisitor)
+    }
+}
+
+/// Serialize a reflected value as a map entry.
+///
+/// With a [`TypeTable`], the key is the index of the type instead of its name.
+/// With a [`TransformValue`], the value is encoded as `MessagePack` and written as the transformed bytes.
+fn serialize_value<M, T>(
+    state: &mut M,
+    name: &str,
+    type_name: &str,
+    value: &T,
+    context: Context,
+) -> Result<(), M::Error>
+where
+    M: SerializeMap,
+    T: Serialize,
+{
+    let key = TypeKey::new(context, name, type_name)?;
+
+    let Some(transform) = context.transform else {
+        return state.serialize_entry(&key, value);
+    };
+
+    let bytes = rmp_serde::to_vec_named(value).map_err(ser::Error::custom)?;
+
+    state.serialize_entry(&key, &ValueBytes(transform(type_name, &bytes)))
+}
+
+/// Deserialize the next map value as a reflected value, undoing [`serialize_value`].
+///
+/// With a [`DepthLimit`], the value fails to deserialize once it nests deeper than the limit.
+fn deserialize_value<'de, A, T>(
+    map: &mut A,
+    seed: T,
+    type_name: &str,
+    context: Context,
+) -> Result<Box<dyn Reflect>, A::Error>
+where
+    A: MapAccess<'de>,
+    T: for<'b> DeserializeSeed<'b, Value = Box<dyn Reflect>>,
+{
+    match context.depth {
+        Some(limit) => next_value(map, limit.seed(seed), type_name, context.transform),
+        None => next_value(map, seed, type_name, context.transform),
+    }
+}
+
+fn next_value<'de, A, T>(
+    map: &mut A,
+    seed: T,
+    type_name: &str,
+    transform: Option<&dyn TransformValue>,
+) -> Result<Box<dyn Reflect>, A::Error>
+where
+    A: MapAccess<'de>,
+    T: for<'b> DeserializeSeed<'b, Value = Box<dyn Reflect>>,
+{
+    let Some(transform) = transform else {
+        return map.next_value_seed(seed);
+    };
+
+    let ValueBytes(bytes) = map.next_value()?;
+    let bytes = transform(type_name, &bytes);
+
+    seed.deserialize(&mut rmp_serde::Deserializer::from_read_ref(&bytes))
+        .map_err(de::Error::custom)
+}
+
+/// Returns the registration for the next map key, undoing the key written by [`serialize_value`].
+fn next_registration<'de, 'a, A>(
+    map: &mut A,
+    registry: &'a TypeRegistryInternal,
+    context: Context,
+) -> Result<Option<&'a TypeRegistration>, A::Error>
+where
+    A: MapAccess<'de>,
+{
+    let (name, registration) = if let Some(types) = context.types {
+        let Some(id) = map.next_key::<u32>()? else {
+            return Ok(None);
+        };
+
+        let name = types.name(id)?;
+
+        (Cow::Borrowed(name), registry.get_with_name(name))
+    } else {
+        let Some(BorrowableCowStr(name)) = map.next_key()? else {
+            return Ok(None);
+        };
+
+        let registration = registration(registry, context, &name);
+
+        (name, registration)
+    };
+
+    registration
+        .map(Some)
+        .ok_or_else(|| de::Error::custom(format!("no registration found for `{name}`")))
+}
+
 // Vec<dyn Reflect> |--------------------------------------------------------------------------------------------------
 
 struct ReflectsSerializer<'a> {
     types: &'a [Box<dyn Reflect>],
-    registry: &'a TypeRegistryArc,
+    registry: &'a TypeRegistryInternal,
+    context: Context<'a>,
 }
 
 impl<'a> ReflectsSerializer<'a> {
-    fn new(reflects: &'a [Box<dyn Reflect>], registry: &'a TypeRegistryArc) -> Self {
+    fn new(
+        reflects: &'a [Box<dyn Reflect>],
+        registry: &'a TypeRegistryInternal,
+        context: Context<'a>,
+    ) -> Self {
         Self {
             types: reflects,
             registry,
+            context,
         }
     }
 }
@@ -73,23 +563,328 @@ impl<'a> Serialize for ReflectsSerializer<'a> {
         let mut state = serializer.serialize_map(Some(self.types.len()))?;
 
         for reflect in self.types {
-            state.serialize_entry(
-                reflect.type_name(),
-                &TypedReflectSerializer::new(&**reflect, &self.registry.read()),
-            )?;
+            let type_name = reflect.type_name();
+            let name = serialized_name(self.registry, type_name);
+            let value = ValueSerializer::new(&**reflect, self.registry);
+
+            if let Some(limit) = self.context.depth {
+                limit.check(&**reflect).map_err(ser::Error::custom)?;
+            }
+
+            serialize_value(&mut state, name, type_name, &value, self.context)?;
+        }
+
+        state.end()
+    }
+}
+
+/// Serializes a single reflected component or resource value, without its type name.
+pub(crate) struct ValueSerializer<'a> {
+    value: &'a dyn Reflect,
+    registry: &'a TypeRegistryInternal,
+}
+
+impl<'a> ValueSerializer<'a> {
+    pub(crate) fn new(value: &'a dyn Reflect, registry: &'a TypeRegistryInternal) -> Self {
+        Self {
+            value,
+            registry,
+        }
+    }
+}
+
+impl Serialize for ValueSerializer<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let paths = self
+            .registry
+            .get_with_name(self.value.type_name())
+            .and_then(|reg| filtered_paths(self.registry, reg));
+
+        if let Some((excluded, relative)) = paths {
+            FilteredSerializer {
+                value: self.value,
+                excluded,
+                relative,
+                registry: self.registry,
+            }
+            .serialize(serializer)
+        } else {
+            TypedReflectSerializer::new(self.value, self.registry).serialize(serializer)
+        }
+    }
+}
+
+/// Serializes a struct as a map of its fields, skipping excluded fields.
+///
+/// Structs containing [`RelativeTime`] fields are also written as maps, so their saved durations can be read back.
+/// Tuple structs with [`IndexedFields`] are written as maps keyed by field index.
+struct FilteredSerializer<'a> {
+    value: &'a dyn Reflect,
+    excluded: Vec<&'a str>,
+    relative: Vec<&'a str>,
+    registry: &'a TypeRegistryInternal,
+}
+
+impl Serialize for FilteredSerializer<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let value = match self.value.reflect_ref() {
+            ReflectRef::Struct(value) => value,
+            ReflectRef::TupleStruct(value) if is_indexed(self.registry, type_id_of(self.value)) => {
+                let mut state = serializer.serialize_map(Some(value.field_len()))?;
+
+                for (index, field) in value.iter_fields().enumerate() {
+                    let index = index.to_string();
+
+                    if is_filtered(self.registry, type_id_of(field), &[], &[]) {
+                        state.serialize_entry(&index, &FilteredSerializer {
+                            value: field,
+                            excluded: Vec::new(),
+                            relative: Vec::new(),
+                            registry: self.registry,
+                        })?;
+                    } else {
+                        let field = TypedReflectSerializer::new(field, self.registry);
+                        state.serialize_entry(&index, &field)?;
+                    }
+                }
+
+                return state.end();
+            }
+            _ => {
+                return TypedReflectSerializer::new(self.value, self.registry).serialize(serializer);
+            }
+        };
+
+        let fields = (0..value.field_len())
+            .filter_map(|i| Some((value.name_at(i)?, value.field_at(i)?)))
+            .filter(|(name, _)| !self.excluded.contains(name))
+            .collect::<Vec<_>>();
+
+        let mut state = serializer.serialize_map(Some(fields.len()))?;
+
+        for (name, field) in fields {
+            let excluded = excluded_fields(&self.excluded, name);
+            let relative = excluded_fields(&self.relative, name);
+            let type_id = type_id_of(field);
+
+            if is_filtered(self.registry, type_id, &excluded, &relative) {
+                state.serialize_entry(name, &FilteredSerializer {
+                    value: field,
+                    excluded,
+                    relative,
+                    registry: self.registry,
+                })?;
+            } else {
+                state.serialize_entry(name, &TypedReflectSerializer::new(field, self.registry))?;
+            }
         }
 
         state.end()
     }
 }
 
+/// Deserializes a struct serialized by [`FilteredSerializer`], leaving excluded fields out.
+///
+/// [`RelativeTime`] fields are read as the [`Duration`](bevy::utils::Duration) they were saved as.
+/// Missing [`IndexedFields`] are filled from the type's [`ReflectDefault`], and unknown ones are ignored.
+struct FilteredDeserializer<'a> {
+    registration: &'a TypeRegistration,
+    excluded: Vec<&'a str>,
+    relative: Vec<&'a str>,
+    registry: &'a TypeRegistryInternal,
+}
+
+impl<'de> DeserializeSeed<'de> for FilteredDeserializer<'_> {
+    type Value = Box<dyn Reflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let info = match self.registration.type_info() {
+            TypeInfo::Struct(info) => info,
+            TypeInfo::TupleStruct(info) if self.registration.data::<IndexedFields>().is_some() => {
+                return deserializer.deserialize_map(IndexedVisitor {
+                    registration: self.registration,
+                    info,
+                    registry: self.registry,
+                });
+            }
+            _ => {
+                return TypedReflectDeserializer::new(self.registration, self.registry)
+                    .deserialize(deserializer);
+            }
+        };
+
+        deserializer.deserialize_map(FilteredVisitor {
+            registration: self.registration,
+            info,
+            excluded: self.excluded,
+            relative: self.relative,
+            registry: self.registry,
+        })
+    }
+}
+
+struct FilteredVisitor<'a> {
+    registration: &'a TypeRegistration,
+    info: &'a StructInfo,
+    excluded: Vec<&'a str>,
+    relative: Vec<&'a str>,
+    registry: &'a TypeRegistryInternal,
+}
+
+impl<'de> Visitor<'de> for FilteredVisitor<'_> {
+    type Value = Box<dyn Reflect>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("map of struct fields")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut value = DynamicStruct::default();
+        value.set_represented_type(Some(self.registration.type_info()));
+
+        while let Some(BorrowableCowStr(key)) = map.next_key()? {
+            let field = self
+                .info
+                .field(&key)
+                .ok_or_else(|| de::Error::custom(format!("unknown field `{key}`")))?;
+
+            let (type_id, type_name) = if self.relative.contains(&&*key) {
+                (TypeId::of::<Duration>(), std::any::type_name::<Duration>())
+            } else {
+                (field.type_id(), field.type_name())
+            };
+
+            let registration = self.registry.get(type_id).ok_or_else(|| {
+                de::Error::custom(format!("no registration found for `{type_name}`"))
+            })?;
+
+            let excluded = excluded_fields(&self.excluded, &key);
+            let relative = excluded_fields(&self.relative, &key);
+
+            let type_id = Some(registration.type_id());
+
+            let field = if is_filtered(self.registry, type_id, &excluded, &relative) {
+                map.next_value_seed(FilteredDeserializer {
+                    registration,
+                    excluded,
+                    relative,
+                    registry: self.registry,
+                })?
+            } else {
+                map.next_value_seed(TypedReflectDeserializer::new(registration, self.registry))?
+            };
+
+            value.insert_boxed(&key, field);
+        }
+
+        Ok(Box::new(value))
+    }
+}
+
+struct IndexedVisitor<'a> {
+    registration: &'a TypeRegistration,
+    info: &'a TupleStructInfo,
+    registry: &'a TypeRegistryInternal,
+}
+
+impl<'de> Visitor<'de> for IndexedVisitor<'_> {
+    type Value = Box<dyn Reflect>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("map of tuple struct fields")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut fields = (0..self.info.field_len()).map(|_| None).collect::<Vec<_>>();
+
+        while let Some(BorrowableCowStr(key)) = map.next_key()? {
+            let index = key
+                .parse::<usize>()
+                .map_err(|_| de::Error::custom(format!("invalid field index `{key}`")))?;
+
+            // Fields removed from the type since the save was written
+            let Some(field) = self.info.field_at(index) else {
+                map.next_value::<de::IgnoredAny>()?;
+                continue;
+            };
+
+            let registration = self.registry.get(field.type_id()).ok_or_else(|| {
+                de::Error::custom(format!("no registration found for `{}`", field.type_name()))
+            })?;
+
+            let field = if is_filtered(self.registry, Some(field.type_id()), &[], &[]) {
+                map.next_value_seed(FilteredDeserializer {
+                    registration,
+                    excluded: Vec::new(),
+                    relative: Vec::new(),
+                    registry: self.registry,
+                })?
+            } else {
+                map.next_value_seed(TypedReflectDeserializer::new(registration, self.registry))?
+            };
+
+            fields[index] = Some(field);
+        }
+
+        // Fields added to the type since the save was written
+        let default = fields
+            .iter()
+            .any(Option::is_none)
+            .then(|| self.registration.data::<ReflectDefault>())
+            .flatten()
+            .map(ReflectDefault::default);
+
+        let mut value = DynamicTupleStruct::default();
+        value.set_represented_type(Some(self.registration.type_info()));
+
+        for (index, field) in fields.into_iter().enumerate() {
+            let field = field
+                .or_else(|| match default.as_ref()?.reflect_ref() {
+                    ReflectRef::TupleStruct(default) => {
+                        default.field(index).map(Reflect::clone_value)
+                    }
+                    _ => None,
+                })
+                .ok_or_else(|| {
+                    de::Error::custom(format!(
+                        "missing field {index} of `{}`, which has no registered default",
+                        self.info.type_name()
+                    ))
+                })?;
+
+            value.insert_boxed(field);
+        }
+
+        Ok(Box::new(value))
+    }
+}
+
 struct ReflectsDeserializer<'a> {
     registry: &'a TypeRegistryInternal,
+    context: Context<'a>,
 }
 
 impl<'a> ReflectsDeserializer<'a> {
-    fn new(registry: &'a TypeRegistryInternal) -> Self {
-        Self { registry }
+    fn new(registry: &'a TypeRegistryInternal, context: Context<'a>) -> Self {
+        Self {
+            registry,
+            context,
+        }
     }
 }
 
@@ -102,12 +897,14 @@ impl<'a, 'de> DeserializeSeed<'de> for ReflectsDeserializer<'a> {
     {
         deserializer.deserialize_map(ReflectsVisitor {
             registry: self.registry,
+            context: self.context,
         })
     }
 }
 
 struct ReflectsVisitor<'a> {
     registry: &'a TypeRegistryInternal,
+    context: Context<'a>,
 }
 
 impl<'a, 'de> Visitor<'de> for ReflectsVisitor<'a> {
@@ -124,19 +921,31 @@ impl<'a, 'de> Visitor<'de> for ReflectsVisitor<'a> {
         let mut added = HashSet::new();
         let mut reflects = Vec::new();
 
-        while let Some(BorrowableCowStr(key)) = map.next_key()? {
-            if !added.insert(key.clone()) {
-                return Err(de::Error::custom(format!("duplicate key: `{key}`")));
+        while let Some(registration) = next_registration(&mut map, self.registry, self.context)? {
+            let type_name = registration.type_name();
+
+            if !added.insert(type_name) {
+                return Err(de::Error::custom(format!("duplicate key: `{type_name}`")));
             }
 
-            let registration = self
-                .registry
-                .get_with_name(&key)
-                .ok_or_else(|| de::Error::custom(format!("no registration found for `{key}`")))?;
+            let paths = filtered_paths(self.registry, registration);
+
+            let reflect = if let Some((excluded, relative)) = paths {
+                let seed = FilteredDeserializer {
+                    registration,
+                    excluded,
+                    relative,
+                    registry: self.registry,
+                };
 
-            reflects.push(
-                map.next_value_seed(TypedReflectDeserializer::new(registration, self.registry))?,
-            );
+                deserialize_value(&mut map, seed, type_name, self.context)?
+            } else {
+                let seed = TypedReflectDeserializer::new(registration, self.registry);
+
+                deserialize_value(&mut map, seed, type_name, self.context)?
+            };
+
+            reflects.push(reflect);
         }
 
         Ok(reflects)
@@ -148,9 +957,18 @@ impl<'a, 'de> Visitor<'de> for ReflectsVisitor<'a> {
     {
         let mut properties = Vec::new();
 
-        while let Some(entity) =
-            seq.next_element_seed(UntypedReflectDeserializer::new(self.registry))?
-        {
+        loop {
+            let seed = UntypedReflectDeserializer::new(self.registry);
+
+            let entity = match self.context.depth {
+                Some(limit) => seq.next_element_seed(limit.seed(seed))?,
+                None => seq.next_element_seed(seed)?,
+            };
+
+            let Some(entity) = entity else {
+                break;
+            };
+
             properties.push(entity);
         }
 
@@ -162,21 +980,83 @@ impl<'a, 'de> Visitor<'de> for ReflectsVisitor<'a> {
 
 const ENTITY_STRUCT: &str = "Entity";
 const ENTITY_FIELD_COMPONENTS: &str = "components";
+const ENTITY_FIELD_DEFAULTS: &str = "defaults";
+const ENTITY_FIELD_ORDER: &str = "order";
 
 #[derive(Deserialize)]
 #[serde(field_identifier, rename_all = "lowercase")]
 enum EntityField {
     Components,
+    Defaults,
+    Order,
+}
+
+/// Returns the type names for the serialized names of compacted components.
+fn default_names<E: Error>(
+    registry: &TypeRegistryInternal,
+    context: Context,
+    names: Vec<String>,
+) -> Result<Vec<String>, E> {
+    names
+        .into_iter()
+        .map(|name| {
+            registration(registry, context, &name)
+                .map(|reg| reg.type_name().to_string())
+                .ok_or_else(|| Error::custom(format!("no registration found for `{name}`")))
+        })
+        .collect()
+}
+
+/// Deserializes the type names of compacted components, saved as serialized names or [`TypeTable`] indices.
+struct DefaultsDeserializer<'a> {
+    registry: &'a TypeRegistryInternal,
+    context: Context<'a>,
+}
+
+impl<'a> DefaultsDeserializer<'a> {
+    fn new(registry: &'a TypeRegistryInternal, context: Context<'a>) -> Self {
+        Self {
+            registry,
+            context,
+        }
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for DefaultsDeserializer<'_> {
+    type Value = Vec<String>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let Some(types) = self.context.types else {
+            return default_names(self.registry, self.context, Vec::deserialize(deserializer)?);
+        };
+
+        Vec::<u32>::deserialize(deserializer)?
+            .into_iter()
+            .map(|id| types.name(id).map(str::to_owned))
+            .collect()
+    }
 }
 
 struct EntitySerializer<'a> {
     entity: &'a SaveableEntity,
-    registry: &'a TypeRegistryArc,
+    registry: &'a TypeRegistryInternal,
+    context: Context<'a>,
 }
 
 impl<'a> EntitySerializer<'a> {
-    fn new(entity: &'a SaveableEntity, registry: &'a TypeRegistryArc) -> Self {
-        Self { entity, registry }
+    fn new(
+        entity: &'a SaveableEntity,
+        registry: &'a TypeRegistryInternal,
+        context: Context<'a>,
+    ) -> Self {
+        Self {
+            entity,
+            registry,
+            context,
+        }
     }
 }
 
@@ -185,11 +1065,39 @@ impl<'a> Serialize for EntitySerializer<'a> {
     where
         S: serde::Serializer,
     {
-        let mut state = serializer.serialize_struct(ENTITY_STRUCT, 1)?;
+        let defaults = &self.entity.defaults;
+        let order = self.entity.order;
+
+        // Formats without field names read fields by position, so the order needs the defaults
+        let with_defaults = !defaults.is_empty() || order.is_some();
+        let len = 1 + usize::from(with_defaults) + usize::from(order.is_some());
+
+        let mut state = serializer.serialize_struct(ENTITY_STRUCT, len)?;
         state.serialize_field(
             ENTITY_FIELD_COMPONENTS,
-            &ReflectsSerializer::new(&self.entity.components, self.registry),
+            &ReflectsSerializer::new(&self.entity.components, self.registry, self.context),
         )?;
+
+        if let Some(types) = self.context.types.filter(|_| with_defaults) {
+            let ids = defaults
+                .iter()
+                .map(|name| types.id(name))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            state.serialize_field(ENTITY_FIELD_DEFAULTS, &ids)?;
+        } else if with_defaults {
+            let names = defaults
+                .iter()
+                .map(|name| serialized_name(self.registry, name))
+                .collect::<Vec<_>>();
+
+            state.serialize_field(ENTITY_FIELD_DEFAULTS, &names)?;
+        }
+
+        if let Some(order) = order {
+            state.serialize_field(ENTITY_FIELD_ORDER, &order)?;
+        }
+
         state.end()
     }
 }
@@ -197,11 +1105,16 @@ impl<'a> Serialize for EntitySerializer<'a> {
 struct EntityDeserializer<'a> {
     id: u32,
     registry: &'a TypeRegistryInternal,
+    context: Context<'a>,
 }
 
 impl<'a> EntityDeserializer<'a> {
-    fn new(id: u32, registry: &'a TypeRegistryInternal) -> Self {
-        Self { id, registry }
+    fn new(id: u32, registry: &'a TypeRegistryInternal, context: Context<'a>) -> Self {
+        Self {
+            id,
+            registry,
+            context,
+        }
     }
 }
 
@@ -212,16 +1125,22 @@ impl<'a, 'de> DeserializeSeed<'de> for EntityDeserializer<'a> {
     where
         D: serde::Deserializer<'de>,
     {
-        deserializer.deserialize_struct(ENTITY_STRUCT, &[ENTITY_FIELD_COMPONENTS], EntityVisitor {
-            id: self.id,
-            registry: self.registry,
-        })
+        deserializer.deserialize_struct(
+            ENTITY_STRUCT,
+            &[ENTITY_FIELD_COMPONENTS, ENTITY_FIELD_DEFAULTS, ENTITY_FIELD_ORDER],
+            EntityVisitor {
+                id: self.id,
+                registry: self.registry,
+                context: self.context,
+            },
+        )
     }
 }
 
 struct EntityVisitor<'a> {
     id: u32,
     registry: &'a TypeRegistryInternal,
+    context: Context<'a>,
 }
 
 impl<'a, 'de> Visitor<'de> for EntityVisitor<'a> {
@@ -236,12 +1155,20 @@ impl<'a, 'de> Visitor<'de> for EntityVisitor<'a> {
         A: SeqAccess<'de>,
     {
         let components = seq
-            .next_element_seed(ReflectsDeserializer::new(self.registry))?
+            .next_element_seed(ReflectsDeserializer::new(self.registry, self.context))?
             .ok_or_else(|| Error::missing_field(ENTITY_FIELD_COMPONENTS))?;
 
+        let defaults = seq
+            .next_element_seed(DefaultsDeserializer::new(self.registry, self.context))?
+            .unwrap_or_default();
+
+        let order = seq.next_element()?;
+
         Ok(SaveableEntity {
             entity: self.id,
             components,
+            defaults,
+            order,
         })
     }
 
@@ -250,6 +1177,8 @@ impl<'a, 'de> Visitor<'de> for EntityVisitor<'a> {
         A: MapAccess<'de>,
     {
         let mut components = None;
+        let mut defaults = None;
+        let mut order = None;
 
         while let Some(key) = map.next_key()? {
             match key {
@@ -258,8 +1187,25 @@ impl<'a, 'de> Visitor<'de> for EntityVisitor<'a> {
                         return Err(Error::duplicate_field(ENTITY_FIELD_COMPONENTS));
                     }
 
-                    components =
-                        Some(map.next_value_seed(ReflectsDeserializer::new(self.registry))?);
+                    let seed = ReflectsDeserializer::new(self.registry, self.context);
+
+                    components = Some(map.next_value_seed(seed)?);
+                }
+                EntityField::Defaults => {
+                    if defaults.is_some() {
+                        return Err(Error::duplicate_field(ENTITY_FIELD_DEFAULTS));
+                    }
+
+                    let seed = DefaultsDeserializer::new(self.registry, self.context);
+
+                    defaults = Some(map.next_value_seed(seed)?);
+                }
+                EntityField::Order => {
+                    if order.is_some() {
+                        return Err(Error::duplicate_field(ENTITY_FIELD_ORDER));
+                    }
+
+                    order = Some(map.next_value()?);
                 }
             }
         }
@@ -271,6 +1217,8 @@ impl<'a, 'de> Visitor<'de> for EntityVisitor<'a> {
         Ok(SaveableEntity {
             entity: self.id,
             components,
+            defaults: defaults.unwrap_or_default(),
+            order,
         })
     }
 }
@@ -279,12 +1227,21 @@ impl<'a, 'de> Visitor<'de> for EntityVisitor<'a> {
 
 struct EntitiesSerializer<'a> {
     entities: &'a [SaveableEntity],
-    registry: &'a TypeRegistryArc,
+    registry: &'a TypeRegistryInternal,
+    context: Context<'a>,
 }
 
 impl<'a> EntitiesSerializer<'a> {
-    fn new(entities: &'a [SaveableEntity], registry: &'a TypeRegistryArc) -> Self {
-        Self { entities, registry }
+    fn new(
+        entities: &'a [SaveableEntity],
+        registry: &'a TypeRegistryInternal,
+        context: Context<'a>,
+    ) -> Self {
+        Self {
+            entities,
+            registry,
+            context,
+        }
     }
 }
 
@@ -298,7 +1255,7 @@ impl<'a> Serialize for EntitiesSerializer<'a> {
         for entity in self.entities {
             state.serialize_entry(
                 &entity.entity,
-                &EntitySerializer::new(entity, self.registry),
+                &EntitySerializer::new(entity, self.registry, self.context),
             )?;
         }
 
@@ -308,11 +1265,15 @@ impl<'a> Serialize for EntitiesSerializer<'a> {
 
 struct EntitiesDeserializer<'a> {
     registry: &'a TypeRegistryInternal,
+    context: Context<'a>,
 }
 
 impl<'a> EntitiesDeserializer<'a> {
-    fn new(registry: &'a TypeRegistryInternal) -> Self {
-        Self { registry }
+    fn new(registry: &'a TypeRegistryInternal, context: Context<'a>) -> Self {
+        Self {
+            registry,
+            context,
+        }
     }
 }
 
@@ -325,12 +1286,14 @@ impl<'a, 'de> DeserializeSeed<'de> for EntitiesDeserializer<'a> {
     {
         deserializer.deserialize_map(EntitiesVisitor {
             registry: self.registry,
+            context: self.context,
         })
     }
 }
 
 struct EntitiesVisitor<'a> {
     registry: &'a TypeRegistryInternal,
+    context: Context<'a>,
 }
 
 impl<'a, 'de> Visitor<'de> for EntitiesVisitor<'a> {
@@ -347,7 +1310,8 @@ impl<'a, 'de> Visitor<'de> for EntitiesVisitor<'a> {
         let mut entities = Vec::new();
 
         while let Some(id) = map.next_key::<u32>()? {
-            let entity = map.next_value_seed(EntityDeserializer::new(id, self.registry))?;
+            let seed = EntityDeserializer::new(id, self.registry, self.context);
+            let entity = map.next_value_seed(seed)?;
             entities.push(entity);
         }
 
@@ -355,6 +1319,155 @@ impl<'a, 'de> Visitor<'de> for EntitiesVisitor<'a> {
     }
 }
 
+// Records |-----------------------------------------------------------------------------------------------------------
+
+const RECORD_ENUM: &str = "Record";
+const RECORD_VARIANTS: &[&str] = &["Resource", "Entity"];
+
+#[derive(Deserialize)]
+enum RecordKind {
+    Resource,
+    Entity,
+}
+
+/// A single resource or entity of a snapshot, as a slice of one, written as a tagged record.
+enum Record<'a> {
+    Resource(&'a [Box<dyn Reflect>]),
+    Entity(&'a [SaveableEntity]),
+}
+
+struct RecordSerializer<'a> {
+    record: Record<'a>,
+    registry: &'a TypeRegistryInternal,
+    context: Context<'a>,
+}
+
+impl Serialize for RecordSerializer<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.record {
+            Record::Resource(resource) => {
+                let value = ReflectsSerializer::new(resource, self.registry, self.context);
+
+                serializer.serialize_newtype_variant(RECORD_ENUM, 0, RECORD_VARIANTS[0], &value)
+            }
+            Record::Entity(entity) => {
+                let value = EntitiesSerializer::new(entity, self.registry, self.context);
+
+                serializer.serialize_newtype_variant(RECORD_ENUM, 1, RECORD_VARIANTS[1], &value)
+            }
+        }
+    }
+}
+
+/// Serializes the resources and entities of a [`RawSnapshot`] as a sequence of tagged records.
+struct RecordsSerializer<'a> {
+    snapshot: &'a RawSnapshot,
+    registry: &'a TypeRegistryInternal,
+    context: Context<'a>,
+}
+
+impl Serialize for RecordsSerializer<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let len = self.snapshot.resources.len() + self.snapshot.entities.len();
+        let mut state = serializer.serialize_seq(Some(len))?;
+
+        let resources = self.snapshot.resources.chunks(1).map(Record::Resource);
+        let entities = self.snapshot.entities.chunks(1).map(Record::Entity);
+
+        for record in resources.chain(entities) {
+            state.serialize_element(&RecordSerializer {
+                record,
+                registry: self.registry,
+                context: self.context,
+            })?;
+        }
+
+        state.end()
+    }
+}
+
+struct RecordDeserializer<'a> {
+    registry: &'a TypeRegistryInternal,
+    context: Context<'a>,
+}
+
+impl<'de> DeserializeSeed<'de> for RecordDeserializer<'_> {
+    type Value = RawSnapshot;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_enum(RECORD_ENUM, RECORD_VARIANTS, self)
+    }
+}
+
+impl<'de> Visitor<'de> for RecordDeserializer<'_> {
+    type Value = RawSnapshot;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("resource or entity record")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (kind, variant) = data.variant::<RecordKind>()?;
+
+        let record = match kind {
+            RecordKind::Resource => RawSnapshot {
+                resources: variant
+                    .newtype_variant_seed(ReflectsDeserializer::new(self.registry, self.context))?,
+                entities: Vec::new(),
+            },
+            RecordKind::Entity => RawSnapshot {
+                resources: Vec::new(),
+                entities: variant
+                    .newtype_variant_seed(EntitiesDeserializer::new(self.registry, self.context))?,
+            },
+        };
+
+        Ok(record)
+    }
+}
+
+struct RecordsVisitor<'a> {
+    registry: &'a TypeRegistryInternal,
+    context: Context<'a>,
+}
+
+impl<'de> Visitor<'de> for RecordsVisitor<'_> {
+    type Value = RawSnapshot;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("sequence of records")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut snapshot = RawSnapshot::default();
+
+        while let Some(mut record) = seq.next_element_seed(RecordDeserializer {
+            registry: self.registry,
+            context: self.context,
+        })? {
+            snapshot.resources.append(&mut record.resources);
+            snapshot.entities.append(&mut record.entities);
+        }
+
+        Ok(snapshot)
+    }
+}
+
 // RawSnapshot |-------------------------------------------------------------------------------------------------------
 
 const RAW_SNAPSHOT_STRUCT: &str = "RawSnapshot";
@@ -369,12 +1482,21 @@ enum RawSnapshotFields {
 
 struct RawSnapshotSerializer<'a> {
     snapshot: &'a RawSnapshot,
-    registry: &'a TypeRegistryArc,
+    registry: &'a TypeRegistryInternal,
+    context: Context<'a>,
 }
 
 impl<'a> RawSnapshotSerializer<'a> {
-    fn new(snapshot: &'a RawSnapshot, registry: &'a TypeRegistryArc) -> Self {
-        Self { snapshot, registry }
+    fn new(
+        snapshot: &'a RawSnapshot,
+        registry: &'a TypeRegistryInternal,
+        context: Context<'a>,
+    ) -> Self {
+        Self {
+            snapshot,
+            registry,
+            context,
+        }
     }
 }
 
@@ -383,8 +1505,19 @@ impl<'a> Serialize for RawSnapshotSerializer<'a> {
     where
         S: serde::Serializer,
     {
-        let resources = ReflectsSerializer::new(&self.snapshot.resources, self.registry);
-        let entities = EntitiesSerializer::new(&self.snapshot.entities, self.registry);
+        if self.context.records {
+            return RecordsSerializer {
+                snapshot: self.snapshot,
+                registry: self.registry,
+                context: self.context,
+            }
+            .serialize(serializer);
+        }
+
+        let resources =
+            ReflectsSerializer::new(&self.snapshot.resources, self.registry, self.context);
+        let entities =
+            EntitiesSerializer::new(&self.snapshot.entities, self.registry, self.context);
 
         let mut state =
             serializer.serialize_struct(RAW_SNAPSHOT_STRUCT, RAW_SNAPSHOT_FIELDS.len())?;
@@ -398,11 +1531,15 @@ impl<'a> Serialize for RawSnapshotSerializer<'a> {
 
 struct RawSnapshotDeserializer<'a> {
     registry: &'a TypeRegistryInternal,
+    context: Context<'a>,
 }
 
 impl<'a> RawSnapshotDeserializer<'a> {
-    fn new(registry: &'a TypeRegistryInternal) -> Self {
-        Self { registry }
+    fn new(registry: &'a TypeRegistryInternal, context: Context<'a>) -> Self {
+        Self {
+            registry,
+            context,
+        }
     }
 }
 
@@ -413,11 +1550,19 @@ impl<'a, 'de> DeserializeSeed<'de> for RawSnapshotDeserializer<'a> {
     where
         D: serde::Deserializer<'de>,
     {
+        if self.context.records {
+            return deserializer.deserialize_seq(RecordsVisitor {
+                registry: self.registry,
+                context: self.context,
+            });
+        }
+
         deserializer.deserialize_struct(
             RAW_SNAPSHOT_STRUCT,
             RAW_SNAPSHOT_FIELDS,
             RawSnapshotVisitor {
                 registry: self.registry,
+                context: self.context,
             },
         )
     }
@@ -425,6 +1570,7 @@ impl<'a, 'de> DeserializeSeed<'de> for RawSnapshotDeserializer<'a> {
 
 struct RawSnapshotVisitor<'a> {
     registry: &'a TypeRegistryInternal,
+    context: Context<'a>,
 }
 
 impl<'a, 'de> Visitor<'de> for RawSnapshotVisitor<'a> {
@@ -439,11 +1585,11 @@ impl<'a, 'de> Visitor<'de> for RawSnapshotVisitor<'a> {
         V: SeqAccess<'de>,
     {
         let resources = seq
-            .next_element_seed(ReflectsDeserializer::new(self.registry))?
+            .next_element_seed(ReflectsDeserializer::new(self.registry, self.context))?
             .ok_or_else(|| de::Error::missing_field(RAW_SNAPSHOT_FIELDS[0]))?;
 
         let entities = seq
-            .next_element_seed(EntitiesDeserializer::new(self.registry))?
+            .next_element_seed(EntitiesDeserializer::new(self.registry, self.context))?
             .ok_or_else(|| de::Error::missing_field(RAW_SNAPSHOT_FIELDS[1]))?;
 
         Ok(Self::Value {
@@ -465,8 +1611,9 @@ impl<'a, 'de> Visitor<'de> for RawSnapshotVisitor<'a> {
                     if resources.is_some() {
                         return Err(de::Error::duplicate_field(RAW_SNAPSHOT_FIELDS[0]));
                     }
-                    resources =
-                        Some(map.next_value_seed(ReflectsDeserializer::new(self.registry))?);
+                    let seed = ReflectsDeserializer::new(self.registry, self.context);
+
+                    resources = Some(map.next_value_seed(seed)?);
                 }
 
                 RawSnapshotFields::Entities => {
@@ -474,7 +1621,9 @@ impl<'a, 'de> Visitor<'de> for RawSnapshotVisitor<'a> {
                         return Err(de::Error::duplicate_field(RAW_SNAPSHOT_FIELDS[1]));
                     }
 
-                    entities = Some(map.next_value_seed(EntitiesDeserializer::new(self.registry))?);
+                    let seed = EntitiesDeserializer::new(self.registry, self.context);
+
+                    entities = Some(map.next_value_seed(seed)?);
                 }
             }
         }
@@ -497,13 +1646,23 @@ const ROLLBACK_STRUCT: &str = "Rollback";
 /// A serializer for [`Rollback`] that uses reflection.
 pub struct RollbackSerializer<'a> {
     rollback: &'a Rollback,
-    registry: &'a TypeRegistryArc,
+    registry: SerializerRegistry<'a>,
+    context: Context<'a>,
 }
 
 impl<'a> RollbackSerializer<'a> {
     /// Returns a new instance of [`RollbackSerializer`].
-    pub fn new(rollback: &'a Rollback, registry: &'a TypeRegistryArc) -> Self {
-        Self { rollback, registry }
+    pub fn new(rollback: &'a Rollback, registry: impl Into<SerializerRegistry<'a>>) -> Self {
+        Self {
+            rollback,
+            registry: registry.into(),
+            context: Context::default(),
+        }
+    }
+
+    fn context(mut self, context: Context<'a>) -> Self {
+        self.context = context;
+        self
     }
 }
 
@@ -512,22 +1671,33 @@ impl<'a> Serialize for RollbackSerializer<'a> {
     where
         S: serde::Serializer,
     {
-        serializer.serialize_newtype_struct(
-            ROLLBACK_STRUCT,
-            &RawSnapshotSerializer::new(&self.rollback.snapshot, self.registry),
-        )
+        self.registry.read(|registry| {
+            serializer.serialize_newtype_struct(
+                ROLLBACK_STRUCT,
+                &RawSnapshotSerializer::new(&self.rollback.snapshot, registry, self.context),
+            )
+        })
     }
 }
 
 /// A deserializer for [`Rollback`] that uses reflection.
 pub struct RollbackDeserializer<'a> {
     registry: &'a TypeRegistryInternal,
+    context: Context<'a>,
 }
 
 impl<'a> RollbackDeserializer<'a> {
     /// Returns a new instance of [`RollbackDeserializer`].
     pub fn new(registry: &'a TypeRegistryInternal) -> Self {
-        Self { registry }
+        Self {
+            registry,
+            context: Context::default(),
+        }
+    }
+
+    fn context(mut self, context: Context<'a>) -> Self {
+        self.context = context;
+        self
     }
 }
 
@@ -538,14 +1708,18 @@ impl<'a, 'de> DeserializeSeed<'de> for RollbackDeserializer<'a> {
     where
         D: serde::Deserializer<'de>,
     {
-        deserializer.deserialize_newtype_struct(ROLLBACK_STRUCT, RollbackVisitor {
-            registry: self.registry,
+        self.context.with_names(self.registry, |context| {
+            deserializer.deserialize_newtype_struct(ROLLBACK_STRUCT, RollbackVisitor {
+                registry: self.registry,
+                context,
+            })
         })
     }
 }
 
 struct RollbackVisitor<'a> {
     registry: &'a TypeRegistryInternal,
+    context: Context<'a>,
 }
 
 impl<'a, 'de> Visitor<'de> for RollbackVisitor<'a> {
@@ -559,8 +1733,13 @@ impl<'a, 'de> Visitor<'de> for RollbackVisitor<'a> {
     where
         D: Deserializer<'de>,
     {
-        let snapshot = RawSnapshotDeserializer::new(self.registry).deserialize(deserializer)?;
-        Ok(Rollback { snapshot })
+        let snapshot =
+            RawSnapshotDeserializer::new(self.registry, self.context).deserialize(deserializer)?;
+        Ok(Rollback {
+            snapshot,
+            scope: None,
+            scoped: false,
+        })
     }
 }
 
@@ -568,14 +1747,20 @@ impl<'a, 'de> Visitor<'de> for RollbackVisitor<'a> {
 
 struct RollbackListSerializer<'a> {
     rollbacks: &'a [Rollback],
-    registry: &'a TypeRegistryArc,
+    registry: &'a TypeRegistryInternal,
+    context: Context<'a>,
 }
 
 impl<'a> RollbackListSerializer<'a> {
-    fn new(rollbacks: &'a [Rollback], registry: &'a TypeRegistryArc) -> Self {
+    fn new(
+        rollbacks: &'a [Rollback],
+        registry: &'a TypeRegistryInternal,
+        context: Context<'a>,
+    ) -> Self {
         Self {
             rollbacks,
             registry,
+            context,
         }
     }
 }
@@ -588,7 +1773,10 @@ impl<'a> Serialize for RollbackListSerializer<'a> {
         let mut seq = serializer.serialize_seq(Some(self.rollbacks.len()))?;
 
         for rollback in self.rollbacks {
-            seq.serialize_element(&RollbackSerializer::new(rollback, self.registry))?;
+            let rollback =
+                RollbackSerializer::new(rollback, self.registry).context(self.context);
+
+            seq.serialize_element(&rollback)?;
         }
 
         seq.end()
@@ -597,11 +1785,15 @@ impl<'a> Serialize for RollbackListSerializer<'a> {
 
 struct RollbackListDeserializer<'a> {
     registry: &'a TypeRegistryInternal,
+    context: Context<'a>,
 }
 
 impl<'a> RollbackListDeserializer<'a> {
-    fn new(registry: &'a TypeRegistryInternal) -> Self {
-        Self { registry }
+    fn new(registry: &'a TypeRegistryInternal, context: Context<'a>) -> Self {
+        Self {
+            registry,
+            context,
+        }
     }
 }
 
@@ -614,12 +1806,14 @@ impl<'a, 'de> DeserializeSeed<'de> for RollbackListDeserializer<'a> {
     {
         deserializer.deserialize_seq(RollbackListVisitor {
             registry: self.registry,
+            context: self.context,
         })
     }
 }
 
 struct RollbackListVisitor<'a> {
     registry: &'a TypeRegistryInternal,
+    context: Context<'a>,
 }
 
 impl<'a, 'de> Visitor<'de> for RollbackListVisitor<'a> {
@@ -635,9 +1829,9 @@ impl<'a, 'de> Visitor<'de> for RollbackListVisitor<'a> {
     {
         let mut rollbacks = Vec::new();
 
-        while let Some(rollback) =
-            seq.next_element_seed(RollbackDeserializer::new(self.registry))?
-        {
+        while let Some(rollback) = seq.next_element_seed(
+            RollbackDeserializer::new(self.registry).context(self.context),
+        )? {
             rollbacks.push(rollback);
         }
 
@@ -648,29 +1842,39 @@ impl<'a, 'de> Visitor<'de> for RollbackListVisitor<'a> {
 // Rollbacks |---------------------------------------------------------------------------------------------------------
 
 const ROLLBACKS_STRUCT: &str = "Rollbacks";
-const ROLLBACKS_FIELDS: &[&str] = &["checkpoints", "active"];
+const ROLLBACKS_FIELDS: &[&str] = &["checkpoints", "active", "pinned", "parents", "scoped"];
 
 #[derive(Deserialize)]
 #[serde(field_identifier, rename_all = "lowercase")]
 enum RollbacksFields {
     Checkpoints,
     Active,
+    Pinned,
+    Parents,
+    Scoped,
 }
 
 /// A serializer for [`Rollbacks`] that uses reflection.
 pub struct RollbacksSerializer<'a> {
     rollbacks: &'a Rollbacks,
-    registry: &'a TypeRegistryArc,
+    registry: SerializerRegistry<'a>,
+    context: Context<'a>,
 }
 
 impl<'a> RollbacksSerializer<'a> {
     /// Returns a new instance of [`RollbacksSerializer`]
-    pub fn new(rollbacks: &'a Rollbacks, registry: &'a TypeRegistryArc) -> Self {
+    pub fn new(rollbacks: &'a Rollbacks, registry: impl Into<SerializerRegistry<'a>>) -> Self {
         Self {
             rollbacks,
-            registry,
+            registry: registry.into(),
+            context: Context::default(),
         }
     }
+
+    fn context(mut self, context: Context<'a>) -> Self {
+        self.context = context;
+        self
+    }
 }
 
 impl<'a> Serialize for RollbacksSerializer<'a> {
@@ -678,26 +1882,73 @@ impl<'a> Serialize for RollbacksSerializer<'a> {
     where
         S: serde::Serializer,
     {
-        let checkpoints = RollbackListSerializer::new(&self.rollbacks.checkpoints, self.registry);
+        self.registry.read(|registry| {
+            let checkpoints =
+                RollbackListSerializer::new(&self.rollbacks.checkpoints, registry, self.context);
+
+            let pinned = &self.rollbacks.pinned;
+
+            let scoped = self
+                .rollbacks
+                .checkpoints
+                .iter()
+                .enumerate()
+                .filter_map(|(index, rollback)| rollback.scoped.then_some(index))
+                .collect::<BTreeSet<_>>();
+
+            // Linear histories are written without parents, pins are written before parents,
+            // and scoped checkpoints are written last
+            let with_parents = !self.rollbacks.is_linear() || !scoped.is_empty();
+            let length = if !scoped.is_empty() {
+                5
+            } else if with_parents {
+                4
+            } else if pinned.is_empty() {
+                2
+            } else {
+                3
+            };
+
+            let mut state = serializer.serialize_struct(ROLLBACKS_STRUCT, length)?;
+
+            state.serialize_field(ROLLBACKS_FIELDS[0], &checkpoints)?;
+            state.serialize_field(ROLLBACKS_FIELDS[1], &self.rollbacks.active)?;
+
+            if with_parents || !pinned.is_empty() {
+                state.serialize_field(ROLLBACKS_FIELDS[2], pinned)?;
+            }
 
-        let mut state = serializer.serialize_struct(ROLLBACKS_STRUCT, ROLLBACKS_FIELDS.len())?;
+            if with_parents {
+                state.serialize_field(ROLLBACKS_FIELDS[3], &self.rollbacks.parents)?;
+            }
 
-        state.serialize_field(ROLLBACKS_FIELDS[0], &checkpoints)?;
-        state.serialize_field(ROLLBACKS_FIELDS[1], &self.rollbacks.active)?;
+            if !scoped.is_empty() {
+                state.serialize_field(ROLLBACKS_FIELDS[4], &scoped)?;
+            }
 
-        state.end()
+            state.end()
+        })
     }
 }
 
 /// A deserializer for [`Rollbacks`] that uses reflection.
 pub struct RollbacksDeserializer<'a> {
     registry: &'a TypeRegistryInternal,
+    context: Context<'a>,
 }
 
 impl<'a> RollbacksDeserializer<'a> {
     /// Returns a new instance of [`RollbacksDeserializer`].
     pub fn new(registry: &'a TypeRegistryInternal) -> Self {
-        Self { registry }
+        Self {
+            registry,
+            context: Context::default(),
+        }
+    }
+
+    fn context(mut self, context: Context<'a>) -> Self {
+        self.context = context;
+        self
     }
 }
 
@@ -708,14 +1959,18 @@ impl<'a, 'de> DeserializeSeed<'de> for RollbacksDeserializer<'a> {
     where
         D: serde::Deserializer<'de>,
     {
-        deserializer.deserialize_struct(ROLLBACKS_STRUCT, ROLLBACKS_FIELDS, RollbacksVisitor {
-            registry: self.registry,
+        self.context.with_names(self.registry, |context| {
+            deserializer.deserialize_struct(ROLLBACKS_STRUCT, ROLLBACKS_FIELDS, RollbacksVisitor {
+                registry: self.registry,
+                context,
+            })
         })
     }
 }
 
 struct RollbacksVisitor<'a> {
     registry: &'a TypeRegistryInternal,
+    context: Context<'a>,
 }
 
 impl<'a, 'de> Visitor<'de> for RollbacksVisitor<'a> {
@@ -730,17 +1985,18 @@ impl<'a, 'de> Visitor<'de> for RollbacksVisitor<'a> {
         V: SeqAccess<'de>,
     {
         let checkpoints = seq
-            .next_element_seed(RollbackListDeserializer::new(self.registry))?
+            .next_element_seed(RollbackListDeserializer::new(self.registry, self.context))?
             .ok_or_else(|| de::Error::missing_field(ROLLBACKS_FIELDS[0]))?;
 
         let active = seq
             .next_element()?
             .ok_or_else(|| de::Error::missing_field(ROLLBACKS_FIELDS[1]))?;
 
-        Ok(Self::Value {
-            checkpoints,
-            active,
-        })
+        let pinned = seq.next_element()?.unwrap_or_default();
+        let parents = seq.next_element()?;
+        let scoped = seq.next_element()?.unwrap_or_default();
+
+        Ok(rollbacks(checkpoints, active, pinned, parents, scoped))
     }
 
     fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
@@ -749,6 +2005,9 @@ impl<'a, 'de> Visitor<'de> for RollbacksVisitor<'a> {
     {
         let mut checkpoints = None;
         let mut active = None;
+        let mut pinned = None;
+        let mut parents = None;
+        let mut scoped = None;
 
         while let Some(key) = map.next_key()? {
             match key {
@@ -757,8 +2016,9 @@ impl<'a, 'de> Visitor<'de> for RollbacksVisitor<'a> {
                         return Err(de::Error::duplicate_field(ROLLBACKS_FIELDS[0]));
                     }
 
-                    checkpoints =
-                        Some(map.next_value_seed(RollbackListDeserializer::new(self.registry))?);
+                    let seed = RollbackListDeserializer::new(self.registry, self.context);
+
+                    checkpoints = Some(map.next_value_seed(seed)?);
                 }
 
                 RollbacksFields::Active => {
@@ -768,6 +2028,30 @@ impl<'a, 'de> Visitor<'de> for RollbacksVisitor<'a> {
 
                     active = Some(map.next_value()?);
                 }
+
+                RollbacksFields::Pinned => {
+                    if pinned.is_some() {
+                        return Err(de::Error::duplicate_field(ROLLBACKS_FIELDS[2]));
+                    }
+
+                    pinned = Some(map.next_value()?);
+                }
+
+                RollbacksFields::Parents => {
+                    if parents.is_some() {
+                        return Err(de::Error::duplicate_field(ROLLBACKS_FIELDS[3]));
+                    }
+
+                    parents = Some(map.next_value()?);
+                }
+
+                RollbacksFields::Scoped => {
+                    if scoped.is_some() {
+                        return Err(de::Error::duplicate_field(ROLLBACKS_FIELDS[4]));
+                    }
+
+                    scoped = Some(map.next_value()?);
+                }
             }
         }
 
@@ -775,73 +2059,609 @@ impl<'a, 'de> Visitor<'de> for RollbacksVisitor<'a> {
             checkpoints.ok_or_else(|| de::Error::missing_field(ROLLBACKS_FIELDS[0]))?;
         let active = active.ok_or_else(|| de::Error::missing_field(ROLLBACKS_FIELDS[1]))?;
 
-        Ok(Self::Value {
+        Ok(rollbacks(
             checkpoints,
             active,
-        })
+            pinned.unwrap_or_default(),
+            parents,
+            scoped.unwrap_or_default(),
+        ))
+    }
+}
+
+/// Returns deserialized [`Rollbacks`], ignoring pins and scopes of checkpoints that do not exist.
+///
+/// Without parents, or with parents that do not match the checkpoints, the history is linear.
+fn rollbacks(
+    mut checkpoints: Vec<Rollback>,
+    active: Option<usize>,
+    mut pinned: BTreeSet<usize>,
+    parents: Option<Vec<Option<usize>>>,
+    scoped: BTreeSet<usize>,
+) -> Rollbacks {
+    pinned.retain(|&index| index < checkpoints.len());
+
+    for index in scoped {
+        if let Some(rollback) = checkpoints.get_mut(index) {
+            rollback.scoped = true;
+        }
+    }
+
+    let valid = |parents: &Vec<Option<usize>>| {
+        parents.len() == checkpoints.len()
+            && parents
+                .iter()
+                .enumerate()
+                .all(|(index, parent)| !parent.is_some_and(|parent| parent >= index))
+    };
+
+    let parents = parents
+        .filter(valid)
+        .unwrap_or_else(|| (0..checkpoints.len()).map(|index| index.checked_sub(1)).collect());
+
+    Rollbacks {
+        checkpoints,
+        parents,
+        active,
+        baseline: None,
+        pinned,
+        capacity: None,
     }
 }
 
 // Snapshot |----------------------------------------------------------------------------------------------------------
 
 const SNAPSHOT_STRUCT: &str = "Snapshot";
-const SNAPSHOT_FIELDS: &[&str] = &["snapshot", "rollbacks"];
+const SNAPSHOT_FIELDS: &[&str] = &["snapshot", "rollbacks", "types"];
 
 #[derive(Deserialize)]
 #[serde(field_identifier, rename_all = "lowercase")]
 enum SnapshotFields {
     Snapshot,
     Rollbacks,
+    Types,
+}
+
+/// Returns the [`TypeTable`] for the serialized names of a type table.
+fn type_table<E: Error>(
+    registry: &TypeRegistryInternal,
+    serialized: &SerializedNames,
+    names: Vec<String>,
+) -> Result<TypeTable<'static>, E> {
+    names
+        .into_iter()
+        .map(|name| {
+            serialized
+                .registration(registry, &name)
+                .map(TypeRegistration::type_name)
+                .ok_or_else(|| Error::custom(format!("no registration found for `{name}`")))
+        })
+        .collect::<Result<_, _>>()
+        .map(TypeTable)
+}
+
+/// How the App writes the type names of components and resources in saves.
+///
+/// With [`AppTypeNames::Table`], [`World::save`](crate::WorldSaveableExt::save) records it in the save header,
+/// so [`World::load`](crate::WorldSaveableExt::load) reads either kind of save regardless of the current setting.
+/// [`World::serialize`](crate::WorldSaveableExt::serialize) and [`World::deserialize`](crate::WorldSaveableExt::deserialize)
+/// have no header and always use the current setting.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// app.insert_resource(AppTypeNames::Table);
+///
+/// let world = &mut app.world;
+/// world.spawn(Transform::from_xyz(1.0, 2.0, 3.0));
+///
+/// let mut save = Vec::new();
+/// world.serialize(&mut rmp_serde::Serializer::new(&mut save)).unwrap();
+///
+/// world.clear_entities();
+/// world.deserialize(&mut rmp_serde::Deserializer::new(&save[..])).unwrap();
+///
+/// let transform = world.query::<&Transform>().single(world);
+/// assert_eq!(transform.translation, Vec3::new(1.0, 2.0, 3.0));
+/// ```
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AppTypeNames {
+    /// Write the full type name with every component and resource.
+    ///
+    /// `bevy_save` default
+    #[default]
+    Inline,
+
+    /// Write each type name once, in a table, see [`SnapshotSerializer::type_table`].
+    Table,
+}
+
+impl AppTypeNames {
+    pub(crate) fn id(self) -> u8 {
+        match self {
+            Self::Inline => 0,
+            Self::Table => 1,
+        }
+    }
+
+    pub(crate) fn from_id(id: u8) -> Option<Self> {
+        match id {
+            0 => Some(Self::Inline),
+            1 => Some(Self::Table),
+            _ => None,
+        }
+    }
 }
 
 /// A serializer for [`Snapshot`] that uses reflection.
+///
+/// Together with [`SnapshotDeserializer`], this can process save files offline against a
+/// [`TypeRegistryInternal`] built without an [`App`] or [`World`].
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy::reflect::TypeRegistryInternal;
+/// # use bevy_save::prelude::*;
+/// # use serde::{de::DeserializeSeed, Serialize};
+/// #[derive(Component, Reflect, Default)]
+/// #[reflect(Component)]
+/// struct Health(u32);
+///
+/// let mut registry = TypeRegistryInternal::default();
+/// registry.register::<Health>();
+///
+/// let save = format!(
+///     r#"{{"snapshot":{{"resources":{{}},"entities":{{"0":{{"components":{{"{}":[10]}}}}}}}}}}"#,
+///     std::any::type_name::<Health>(),
+/// );
+///
+/// let mut de = serde_json::Deserializer::from_str(&save);
+/// let snapshot = SnapshotDeserializer::new(&registry).deserialize(&mut de).unwrap();
+///
+/// let mut output = Vec::new();
+/// SnapshotSerializer::new(&snapshot, &registry)
+///     .serialize(&mut serde_json::Serializer::new(&mut output))
+///     .unwrap();
+///
+/// assert_eq!(String::from_utf8(output).unwrap(), save);
+/// ```
 pub struct SnapshotSerializer<'a> {
     snapshot: &'a Snapshot,
-    registry: &'a TypeRegistryArc,
+    registry: SerializerRegistry<'a>,
+    transform: Option<BoxedTransformValue>,
+    type_table: bool,
+    records: bool,
+    max_depth: Option<usize>,
 }
 
 impl<'a> SnapshotSerializer<'a> {
     /// Returns a new instance of [`SnapshotSerializer`].
-    pub fn new(snapshot: &'a Snapshot, registry: &'a TypeRegistryArc) -> Self {
-        Self { snapshot, registry }
+    pub fn new(snapshot: &'a Snapshot, registry: impl Into<SerializerRegistry<'a>>) -> Self {
+        Self {
+            snapshot,
+            registry: registry.into(),
+            transform: None,
+            type_table: false,
+            records: false,
+            max_depth: None,
+        }
     }
-}
 
-impl<'a> Serialize for SnapshotSerializer<'a> {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    /// Write the name of every type in the snapshot once, in a table before the snapshot,
+    /// and refer to components and resources by their index in the table instead of by name.
+    ///
+    /// This shrinks saves with many entities, where the same type names would otherwise be repeated.
+    /// Read the output with a [`SnapshotDeserializer`] using [`SnapshotDeserializer::type_table`].
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # use serde::{de::DeserializeSeed, Serialize};
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let world = &mut app.world;
+    /// for i in 0..100 {
+    ///     world.spawn((Name::new(format!("Entity {i}")), Transform::from_xyz(i as f32, 0.0, 0.0)));
+    /// }
+    ///
+    /// let snapshot = Snapshot::from_world(world);
+    /// let registry = world.resource::<AppTypeRegistry>().read();
+    ///
+    /// let to_json = |serializer: SnapshotSerializer| {
+    ///     let mut json = Vec::new();
+    ///     serializer.serialize(&mut serde_json::Serializer::new(&mut json)).unwrap();
+    ///     String::from_utf8(json).unwrap()
+    /// };
+    ///
+    /// let inline = to_json(SnapshotSerializer::new(&snapshot, &*registry));
+    /// let table = to_json(SnapshotSerializer::new(&snapshot, &*registry).type_table());
+    ///
+    /// // Each type name is written exactly once
+    /// for name in [std::any::type_name::<Name>(), std::any::type_name::<Transform>()] {
+    ///     assert_eq!(table.matches(&format!("\"{name}\"")).count(), 1);
+    /// }
+    ///
+    /// assert!(table.len() < inline.len());
+    ///
+    /// let loaded = SnapshotDeserializer::new(&registry)
+    ///     .type_table()
+    ///     .deserialize(&mut serde_json::Deserializer::from_str(&table))
+    ///     .unwrap();
+    ///
+    /// loaded.assert_matches(&snapshot);
+    /// ```
+    pub fn type_table(mut self) -> Self {
+        self.type_table = true;
+        self
+    }
+
+    /// Write the resources and entities of the snapshot as a sequence of records, each tagged `Resource` or `Entity`,
+    /// instead of as a block of resources followed by a map of entities.
+    ///
+    /// Each record can be handled as soon as it is read, without knowing how many follow,
+    /// so a streaming loader does not need to buffer the whole snapshot.
+    /// Read the output with a [`SnapshotDeserializer`] using [`SnapshotDeserializer::records`].
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # use serde::{de::DeserializeSeed, Serialize};
+    /// #[derive(Resource, Reflect, Default)]
+    /// #[reflect(Resource)]
+    /// struct Score(u32);
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// app.register_saveable::<Score>().insert_resource(Score(10));
+    ///
+    /// let world = &mut app.world;
+    ///
+    /// for i in 0..3 {
+    ///     world.spawn((Name::new(format!("Entity {i}")), Transform::from_xyz(i as f32, 0.0, 0.0)));
+    /// }
+    ///
+    /// let snapshot = Snapshot::from_world(world);
+    /// let registry = world.resource::<AppTypeRegistry>().read();
+    ///
+    /// let to_rmp = |serializer: SnapshotSerializer| {
+    ///     let mut bytes = Vec::new();
+    ///     serializer.serialize(&mut rmp_serde::Serializer::new(&mut bytes)).unwrap();
+    ///     bytes
+    /// };
+    ///
+    /// let block = to_rmp(SnapshotSerializer::new(&snapshot, &*registry));
+    /// let records = to_rmp(SnapshotSerializer::new(&snapshot, &*registry).records());
+    ///
+    /// let block = SnapshotDeserializer::new(&registry)
+    ///     .deserialize(&mut rmp_serde::Deserializer::new(&block[..]))
+    ///     .unwrap();
+    ///
+    /// let records = SnapshotDeserializer::new(&registry)
+    ///     .records()
+    ///     .deserialize(&mut rmp_serde::Deserializer::new(&records[..]))
+    ///     .unwrap();
+    ///
+    /// records.assert_matches(&block);
+    /// records.assert_matches(&snapshot);
+    /// ```
+    pub fn records(mut self) -> Self {
+        self.records = true;
+        self
+    }
+
+    /// Pass the serialized form of every component and resource through `wrap`, including those in rollbacks.
+    ///
+    /// Each value is encoded as `MessagePack`, given to `wrap` along with its type name,
+    /// and the returned bytes are written in its place. The structure of the snapshot is unchanged.
+    ///
+    /// Read the output with a [`SnapshotDeserializer`] using the matching [`SnapshotDeserializer::unwrap_values`].
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # use serde::{de::DeserializeSeed, Serialize};
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let world = &mut app.world;
+    /// fn xor(_: &str, bytes: &[u8]) -> Vec<u8> {
+    ///     bytes.iter().map(|b| b ^ 0x5A).collect()
+    /// }
+    ///
+    /// world.spawn((Name::new("Player"), Transform::from_xyz(1.0, 2.0, 3.0)));
+    ///
+    /// let snapshot = Snapshot::from_world(world);
+    /// let registry = world.resource::<AppTypeRegistry>().read();
+    ///
+    /// let mut json = Vec::new();
+    /// SnapshotSerializer::new(&snapshot, &*registry)
+    ///     .wrap_values(xor)
+    ///     .serialize(&mut serde_json::Serializer::new(&mut json))
+    ///     .unwrap();
+    ///
+    /// // Component values are no longer readable
+    /// assert!(!String::from_utf8_lossy(&json).contains("Player"));
+    ///
+    /// let loaded = SnapshotDeserializer::new(&registry)
+    ///     .unwrap_values(xor)
+    ///     .deserialize(&mut serde_json::Deserializer::from_slice(&json))
+    ///     .unwrap();
+    ///
+    /// loaded.assert_matches(&snapshot);
+    ///
+    /// // Without the matching unwrap, the values cannot be read
+    /// let result = SnapshotDeserializer::new(&registry)
+    ///     .deserialize(&mut serde_json::Deserializer::from_slice(&json));
+    ///
+    /// assert!(result.is_err());
+    /// ```
+    pub fn wrap_values<F: TransformValue + 'static>(mut self, wrap: F) -> Self {
+        self.transform = Some(Box::new(wrap));
+        self
+    }
+
+    /// Fail with [`SaveableError::DepthExceeded`](crate::SaveableError::DepthExceeded) instead of serializing
+    /// a component or resource that nests deeper than `max_depth`, see [`AppMaxDepth`](crate::AppMaxDepth).
+    ///
+    /// The error is returned as an error of the serializer.
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Serialize the snapshot, returning [`SaveableError::DepthExceeded`](crate::SaveableError::DepthExceeded)
+    /// if a value is deeper than the [`SnapshotSerializer::max_depth`].
+    pub(crate) fn serialize_checked<S>(&self, serializer: S) -> Result<(), SaveableError>
     where
         S: serde::Serializer,
+        S::Error: 'static,
     {
-        let snapshot = RawSnapshotSerializer::new(&self.snapshot.snapshot, self.registry);
+        let limit = self.max_depth.map(DepthLimit::new);
+
+        self.serialize_with(serializer, limit.as_ref())
+            .map(|_| ())
+            .map_err(|err| match &limit {
+                Some(limit) if limit.exceeded() => limit.error(),
+                _ => SaveableError::other(err),
+            })
+    }
 
-        let length = if self.snapshot.rollbacks.is_some() {
-            2
-        } else {
-            1
-        };
+    fn serialize_with<S>(
+        &self,
+        serializer: S,
+        depth: Option<&DepthLimit>,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.registry.read(|registry| {
+            let table = self.type_table.then(|| TypeTable::new(self.snapshot));
+
+            let context = Context {
+                transform: self.transform.as_deref(),
+                types: table.as_ref(),
+                records: self.records,
+                depth,
+                names: None,
+            };
+
+            let snapshot = RawSnapshotSerializer::new(&self.snapshot.snapshot, registry, context);
+
+            let length = 1
+                + usize::from(table.is_some())
+                + usize::from(self.snapshot.rollbacks.is_some());
+
+            let mut state = serializer.serialize_struct(SNAPSHOT_STRUCT, length)?;
+
+            // The type table is written first, so it is known before any type is referenced
+            if let Some(table) = &table {
+                let names = table
+                    .0
+                    .iter()
+                    .map(|name| serialized_name(registry, name))
+                    .collect::<Vec<_>>();
+
+                state.serialize_field(SNAPSHOT_FIELDS[2], &names)?;
+            }
 
-        let mut state = serializer.serialize_struct(SNAPSHOT_STRUCT, length)?;
+            state.serialize_field(SNAPSHOT_FIELDS[0], &snapshot)?;
 
-        state.serialize_field(SNAPSHOT_FIELDS[0], &snapshot)?;
+            if let Some(rollbacks) = &self.snapshot.rollbacks {
+                let rollbacks = RollbacksSerializer::new(rollbacks, registry).context(context);
+                state.serialize_field(SNAPSHOT_FIELDS[1], &rollbacks)?;
+            }
 
-        if let Some(rollbacks) = &self.snapshot.rollbacks {
-            let rollbacks = RollbacksSerializer::new(rollbacks, self.registry);
-            state.serialize_field(SNAPSHOT_FIELDS[1], &rollbacks)?;
-        }
+            state.end()
+        })
+    }
+}
 
-        state.end()
+impl<'a> Serialize for SnapshotSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let limit = self.max_depth.map(DepthLimit::new);
+        self.serialize_with(serializer, limit.as_ref())
     }
 }
 
 /// A deserializer for [`Snapshot`] that uses reflection.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// # use serde::{de::DeserializeSeed, Serialize};
+/// #[derive(Reflect, Default, Debug, Clone, PartialEq)]
+/// enum Shape {
+///     #[default]
+///     Point,
+///     Circle(f32),
+///     Rect { width: f32, height: f32 },
+/// }
+///
+/// #[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+/// #[reflect(Component)]
+/// struct Collider {
+///     shape: Shape,
+/// }
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// app.register_saveable::<Collider>()
+///     .register_type::<Shape>();
+///
+/// let world = &mut app.world;
+///
+/// let shapes = [
+///     Shape::Point,
+///     Shape::Circle(2.0),
+///     Shape::Rect { width: 1.0, height: 3.0 },
+/// ];
+///
+/// for shape in &shapes {
+///     world.spawn(Collider { shape: shape.clone() });
+/// }
+///
+/// let snapshot = Snapshot::from_world(world);
+/// let registry = world.resource::<AppTypeRegistry>().read();
+///
+/// let mut json = Vec::new();
+/// SnapshotSerializer::new(&snapshot, &*registry)
+///     .serialize(&mut serde_json::Serializer::new(&mut json))
+///     .unwrap();
+///
+/// let mut rmp = Vec::new();
+/// SnapshotSerializer::new(&snapshot, &*registry)
+///     .serialize(&mut rmp_serde::Serializer::new(&mut rmp))
+///     .unwrap();
+///
+/// let loaded = [
+///     SnapshotDeserializer::new(&registry)
+///         .deserialize(&mut serde_json::Deserializer::from_slice(&json))
+///         .unwrap(),
+///     SnapshotDeserializer::new(&registry)
+///         .deserialize(&mut rmp_serde::Deserializer::new(&rmp[..]))
+///         .unwrap(),
+/// ];
+///
+/// drop(registry);
+///
+/// // Unit, tuple and struct variants all keep their active variant and fields
+/// for snapshot in loaded {
+///     world.clear_entities();
+///     snapshot.apply(world).unwrap();
+///
+///     let mut query = world.query::<&Collider>();
+///     let loaded = query.iter(world).map(|c| c.shape.clone()).collect::<Vec<_>>();
+///
+///     assert_eq!(loaded.len(), shapes.len());
+///     assert!(shapes.iter().all(|shape| loaded.contains(shape)));
+/// }
+/// ```
 pub struct SnapshotDeserializer<'a> {
     registry: &'a TypeRegistryInternal,
+    transform: Option<BoxedTransformValue>,
+    type_table: bool,
+    records: bool,
+    max_depth: Option<usize>,
 }
 
 impl<'a> SnapshotDeserializer<'a> {
     /// Returns a new instance of [`SnapshotDeserializer`].
     pub fn new(registry: &'a TypeRegistryInternal) -> Self {
-        Self { registry }
+        Self {
+            registry,
+            transform: None,
+            type_table: false,
+            records: false,
+            max_depth: None,
+        }
+    }
+
+    /// Pass the bytes of every component and resource through `unwrap` before reading them.
+    ///
+    /// `unwrap` must reverse the [`SnapshotSerializer::wrap_values`] the snapshot was written with.
+    /// See [`SnapshotSerializer::wrap_values`] for an example.
+    pub fn unwrap_values<F: TransformValue + 'static>(mut self, unwrap: F) -> Self {
+        self.transform = Some(Box::new(unwrap));
+        self
+    }
+
+    /// Read a snapshot written with [`SnapshotSerializer::type_table`].
+    ///
+    /// Self-describing formats such as JSON are read correctly either way,
+    /// but formats that do not store field names need this to know the type table comes first.
+    pub fn type_table(mut self) -> Self {
+        self.type_table = true;
+        self
+    }
+
+    /// Read a snapshot written with [`SnapshotSerializer::records`].
+    pub fn records(mut self) -> Self {
+        self.records = true;
+        self
+    }
+
+    /// Fail with [`SaveableError::DepthExceeded`](crate::SaveableError::DepthExceeded) instead of reading
+    /// a component or resource that nests deeper than `max_depth`, see [`AppMaxDepth`](crate::AppMaxDepth).
+    ///
+    /// Reading stops as soon as the limit is reached, so an untrusted save cannot overflow the stack.
+    /// The error is returned as an error of the deserializer.
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Deserialize a snapshot, returning [`SaveableError::DepthExceeded`](crate::SaveableError::DepthExceeded)
+    /// if a value is deeper than the [`SnapshotDeserializer::max_depth`].
+    pub(crate) fn deserialize_checked<'de, D>(
+        self,
+        deserializer: D,
+    ) -> Result<Snapshot, SaveableError>
+    where
+        D: serde::Deserializer<'de>,
+        D::Error: 'static,
+    {
+        let limit = self.max_depth.map(DepthLimit::new);
+
+        self.deserialize_with(deserializer, limit.as_ref())
+            .map_err(|err| match &limit {
+                Some(limit) if limit.exceeded() => limit.error(),
+                _ => SaveableError::other(err),
+            })
+    }
+
+    fn deserialize_with<'de, D>(
+        self,
+        deserializer: D,
+        depth: Option<&DepthLimit>,
+    ) -> Result<Snapshot, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let names = SerializedNames::new(self.registry);
+
+        deserializer.deserialize_struct(SNAPSHOT_STRUCT, SNAPSHOT_FIELDS, SnapshotVisitor {
+            registry: self.registry,
+            transform: self.transform.as_deref(),
+            type_table: self.type_table,
+            records: self.records,
+            depth,
+            names: &names,
+        })
     }
 }
 
@@ -852,14 +2672,18 @@ impl<'a, 'de> DeserializeSeed<'de> for SnapshotDeserializer<'a> {
     where
         D: serde::Deserializer<'de>,
     {
-        deserializer.deserialize_struct(SNAPSHOT_STRUCT, SNAPSHOT_FIELDS, SnapshotVisitor {
-            registry: self.registry,
-        })
+        let limit = self.max_depth.map(DepthLimit::new);
+        self.deserialize_with(deserializer, limit.as_ref())
     }
 }
 
 struct SnapshotVisitor<'a> {
     registry: &'a TypeRegistryInternal,
+    transform: Option<&'a dyn TransformValue>,
+    type_table: bool,
+    records: bool,
+    depth: Option<&'a DepthLimit>,
+    names: &'a SerializedNames<'a>,
 }
 
 impl<'a, 'de> Visitor<'de> for SnapshotVisitor<'a> {
@@ -873,11 +2697,30 @@ impl<'a, 'de> Visitor<'de> for SnapshotVisitor<'a> {
     where
         V: SeqAccess<'de>,
     {
+        let table = if self.type_table {
+            let names = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::missing_field(SNAPSHOT_FIELDS[2]))?;
+
+            Some(type_table(self.registry, self.names, names)?)
+        } else {
+            None
+        };
+
+        let context = Context {
+            transform: self.transform,
+            types: table.as_ref(),
+            records: self.records,
+            depth: self.depth,
+            names: Some(self.names),
+        };
+
         let snapshot = seq
-            .next_element_seed(RawSnapshotDeserializer::new(self.registry))?
+            .next_element_seed(RawSnapshotDeserializer::new(self.registry, context))?
             .ok_or_else(|| de::Error::missing_field(SNAPSHOT_FIELDS[0]))?;
 
-        let rollbacks = seq.next_element_seed(RollbacksDeserializer::new(self.registry))?;
+        let rollbacks =
+            seq.next_element_seed(RollbacksDeserializer::new(self.registry).context(context))?;
 
         Ok(Self::Value {
             snapshot,
@@ -889,17 +2732,31 @@ impl<'a, 'de> Visitor<'de> for SnapshotVisitor<'a> {
     where
         V: MapAccess<'de>,
     {
+        let mut table = None;
         let mut snapshot = None;
         let mut rollbacks = None;
 
         while let Some(key) = map.next_key()? {
+            if self.type_table && table.is_none() && !matches!(key, SnapshotFields::Types) {
+                return Err(de::Error::missing_field(SNAPSHOT_FIELDS[2]));
+            }
+
+            let context = Context {
+                transform: self.transform,
+                types: table.as_ref(),
+                records: self.records,
+                depth: self.depth,
+                names: Some(self.names),
+            };
+
             match key {
                 SnapshotFields::Snapshot => {
                     if snapshot.is_some() {
                         return Err(de::Error::duplicate_field(SNAPSHOT_FIELDS[0]));
                     }
-                    snapshot =
-                        Some(map.next_value_seed(RawSnapshotDeserializer::new(self.registry))?);
+                    let seed = RawSnapshotDeserializer::new(self.registry, context);
+
+                    snapshot = Some(map.next_value_seed(seed)?);
                 }
 
                 SnapshotFields::Rollbacks => {
@@ -907,8 +2764,19 @@ impl<'a, 'de> Visitor<'de> for SnapshotVisitor<'a> {
                         return Err(de::Error::duplicate_field(SNAPSHOT_FIELDS[1]));
                     }
 
-                    rollbacks =
-                        Some(map.next_value_seed(RollbacksDeserializer::new(self.registry))?);
+                    let seed = RollbacksDeserializer::new(self.registry).context(context);
+
+                    rollbacks = Some(map.next_value_seed(seed)?);
+                }
+
+                SnapshotFields::Types => {
+                    if table.is_some() || snapshot.is_some() || rollbacks.is_some() {
+                        return Err(de::Error::custom(
+                            "the type table must come first and only once",
+                        ));
+                    }
+
+                    table = Some(type_table(self.registry, self.names, map.next_value()?)?);
                 }
             }
         }
@@ -0,0 +1,408 @@
+use std::collections::HashSet;
+
+use bevy::{
+    ecs::entity::EntityMap,
+    prelude::*,
+};
+
+use crate::{
+    Applier,
+    SaveableError,
+    Snapshot,
+};
+
+/// A [`Snapshot`] being applied to the [`World`] incrementally, a few entities per frame.
+///
+/// While this resource exists, [`apply_load_session`] despawns or applies up to `budget` entities each frame,
+/// removing the session and sending [`LoadCompleted`] once every entity has been applied,
+/// or [`LoadFailed`] if a step fails.
+///
+/// Entities removed by the [`DespawnMode`](crate::DespawnMode) are collected on the first frame
+/// and despawned over the following frames, before any snapshot entity is applied.
+///
+/// Entities are applied in the order recorded by [`Builder::spawn_order`](crate::Builder::spawn_order)
+/// across every frame, not only within each frame's slice.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// # let mut other = App::new();
+/// # other.add_plugins(MinimalPlugins);
+/// # other.add_plugins(SavePlugins);
+/// for i in 0..10 {
+///     app.world.spawn(Transform::from_xyz(i as f32, 0.0, 0.0));
+/// }
+///
+/// let snapshot = Snapshot::from_world(&app.world);
+///
+/// // Apply the whole snapshot at once
+/// snapshot.apply(&mut other.world).unwrap();
+///
+/// // Apply the same snapshot three entities at a time
+/// app.world.clear_entities();
+/// app.insert_resource(LoadSession::new(snapshot.clone_value()).with_budget(3));
+///
+/// let mut frames = 0;
+/// while app.world.contains_resource::<LoadSession>() {
+///     app.update();
+///     frames += 1;
+/// }
+///
+/// assert_eq!(frames, 4);
+/// assert_eq!(app.world.resource::<Events<LoadCompleted>>().len(), 1);
+///
+/// let mut positions = |world: &mut World| {
+///     let mut xs = world
+///         .query::<&Transform>()
+///         .iter(world)
+///         .map(|t| t.translation.x)
+///         .collect::<Vec<_>>();
+///     xs.sort_by(f32::total_cmp);
+///     xs
+/// };
+///
+/// assert_eq!(positions(&mut app.world), positions(&mut other.world));
+/// ```
+///
+/// Loading a snapshot recorded with [`Builder::spawn_order`](crate::Builder::spawn_order) one entity per frame:
+/// ```
+/// # use serde::de::DeserializeSeed;
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// # let mut other = App::new();
+/// # other.add_plugins(MinimalPlugins);
+/// # other.add_plugins(SavePlugins);
+/// let world = &mut app.world;
+///
+/// let first = world.spawn((Name::new("First"), SpawnTick(1))).id();
+/// world.spawn((Name::new("Second"), SpawnTick(2)));
+/// world.spawn((Name::new("Third"), SpawnTick(3)));
+///
+/// // The last entity reuses the index of the first
+/// world.despawn(first);
+/// world.spawn((Name::new("Fourth"), SpawnTick(4)));
+///
+/// let snapshot = Snapshot::builder(world)
+///     .spawn_order(true)
+///     .extract_all_entities()
+///     .build();
+///
+/// let registry = world.resource::<AppTypeRegistry>();
+/// let mut json = serde_json::to_value(SnapshotSerializer::new(&snapshot, registry)).unwrap();
+///
+/// // Store the entities by index, so the stored order differs from the spawn order
+/// let entities = json["snapshot"]["entities"].as_object_mut().unwrap();
+/// let mut sorted = std::mem::take(entities).into_iter().collect::<Vec<_>>();
+/// sorted.sort_by(|(a, _), (b, _)| a.cmp(b));
+/// entities.extend(sorted);
+///
+/// let registry = registry.read();
+/// let loaded = SnapshotDeserializer::new(&registry).deserialize(json).unwrap();
+///
+/// other.insert_resource(LoadSession::new(loaded).with_budget(1));
+///
+/// while other.world.contains_resource::<LoadSession>() {
+///     other.update();
+/// }
+///
+/// // The fresh world hands out indices in spawn order
+/// let mut spawned = other
+///     .world
+///     .query::<(Entity, &Name)>()
+///     .iter(&other.world)
+///     .map(|(entity, name)| (entity.index(), name.to_string()))
+///     .collect::<Vec<_>>();
+///
+/// spawned.sort();
+///
+/// assert_eq!(spawned, [(0, "Second".into()), (1, "Third".into()), (2, "Fourth".into())]);
+/// ```
+#[derive(Resource)]
+pub struct LoadSession {
+    snapshot: Snapshot,
+    budget: usize,
+    map: EntityMap,
+    fallback: Option<EntityMap>,
+    entities: EntityPhase,
+    despawn: DespawnPhase,
+}
+
+/// The snapshot entities a [`LoadSession`] still has to apply, sorted once in the order they are spawned.
+struct EntityPhase {
+    order: Vec<usize>,
+    cursor: usize,
+    spawned: Vec<Entity>,
+    claimed: HashSet<Entity>,
+}
+
+/// The entities a [`LoadSession`] still has to despawn, collected on its first step.
+#[derive(Default)]
+struct DespawnPhase {
+    entities: Option<Vec<Entity>>,
+    cursor: usize,
+}
+
+impl LoadSession {
+    /// The default number of entities applied per frame.
+    pub const DEFAULT_BUDGET: usize = 1024;
+
+    /// Create a new [`LoadSession`] that will apply the given [`Snapshot`].
+    pub fn new(snapshot: Snapshot) -> Self {
+        let order = snapshot.snapshot.apply_order();
+
+        let entities = EntityPhase {
+            spawned: vec![Entity::PLACEHOLDER; order.len()],
+            order,
+            cursor: 0,
+            claimed: HashSet::new(),
+        };
+
+        Self {
+            snapshot,
+            budget: Self::DEFAULT_BUDGET,
+            map: EntityMap::default(),
+            fallback: None,
+            entities,
+            despawn: DespawnPhase::default(),
+        }
+    }
+
+    /// Change the maximum number of entities despawned or applied per frame.
+    ///
+    /// A budget of `0` is treated as `1`.
+    pub fn with_budget(mut self, budget: usize) -> Self {
+        self.budget = budget.max(1);
+        self
+    }
+
+    /// Returns the number of entities applied so far.
+    pub fn applied(&self) -> usize {
+        self.entities.cursor
+    }
+
+    /// Returns the total number of entities in the [`Snapshot`].
+    pub fn total(&self) -> usize {
+        self.snapshot.snapshot.entities.len()
+    }
+
+    /// Apply the next slice of the [`Snapshot`] to the [`World`].
+    ///
+    /// The first step also applies resources.
+    /// Entities are despawned, as [`Applier::apply`] would, before the first snapshot entity is applied.
+    ///
+    /// Returns `true` once the [`Snapshot`] has been completely applied.
+    ///
+    /// # Errors
+    /// - See [`SaveableError`]
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let mut other = App::new();
+    /// # other.add_plugins(MinimalPlugins);
+    /// # other.add_plugins(SavePlugins);
+    /// let setup = |world: &mut World| {
+    ///     for i in 0..10 {
+    ///         world.spawn(Transform::from_xyz(i as f32, 0.0, 0.0));
+    ///     }
+    ///
+    ///     let snapshot = Snapshot::from_world(world);
+    ///
+    ///     // Entities missing from the snapshot, which will be despawned
+    ///     for _ in 0..20 {
+    ///         world.spawn(Name::new("Projectile"));
+    ///     }
+    ///
+    ///     snapshot
+    /// };
+    ///
+    /// setup(&mut other.world).apply(&mut other.world).unwrap();
+    ///
+    /// let mut session = LoadSession::new(setup(&mut app.world)).with_budget(4);
+    ///
+    /// let mut steps = 1;
+    /// while !session.step(&mut app.world).unwrap() {
+    ///     steps += 1;
+    /// }
+    ///
+    /// // 20 despawned and 10 applied entities, 4 per step
+    /// assert_eq!(steps, 8);
+    ///
+    /// let mut positions = |world: &mut World| {
+    ///     let mut xs = world
+    ///         .query::<&Transform>()
+    ///         .iter(world)
+    ///         .map(|t| t.translation.x)
+    ///         .collect::<Vec<_>>();
+    ///     xs.sort_by(f32::total_cmp);
+    ///     xs
+    /// };
+    ///
+    /// assert_eq!(app.world.entities().len(), other.world.entities().len());
+    /// assert_eq!(app.world.query::<&Name>().iter(&app.world).count(), 0);
+    /// assert_eq!(positions(&mut app.world), positions(&mut other.world));
+    /// ```
+    pub fn step(&mut self, world: &mut World) -> Result<bool, SaveableError> {
+        let mut applier = Applier::new(world, &self.snapshot).map(std::mem::take(&mut self.map));
+
+        let result = Self::step_applier(
+            &mut applier,
+            self.budget,
+            &mut self.fallback,
+            &mut self.entities,
+            &mut self.despawn,
+        );
+
+        self.map = std::mem::take(&mut applier.map);
+
+        let finished = result?;
+
+        if finished {
+            if let Some(rollbacks) = &self.snapshot.rollbacks {
+                rollbacks.insert_into(world);
+            }
+        }
+
+        Ok(finished)
+    }
+
+    fn step_applier(
+        applier: &mut Applier<&Snapshot>,
+        mut budget: usize,
+        fallback: &mut Option<EntityMap>,
+        entities: &mut EntityPhase,
+        despawn: &mut DespawnPhase,
+    ) -> Result<bool, SaveableError> {
+        let fallback = if let Some(fallback) = fallback {
+            fallback
+        } else {
+            let despawned = if let Some(entities) = &despawn.entities {
+                entities
+            } else {
+                applier.apply_resources()?;
+
+                despawn.entities.insert(applier.despawned())
+            };
+
+            let end = (despawn.cursor + budget).min(despawned.len());
+
+            applier.despawn_entities(&despawned[despawn.cursor..end]);
+            budget -= end - despawn.cursor;
+            despawn.cursor = end;
+
+            if despawn.cursor < despawned.len() {
+                return Ok(false);
+            }
+
+            applier.apply_between_phases();
+
+            fallback.insert(applier.fallback(&despawned.iter().copied().collect()))
+        };
+
+        let EntityPhase {
+            order,
+            cursor,
+            spawned,
+            claimed,
+        } = entities;
+
+        let end = (*cursor + budget).min(order.len());
+
+        applier.apply_entities(&order[*cursor..end], fallback, spawned, claimed)?;
+        *cursor = end;
+
+        if *cursor < order.len() {
+            return Ok(false);
+        }
+
+        applier.apply_save_ids(spawned);
+        applier.apply_entity_mapping(spawned);
+        applier.apply_reparent(spawned);
+        applier.apply_hook(spawned);
+        applier.apply_post_load();
+
+        Ok(true)
+    }
+}
+
+/// Event sent by [`apply_load_session`] once a [`LoadSession`] has been completely applied.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoadCompleted;
+
+/// Event sent by [`apply_load_session`] when a step of a [`LoadSession`] fails, with the error message.
+///
+/// The session is removed, but nothing it already did is undone: resources, despawned entities and the
+/// entities applied so far stay as they are, so the [`World`] holds a partially loaded save.
+/// The entity that failed is kept too, without the components that were not applied.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// #[derive(Component, Reflect, Default)]
+/// #[reflect(Component)]
+/// struct Velocity(Vec3);
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// # let mut other = App::new();
+/// # other.add_plugins(MinimalPlugins);
+/// # other.add_plugins(SavePlugins);
+/// app.register_saveable::<Velocity>();
+///
+/// app.world.spawn(Transform::from_xyz(1.0, 0.0, 0.0));
+/// app.world.spawn(Transform::from_xyz(2.0, 0.0, 0.0));
+/// app.world.spawn((Transform::from_xyz(3.0, 0.0, 0.0), Velocity(Vec3::X)));
+///
+/// let snapshot = Snapshot::from_world(&app.world);
+///
+/// // `other` never registered `Velocity`, so the step applying the third entity fails
+/// other.insert_resource(LoadSession::new(snapshot).with_budget(2));
+///
+/// while other.world.contains_resource::<LoadSession>() {
+///     other.update();
+/// }
+///
+/// let failed = other.world.resource::<Events<LoadFailed>>();
+/// let mut reader = failed.get_reader();
+///
+/// assert_eq!(reader.iter(failed).count(), 1);
+/// assert!(other.world.resource::<Events<LoadCompleted>>().is_empty());
+///
+/// // The entities applied so far are kept, and the failed one is missing its `Velocity`
+/// assert_eq!(other.world.query::<&Transform>().iter(&other.world).count(), 3);
+/// assert_eq!(other.world.query::<&Velocity>().iter(&other.world).count(), 0);
+/// ```
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct LoadFailed(pub String);
+
+/// Applies the next slice of the current [`LoadSession`], if any.
+///
+/// Sends [`LoadCompleted`] when the session finishes, or [`LoadFailed`] if it fails.
+/// Added to [`PreUpdate`] by [`SavePlugin`](crate::SavePlugin).
+pub fn apply_load_session(world: &mut World) {
+    let Some(mut session) = world.remove_resource::<LoadSession>() else {
+        return;
+    };
+
+    match session.step(world) {
+        Ok(true) => world.send_event(LoadCompleted),
+        Ok(false) => world.insert_resource(session),
+        Err(err) => {
+            error!("Failed to apply load session: {err}");
+            world.send_event(LoadFailed(err.to_string()));
+        }
+    }
+}
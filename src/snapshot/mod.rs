@@ -4,4 +4,51 @@ mod snapshot;
 
 pub(crate) use raw::RawSnapshot;
 pub use rollback::Rollback;
-pub use snapshot::Snapshot;
+pub use snapshot::{
+    Snapshot,
+    TypeStat,
+};
+
+use crate::SaveableRegistry;
+
+/// Internal access to the [`RawSnapshot`] backing each snapshot type.
+pub(crate) trait AsRawSnapshot {
+    fn as_raw(&self) -> &RawSnapshot;
+
+    /// Returns true if components of the type would be captured by this kind of snapshot.
+    fn captures(&self, saveables: &SaveableRegistry, type_name: &str) -> bool {
+        saveables.contains(type_name)
+    }
+}
+
+impl AsRawSnapshot for RawSnapshot {
+    fn as_raw(&self) -> &RawSnapshot {
+        self
+    }
+}
+
+impl AsRawSnapshot for Snapshot {
+    fn as_raw(&self) -> &RawSnapshot {
+        &self.snapshot
+    }
+}
+
+impl AsRawSnapshot for Rollback {
+    fn as_raw(&self) -> &RawSnapshot {
+        &self.snapshot
+    }
+
+    fn captures(&self, saveables: &SaveableRegistry, type_name: &str) -> bool {
+        saveables.contains(type_name) && saveables.can_rollback(type_name)
+    }
+}
+
+impl<T: AsRawSnapshot> AsRawSnapshot for &T {
+    fn as_raw(&self) -> &RawSnapshot {
+        (**self).as_raw()
+    }
+
+    fn captures(&self, saveables: &SaveableRegistry, type_name: &str) -> bool {
+        (**self).captures(saveables, type_name)
+    }
+}
@@ -1,18 +1,40 @@
-use std::collections::HashSet;
+use std::collections::{
+    HashMap,
+    HashSet,
+};
 
 use bevy::{
     ecs::{
+        archetype::Archetype,
+        component::ComponentId,
         entity::EntityMap,
         reflect::ReflectMapEntities,
         system::CommandQueue,
     },
     prelude::*,
-    reflect::TypeRegistration,
+    reflect::{
+        std_traits::ReflectDefault,
+        ReflectRef,
+        TypeRegistration,
+        TypeRegistryInternal,
+    },
+    tasks::{
+        ComputeTaskPool,
+        TaskPool,
+    },
+    utils::get_short_name,
 };
 
 use crate::{
+    builder::{
+        canonicalize,
+        ordered_entities,
+    },
     entity::SaveableEntity,
     prelude::*,
+    relative_time::Clock,
+    save_id::visit_entities,
+    snapshot::AsRawSnapshot,
 };
 
 pub(crate) struct RawSnapshot {
@@ -27,6 +49,101 @@ impl RawSnapshot {
             entities: Vec::default(),
         }
     }
+
+    /// Returns the positions of the entities in the order they are applied, and so spawned.
+    ///
+    /// Entities are sorted by the order recorded by [`Builder::spawn_order`], keeping the stored order otherwise.
+    pub(crate) fn apply_order(&self) -> Vec<usize> {
+        let mut positions = (0..self.entities.len()).collect::<Vec<_>>();
+        positions.sort_by_key(|&position| self.entities[position].order);
+        positions
+    }
+}
+
+/// Shared state for extracting entities from a [`World`], possibly from several threads.
+struct Capture<'a> {
+    world: &'a World,
+    components: &'a HashMap<ComponentId, &'a ReflectComponent>,
+    times: &'a HashMap<ComponentId, &'a RelativeTime>,
+    clock: Clock,
+    refs: AppEntityRefs,
+}
+
+impl Capture<'_> {
+    fn entity(&self, entity: Entity) -> SaveableEntity {
+        self.entity_into(entity, SaveableEntity {
+            entity: entity.index(),
+            components: Vec::new(),
+            defaults: Vec::new(),
+            order: None,
+        })
+    }
+
+    /// Extract an entity into a previously extracted [`SaveableEntity`], reusing its allocations.
+    ///
+    /// A recycled component of the same type at the same position is overwritten in place,
+    /// falling back to a fresh clone if applying does not reproduce the live value.
+    fn entity_into(&self, entity: Entity, mut entry: SaveableEntity) -> SaveableEntity {
+        let entity = self.world.entity(entity);
+
+        entry.entity = entity.id().index();
+        entry.defaults.clear();
+
+        let mut len = 0;
+
+        for component_id in archetype_components(entity.archetype()) {
+            let reflect = self
+                .components
+                .get(&component_id)
+                .and_then(|reflect| reflect.reflect(entity));
+
+            let Some(reflect) = reflect else {
+                continue;
+            };
+
+            let reused = entry.components.get_mut(len).is_some_and(|existing| {
+                existing.type_name() == reflect.type_name() && {
+                    existing.apply(reflect);
+                    existing.reflect_partial_eq(reflect) == Some(true)
+                }
+            });
+
+            if !reused {
+                if len < entry.components.len() {
+                    entry.components[len] = reflect.clone_value();
+                } else {
+                    entry.components.push(reflect.clone_value());
+                }
+            }
+
+            let value = &mut *entry.components[len];
+            len += 1;
+
+            if self.refs == AppEntityRefs::SaveId {
+                visit_entities(value, &mut |entity| {
+                    if let Some(id) = self.world.get::<SaveId>(*entity) {
+                        *entity = id.to_entity();
+                    }
+                });
+            }
+
+            if let Some(time) = self.times.get(&component_id) {
+                self.clock.relativize(value, time);
+            }
+        }
+
+        entry.components.truncate(len);
+
+        entry
+    }
+}
+
+/// Returns the components of the archetype in both [`Table`](bevy::ecs::storage::Table)
+/// and [`SparseSet`](bevy::ecs::storage::SparseSet) storage.
+fn archetype_components(archetype: &Archetype) -> impl Iterator<Item = ComponentId> + '_ {
+    archetype
+        .table_components()
+        .chain(archetype.sparse_set_components())
 }
 
 #[doc(hidden)]
@@ -42,32 +159,87 @@ where
 
         let saveables = self.world.resource::<SaveableRegistry>();
 
-        for entity in entities {
-            let mut entry = SaveableEntity {
-                entity: entity.index(),
-                components: Vec::new(),
+        let candidates = self
+            .world
+            .components()
+            .iter()
+            .filter(|info| saveables.contains(info.name()))
+            .filter_map(|info| Some((info.id(), registry.get(info.type_id()?)?)))
+            .collect::<Vec<_>>();
+
+        let mut components = candidates
+            .iter()
+            .filter(|(_, reg)| (self.filter)(reg))
+            .filter_map(|(id, reg)| Some((*id, reg.data::<ReflectComponent>()?)))
+            .collect::<HashMap<_, _>>();
+
+        // Keep coupled types together: if the filter splits a pair, exclude both
+        for (a, b) in saveables.coupled() {
+            let find = |name: &str| {
+                candidates
+                    .iter()
+                    .find(|(_, reg)| reg.type_name() == name)
+                    .map(|(id, _)| *id)
             };
 
-            let entity = self.world.entity(entity);
+            let (Some(a_id), Some(b_id)) = (find(a), find(b)) else {
+                continue;
+            };
 
-            for component_id in entity.archetype().components() {
-                let reflect = self
-                    .world
-                    .components()
-                    .get_info(component_id)
-                    .filter(|info| saveables.contains(info.name()))
-                    .and_then(|info| info.type_id())
-                    .and_then(|id| registry.get(id))
-                    .filter(&self.filter)
-                    .and_then(|reg| reg.data::<ReflectComponent>())
-                    .and_then(|reflect| reflect.reflect(entity));
-
-                if let Some(reflect) = reflect {
-                    entry.components.push(reflect.clone_value());
-                }
+            if components.contains_key(&a_id) != components.contains_key(&b_id) {
+                warn!("Snapshot filter splits the coupled types `{a}` and `{b}`, excluding both");
+
+                components.remove(&a_id);
+                components.remove(&b_id);
             }
+        }
+
+        let times = candidates
+            .iter()
+            .filter(|(id, _)| components.contains_key(id))
+            .filter_map(|(id, reg)| Some((*id, reg.data::<RelativeTime>()?)))
+            .collect::<HashMap<_, _>>();
+
+        let capture = Capture {
+            world: self.world,
+            components: &components,
+            times: &times,
+            clock: Clock::new(self.world),
+            refs: self
+                .world
+                .get_resource::<AppEntityRefs>()
+                .copied()
+                .unwrap_or_default(),
+        };
+
+        if self.parallel {
+            let entities = entities.collect::<Vec<_>>();
+            let pool = ComputeTaskPool::init(TaskPool::default);
+            let size = (entities.len() / pool.thread_num().max(1)).max(1);
+
+            let chunks = pool.scope(|scope| {
+                for chunk in entities.chunks(size) {
+                    let capture = &capture;
+
+                    scope.spawn(async move {
+                        chunk
+                            .iter()
+                            .map(|&entity| (entity, capture.entity(entity)))
+                            .collect::<Vec<_>>()
+                    });
+                }
+            });
+
+            self.entities.extend(chunks.into_iter().flatten());
+        } else {
+            for entity in entities {
+                let entry = match self.recycled.remove(&entity.index()) {
+                    Some(recycled) => capture.entity_into(entity, recycled),
+                    None => capture.entity(entity),
+                };
 
-            self.entities.insert(entity.id(), entry);
+                self.entities.insert(entity, entry);
+            }
         }
 
         self
@@ -95,14 +267,23 @@ where
         let registry = registry_arc.read();
 
         let saveables = self.world.resource::<SaveableRegistry>();
+        let clock = Clock::new(self.world);
 
         saveables
             .types()
             .filter_map(|name| Some((name, registry.get_with_name(name)?)))
             .filter(|(_, reg)| (self.filter)(reg))
-            .filter_map(|(name, reg)| Some((name, reg.data::<ReflectResource>()?)))
-            .filter_map(|(name, res)| Some((name, res.reflect(self.world)?)))
-            .map(|(name, reflect)| (name, reflect.clone_value()))
+            .filter_map(|(name, reg)| Some((name, reg, reg.data::<ReflectResource>()?)))
+            .filter_map(|(name, reg, res)| Some((name, reg, res.reflect(self.world)?)))
+            .map(|(name, reg, reflect)| {
+                let mut reflect = reflect.clone_value();
+
+                if let Some(time) = reg.data::<RelativeTime>() {
+                    clock.relativize(&mut *reflect, time);
+                }
+
+                (name, reflect)
+            })
             .for_each(|(name, reflect)| {
                 self.resources.insert(name.clone(), reflect);
             });
@@ -125,42 +306,28 @@ where
         self
     }
 
-    fn build(self) -> Self::Output {
+    fn build(mut self) -> Self::Output {
+        canonicalize(&mut self);
+
         RawSnapshot {
             resources: self.resources.into_values().collect(),
-            entities: self.entities.into_values().collect(),
+            entities: ordered_entities(self.world, self.entities, self.spawn_order),
         }
     }
 }
 
-impl<'a> Applier<'a, &'a RawSnapshot> {
-    pub(crate) fn apply(mut self) -> Result<(), SaveableError> {
-        let registry_arc = self.world.resource::<AppTypeRegistry>().clone();
-        let registry = registry_arc.read();
-
-        // Resources
-
-        for resource in &self.snapshot.resources {
-            let reg = registry
-                .get_with_name(resource.type_name())
-                .ok_or_else(|| SaveableError::UnregisteredType {
-                    type_name: resource.type_name().to_string(),
-                })?;
-
-            let data = reg.data::<ReflectResource>().ok_or_else(|| {
-                SaveableError::UnregisteredResource {
-                    type_name: resource.type_name().to_string(),
-                }
-            })?;
-
-            data.insert(self.world, resource.as_reflect());
-
-            if let Some(mapper) = reg.data::<ReflectMapEntities>() {
-                mapper.map_all_entities(self.world, &mut self.map);
-            }
+#[allow(private_bounds)]
+impl<S> Applier<'_, S>
+where
+    S: AsRawSnapshot,
+{
+    /// Collect the entities that the configured [`DespawnMode`] would despawn.
+    pub(crate) fn despawned(&mut self) -> Vec<Entity> {
+        if self.only_types.is_some() {
+            return Vec::new();
         }
 
-        // Entities
+        let snapshot = self.snapshot.as_raw();
 
         let despawn_default = self
             .world
@@ -170,13 +337,16 @@ impl<'a> Applier<'a, &'a RawSnapshot> {
 
         let despawn = self.despawn.as_ref().unwrap_or(&despawn_default);
 
-        match despawn {
-            DespawnMode::Missing | DespawnMode::MissingWith(_) => {
-                let valid = self
-                    .snapshot
+        let mut invalid = match despawn {
+            DespawnMode::Missing
+            | DespawnMode::MissingWith(_)
+            | DespawnMode::MissingOlderThan(_) => {
+                let positional = self.positional().unwrap_or_default();
+
+                let valid = snapshot
                     .entities
                     .iter()
-                    .map(|e| e.try_map(&self.map))
+                    .map(|e| e.map(&positional).unwrap_or_else(|| e.try_map(&self.map)))
                     .collect::<HashSet<_>>();
 
                 let mut invalid = self
@@ -191,14 +361,18 @@ impl<'a> Applier<'a, &'a RawSnapshot> {
                     invalid.retain(|e| matches.contains(e));
                 }
 
-                for entity in invalid {
-                    self.world.despawn(entity);
+                if let DespawnMode::MissingOlderThan(tick) = despawn {
+                    invalid.retain(|e| {
+                        self.world
+                            .get::<SpawnTick>(*e)
+                            .is_some_and(|spawned| spawned.0 < *tick)
+                    });
                 }
-            }
 
+                invalid
+            }
             DespawnMode::Unmapped | DespawnMode::UnmappedWith(_) => {
-                let valid = self
-                    .snapshot
+                let valid = snapshot
                     .entities
                     .iter()
                     .filter_map(|e| e.map(&self.map))
@@ -216,31 +390,61 @@ impl<'a> Applier<'a, &'a RawSnapshot> {
                     invalid.retain(|e| matches.contains(e));
                 }
 
-                for entity in invalid {
-                    self.world.despawn(entity);
-                }
+                invalid
             }
-            DespawnMode::All => {
-                let invalid = self
-                    .world
-                    .iter_entities()
-                    .map(|e| e.id())
-                    .collect::<Vec<_>>();
+            DespawnMode::All => self.world.iter_entities().map(|e| e.id()).collect(),
+            DespawnMode::AllWith(filter) => filter.collect(self.world).into_iter().collect(),
+            DespawnMode::None => Vec::new(),
+        };
+
+        if let Some(protect) = &self.protect {
+            let protected = protect.collect(self.world);
+            invalid.retain(|e| !protected.contains(e));
+        }
+
+        invalid
+    }
 
-                for entity in invalid {
-                    self.world.despawn(entity);
+    /// Build the fallback [`EntityMap`] used by the configured [`MappingMode`].
+    ///
+    /// Entities in `skip` are never used as a fallback.
+    pub(crate) fn fallback(&self, skip: &HashSet<Entity>) -> EntityMap {
+        let mapping_default = self
+            .world
+            .get_resource::<AppMappingMode>()
+            .cloned()
+            .unwrap_or_default();
+
+        let mapping = self.mapping.as_ref().unwrap_or(&mapping_default);
+
+        let mut fallback = EntityMap::default();
+
+        match &mapping {
+            MappingMode::Simple => {
+                for entity in self.world.iter_entities() {
+                    if !skip.contains(&entity.id()) {
+                        fallback.insert(Entity::from_raw(entity.id().index()), entity.id());
+                    }
                 }
             }
-            DespawnMode::AllWith(filter) => {
-                let invalid = filter.collect(self.world);
-
-                for entity in invalid {
-                    self.world.despawn(entity);
+            MappingMode::Positional => {
+                for (saved, live) in self.positional().unwrap_or_default().iter() {
+                    if !skip.contains(&live) {
+                        fallback.insert(saved, live);
+                    }
                 }
             }
-            DespawnMode::None => {}
+            MappingMode::Strict => {}
         }
 
+        fallback
+    }
+
+    /// Pair saved entities with live entities by position if using [`MappingMode::Positional`].
+    ///
+    /// Saved entities not in the user-provided map are paired, in index order,
+    /// with the live entities that are not mapped targets, in index order.
+    fn positional(&self) -> Option<EntityMap> {
         let mapping_default = self
             .world
             .get_resource::<AppMappingMode>()
@@ -249,34 +453,100 @@ impl<'a> Applier<'a, &'a RawSnapshot> {
 
         let mapping = self.mapping.as_ref().unwrap_or(&mapping_default);
 
-        let fallback = if let MappingMode::Simple = &mapping {
-            let mut fallback = EntityMap::default();
+        if !matches!(mapping, MappingMode::Positional) {
+            return None;
+        }
+
+        let mut saved = self
+            .snapshot
+            .as_raw()
+            .entities
+            .iter()
+            .filter(|e| e.map(&self.map).is_none())
+            .map(|e| e.entity)
+            .collect::<Vec<_>>();
+
+        saved.sort_unstable();
+
+        let targets = self.map.values().collect::<HashSet<_>>();
+
+        let mut live = self
+            .world
+            .iter_entities()
+            .map(|e| e.id())
+            .filter(|e| !targets.contains(e))
+            .collect::<Vec<_>>();
+
+        live.sort_unstable_by_key(|e| e.index());
+
+        let mut positional = EntityMap::default();
+
+        for (saved, live) in saved.into_iter().zip(live) {
+            positional.insert(Entity::from_raw(saved), live);
+        }
+
+        Some(positional)
+    }
 
-            for entity in self.world.iter_entities() {
-                fallback.insert(Entity::from_raw(entity.id().index()), entity.id());
+    /// Compute what applying the snapshot would do without modifying the [`World`].
+    pub(crate) fn plan(&mut self) -> Result<ApplyPlan, SaveableError> {
+        let registry_arc = self.world.resource::<AppTypeRegistry>().clone();
+        let registry = registry_arc.read();
+
+        let snapshot = self.snapshot.as_raw();
+
+        for resource in &snapshot.resources {
+            if self.skipped_resources.contains(resource.type_name())
+                || !applies(self.only_types.as_ref(), resource.type_name())
+            {
+                continue;
             }
 
-            fallback
-        } else {
-            EntityMap::default()
-        };
+            if let Err(err) = resource_data(&registry, resource.type_name()) {
+                if !self.skip_unregistered {
+                    return Err(err);
+                }
+            }
+        }
 
-        let mut spawned = Vec::new();
+        let despawned = self.despawned();
+        let skip = despawned.iter().copied().collect::<HashSet<_>>();
+        let fallback = self.fallback(&skip);
 
-        // Apply snapshot entities
-        for saved in &self.snapshot.entities {
-            let index = saved.entity;
+        let snapshot = self.snapshot.as_raw();
+
+        let mut spawned = Vec::new();
+        let mut overwritten = Vec::new();
+        let mut claimed = HashSet::new();
 
+        for saved in &snapshot.entities {
             let entity = saved
                 .map(&self.map)
-                .or_else(|| fallback.get(Entity::from_raw(index)))
-                .unwrap_or_else(|| self.world.spawn_empty().id());
+                .or_else(|| fallback.get(Entity::from_raw(saved.entity)))
+                .filter(|entity| !skip.contains(entity) && claimed.insert(*entity))
+                .and_then(|entity| self.world.get_entity(entity));
 
-            spawned.push(entity);
+            if entity.is_none() {
+                spawned.push(saved.entity);
+            }
 
-            let entity_mut = &mut self.world.entity_mut(entity);
+            let expanded = saved.expanded(&registry)?;
+            let expanded = expanded.as_ref().unwrap_or(&saved.components);
+
+            let reinterpreted = self.reinterpret.as_ref().map(|reinterpret| {
+                expanded
+                    .iter()
+                    .flat_map(|c| reinterpret(c.type_name(), &**c))
+                    .collect::<Vec<_>>()
+            });
+
+            let components = reinterpreted.as_ref().unwrap_or(expanded);
+
+            for component in components {
+                if !applies(self.only_types.as_ref(), component.type_name()) {
+                    continue;
+                }
 
-            for component in &saved.components {
                 let reg = registry
                     .get_with_name(component.type_name())
                     .ok_or_else(|| SaveableError::UnregisteredType {
@@ -289,23 +559,406 @@ impl<'a> Applier<'a, &'a RawSnapshot> {
                     }
                 })?;
 
-                data.apply_or_insert(entity_mut, &**component);
+                let Some(entity) = entity else {
+                    continue;
+                };
+
+                if preserves(&self.preserved, entity.id(), component.type_name()) {
+                    continue;
+                }
+
+                if let Some(live) = data.reflect(entity) {
+                    if !live.reflect_partial_eq(&**component).unwrap_or(false) {
+                        overwritten.push((entity.id(), component.type_name().to_string()));
+                    }
+                }
             }
         }
 
-        // ReflectMapEntities
-        for reg in registry.iter() {
+        Ok(ApplyPlan {
+            despawned,
+            spawned,
+            overwritten,
+        })
+    }
+
+    /// Insert the snapshot's resources into the [`World`].
+    pub(crate) fn apply_resources(&mut self) -> Result<(), SaveableError> {
+        let registry_arc = self.world.resource::<AppTypeRegistry>().clone();
+        let registry = registry_arc.read();
+
+        let snapshot = self.snapshot.as_raw();
+
+        for resource in &snapshot.resources {
+            if self.skipped_resources.contains(resource.type_name())
+                || !applies(self.only_types.as_ref(), resource.type_name())
+            {
+                continue;
+            }
+
+            let (reg, data) = match resource_data(&registry, resource.type_name()) {
+                Ok(found) => found,
+                Err(err) if self.skip_unregistered => {
+                    warn!("Skipping saved resource: {err}");
+                    continue;
+                }
+                Err(err) => return Err(err),
+            };
+
+            match filled(reg, resource.as_reflect()) {
+                Some(_) if data.reflect(self.world).is_some() => {
+                    data.apply(self.world, resource.as_reflect());
+                }
+                Some(filled) => data.insert(self.world, &*filled),
+                None => data.insert(self.world, resource.as_reflect()),
+            }
+
+            if self.skip_mapping {
+                continue;
+            }
+
             if let Some(mapper) = reg.data::<ReflectMapEntities>() {
                 mapper.map_all_entities(self.world, &mut self.map);
             }
         }
 
-        // Entity hook
+        Ok(())
+    }
+
+    /// Despawn every entity that the configured [`DespawnMode`] does not keep, returning the despawned entities.
+    ///
+    /// The entities are collected before any are despawned, so no query is held while despawning.
+    pub(crate) fn apply_despawn(&mut self) -> Vec<Entity> {
+        let despawned = self.despawned();
+
+        self.despawn_entities(&despawned);
+
+        despawned
+    }
+
+    /// Despawn the given entities, using the [`DespawnHook`] set with [`Applier::despawn_with`], if any.
+    ///
+    /// Entities that no longer exist are skipped.
+    pub(crate) fn despawn_entities(&mut self, entities: &[Entity]) {
+        for &entity in entities {
+            if self.world.get_entity(entity).is_none() {
+                continue;
+            }
+
+            if let Some(despawn) = &self.despawn_with {
+                despawn(self.world, entity);
+            } else {
+                self.world.despawn(entity);
+            }
+        }
+    }
+
+    /// Run the [`PhaseHook`] set with [`Applier::between_phases`], if any.
+    pub(crate) fn apply_between_phases(&mut self) {
+        if let Some(between) = &self.between {
+            between(self.world);
+        }
+    }
+
+    /// Apply the snapshot entities at `positions`, in that order, storing the entity each was applied to
+    /// at its position in `spawned`.
+    ///
+    /// Each live entity receives at most one snapshot entity: once an entity is in `claimed`,
+    /// later snapshot entities mapped to it are spawned as new entities instead.
+    ///
+    /// Callers pass slices of [`RawSnapshot::apply_order`], so entities with an order recorded by
+    /// [`Builder::spawn_order`] are applied, and so spawned, in that order, even across several calls.
+    pub(crate) fn apply_entities(
+        &mut self,
+        positions: &[usize],
+        fallback: &EntityMap,
+        spawned: &mut [Entity],
+        claimed: &mut HashSet<Entity>,
+    ) -> Result<(), SaveableError> {
+        let registry_arc = self.world.resource::<AppTypeRegistry>().clone();
+        let registry = registry_arc.read();
+
+        let snapshot = self.snapshot.as_raw();
+        let clock = Clock::new(self.world);
+
+        for &position in positions {
+            let saved = &snapshot.entities[position];
+            let index = saved.entity;
+
+            let existing = saved
+                .map(&self.map)
+                .or_else(|| fallback.get(Entity::from_raw(index)))
+                .filter(|entity| !claimed.contains(entity))
+                .filter(|entity| self.world.get_entity(*entity).is_some());
+
+            let entity = existing
+                .or_else(|| Self::spawn_at(self.world, self.layout, Entity::from_raw(index)))
+                .unwrap_or_else(|| self.world.spawn_empty().id());
+
+            claimed.insert(entity);
+            spawned[position] = entity;
+
+            if let Some(mapped) = &self.mapped {
+                mapped(index, entity);
+            }
+
+            if self.exact && self.only_types.is_none() && existing.is_some() {
+                let preserved = self.preserved.get(&entity);
+                Self::clear_saveables(self.world, &self.snapshot, entity, preserved, &registry);
+            }
+
+            let expanded = match saved.expanded(&registry) {
+                Ok(expanded) => expanded,
+                Err(err) => {
+                    recover(&mut self.failures, entity, err)?;
+                    None
+                }
+            };
+
+            let expanded = expanded.as_ref().unwrap_or(&saved.components);
+
+            let restored = restored(&registry, expanded, clock);
+            let expanded = restored.as_ref().unwrap_or(expanded);
+
+            let reinterpreted = self.reinterpret.as_ref().map(|reinterpret| {
+                expanded
+                    .iter()
+                    .flat_map(|c| reinterpret(c.type_name(), &**c))
+                    .collect::<Vec<_>>()
+            });
+
+            let components = reinterpreted.as_ref().unwrap_or(expanded);
+
+            let entity_mut = &mut self.world.entity_mut(entity);
+
+            for component in components {
+                if !applies(self.only_types.as_ref(), component.type_name())
+                    || preserves(&self.preserved, entity, component.type_name())
+                {
+                    continue;
+                }
+
+                let (reg, data) = match component_data(&registry, component.type_name()) {
+                    Ok(data) => data,
+                    Err(err) => {
+                        recover(&mut self.failures, entity, err)?;
+                        continue;
+                    }
+                };
+
+                if reg.data::<LocalOnly>().is_some() && entity_mut.contains_type_id(reg.type_id()) {
+                    continue;
+                }
+
+                let resolved = self.resolve.as_ref().and_then(|resolve| {
+                    data.reflect_mut(entity_mut)
+                        .map(|live| resolve(component.type_name(), &*live, &**component))
+                });
+
+                let component = resolved.as_ref().unwrap_or(component);
+
+                if let Some(overwrite) = &self.overwrite {
+                    let differs = data
+                        .reflect_mut(entity_mut)
+                        .is_some_and(|live| !reflect_values_eq(&*live, &**component));
+
+                    if differs {
+                        overwrite(entity, component.type_name());
+                    }
+                }
+
+                match filled(reg, &**component) {
+                    Some(filled) if !entity_mut.contains_type_id(reg.type_id()) => {
+                        data.insert(entity_mut, &*filled);
+                    }
+                    _ => data.apply_or_insert(entity_mut, &**component),
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Spawn an entity at the index of `target` if using [`Applier::preserve_layout`] and the index is free.
+    fn spawn_at(world: &mut World, layout: bool, target: Entity) -> Option<Entity> {
+        if !layout || world.get_entity(target).is_some() {
+            return None;
+        }
+
+        world.get_or_spawn(target).map(|e| e.id())
+    }
+
+    /// Re-link [`SaveId`] placeholders in the components applied to `spawned`, if using [`AppEntityRefs::SaveId`].
+    ///
+    /// Components remapped by [`Applier::apply_entity_mapping`] are skipped, as their placeholders are resolved
+    /// there in the same pass as the saved indices, so an already re-linked entity is never mapped again.
+    /// `spawned` holds the entity each snapshot entity was applied to, in snapshot order.
+    pub(crate) fn apply_save_ids(&mut self, spawned: &[Entity]) {
+        let Some(ids) = save_ids(self.world) else {
+            return;
+        };
+
+        let registry_arc = self.world.resource::<AppTypeRegistry>().clone();
+        let registry = registry_arc.read();
+
+        let snapshot = self.snapshot.as_raw();
+
+        for (saved, &entity) in snapshot.entities.iter().zip(spawned) {
+            let mut entity_mut = self.world.entity_mut(entity);
+
+            for component in &saved.components {
+                let Some(reg) = registry.get_with_name(component.type_name()) else {
+                    continue;
+                };
+
+                let mapped = reg.data::<ReflectMapEntities>().is_some()
+                    || reg.data::<EntityFields>().is_some();
+
+                if mapped && !self.skip_mapping {
+                    continue;
+                }
+
+                let Some(data) = reg.data::<ReflectComponent>() else {
+                    continue;
+                };
+
+                if let Some(mut value) = data.reflect_mut(&mut entity_mut) {
+                    visit_entities(&mut *value, &mut |entity| {
+                        let live = SaveId::from_entity(*entity).and_then(|id| ids.get(&id));
+
+                        if let Some(live) = live {
+                            *entity = *live;
+                        }
+                    });
+                }
+            }
+        }
+    }
+
+    /// Remove every component the snapshot could have captured from the entity.
+    fn clear_saveables(
+        world: &mut World,
+        snapshot: &S,
+        entity: Entity,
+        preserved: Option<&HashSet<String>>,
+        registry: &TypeRegistryInternal,
+    ) {
+        let saveables = world.resource::<SaveableRegistry>();
+
+        let captured = archetype_components(world.entity(entity).archetype())
+            .filter_map(|id| world.components().get_info(id))
+            .filter(|info| snapshot.captures(saveables, info.name()))
+            .filter(|info| !preserved.is_some_and(|names| named(names, info.name())))
+            .filter_map(|info| info.type_id())
+            .filter_map(|id| registry.get(id))
+            .filter(|reg| reg.data::<LocalOnly>().is_none())
+            .filter_map(|reg| reg.data::<ReflectComponent>())
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut entity_mut = world.entity_mut(entity);
+
+        for data in captured {
+            data.remove(&mut entity_mut);
+        }
+    }
+
+    /// Run [`ReflectMapEntities`] for every registered type, unless [`Applier::skip_entity_mapping`] is set.
+    ///
+    /// Components with [`EntityFields`] then have every [`Entity`] in their reflected value remapped.
+    /// Only the mapped entities are visited, and with [`AppEntityRefs::SaveId`] the [`SaveId`] placeholders
+    /// are mapped to their live entities in the same pass.
+    /// `spawned` holds the entity each snapshot entity was applied to, in snapshot order.
+    pub(crate) fn apply_entity_mapping(&mut self, spawned: &[Entity]) {
+        if self.skip_mapping {
+            return;
+        }
+
+        let registry_arc = self.world.resource::<AppTypeRegistry>().clone();
+        let registry = registry_arc.read();
+
+        let mut map = self.resolved_map(spawned);
+
+        let targets = map
+            .values()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        // Placeholders use the maximum generation, so they never collide with a saved index
+        for (id, live) in save_ids(self.world).into_iter().flatten() {
+            map.insert(id.to_entity(), live);
+        }
+
+        for reg in registry.iter() {
+            if let Some(mapper) = reg.data::<ReflectMapEntities>() {
+                mapper.map_entities(self.world, &mut map, &targets);
+            }
+        }
+
+        let fields = registry
+            .iter()
+            .filter(|reg| reg.data::<EntityFields>().is_some())
+            .filter_map(|reg| reg.data::<ReflectComponent>())
+            .collect::<Vec<_>>();
+
+        if fields.is_empty() {
+            return;
+        }
+
+        for &entity in &targets {
+            let Some(mut entity_mut) = self.world.get_entity_mut(entity) else {
+                continue;
+            };
+
+            for data in &fields {
+                if let Some(mut value) = data.reflect_mut(&mut entity_mut) {
+                    visit_entities(&mut *value, &mut |entity| {
+                        if let Some(mapped) = map.get(*entity) {
+                            *entity = mapped;
+                        }
+                    });
+                }
+            }
+        }
+    }
+
+    /// Attach the entities applied from snapshot roots to the parent set with [`Applier::reparent_roots_to`], if any.
+    ///
+    /// `spawned` holds the entity each snapshot entity was applied to, in snapshot order.
+    pub(crate) fn apply_reparent(&mut self, spawned: &[Entity]) {
+        let Some(parent) = self.reparent else {
+            return;
+        };
+
+        let roots = self
+            .snapshot
+            .as_raw()
+            .entities
+            .iter()
+            .zip(spawned)
+            .filter(|(saved, _)| {
+                !saved
+                    .components
+                    .iter()
+                    .any(|c| c.type_name() == std::any::type_name::<Parent>())
+            })
+            .map(|(_, &entity)| entity)
+            .filter(|&entity| entity != parent && self.world.get_entity(entity).is_some())
+            .collect::<Vec<_>>();
+
+        if let Some(mut parent) = self.world.get_entity_mut(parent) {
+            parent.push_children(&roots);
+        }
+    }
+
+    /// Run the entity [`Hook`] on each of the given entities.
+    pub(crate) fn apply_hook(&mut self, spawned: &[Entity]) {
         if let Some(hook) = &self.hook {
             let mut queue = CommandQueue::default();
             let mut commands = Commands::new(&mut queue, self.world);
 
-            for entity in spawned {
+            for &entity in spawned {
                 let entity_ref = self.world.entity(entity);
                 let mut entity_mut = commands.entity(entity);
 
@@ -314,9 +967,251 @@ impl<'a> Applier<'a, &'a RawSnapshot> {
 
             queue.apply(self.world);
         }
+    }
 
-        Ok(())
+    /// Run the [`PostLoad`] schedule, if it exists.
+    pub(crate) fn apply_post_load(&mut self) {
+        self.world.try_run_schedule(PostLoad).ok();
     }
+
+    /// Build the [`EntityMap`] from saved entity indices to the entities they were applied to.
+    ///
+    /// `applied` holds the entity each snapshot entity was applied to, in snapshot order.
+    /// Entries in the user-provided map that are not part of the snapshot are kept.
+    pub(crate) fn resolved_map(&self, applied: &[Entity]) -> EntityMap {
+        let mut map = EntityMap::default();
+
+        for (saved, live) in self.map.iter() {
+            map.insert(saved, live);
+        }
+
+        for (saved, &live) in self.snapshot.as_raw().entities.iter().zip(applied) {
+            map.insert(Entity::from_raw(saved.entity), live);
+        }
+
+        map
+    }
+
+    /// Apply the snapshot, returning the entity each snapshot entity was applied to, in snapshot order.
+    ///
+    /// The phases run in a fixed order:
+    /// 1. Resources are applied.
+    /// 2. Entities rejected by the [`DespawnMode`] are despawned.
+    /// 3. The [`PhaseHook`] set with [`Applier::between_phases`] runs.
+    /// 4. The fallback [`EntityMap`] is built from the remaining entities, never including a despawned entity.
+    /// 5. Snapshot entities are applied to their mapped entity, or spawned. Each live entity is claimed by
+    ///    at most one snapshot entity, so an entity spawned during this phase is never reused as a target.
+    /// 6. Entity references are re-linked, roots are attached to the [`Applier::reparent_roots_to`] parent,
+    ///    and the [`Hook`] runs.
+    /// 7. The [`PostLoad`] schedule runs.
+    pub(crate) fn apply_raw(&mut self) -> Result<Vec<Entity>, SaveableError> {
+        self.apply_resources()?;
+        self.apply_entity_phases()
+    }
+
+    /// Apply the snapshot entities, skipping resources, returning the entity each snapshot entity was applied to.
+    ///
+    /// Runs every phase of [`Applier::apply_raw`] after resources are applied.
+    pub(crate) fn apply_entity_phases(&mut self) -> Result<Vec<Entity>, SaveableError> {
+        let despawned = self.apply_despawn();
+
+        self.apply_between_phases();
+
+        let fallback = self.fallback(&despawned.into_iter().collect());
+        let order = self.snapshot.as_raw().apply_order();
+
+        let mut spawned = vec![Entity::PLACEHOLDER; order.len()];
+        let mut claimed = HashSet::new();
+
+        self.apply_entities(&order, &fallback, &mut spawned, &mut claimed)?;
+        self.apply_save_ids(&spawned);
+        self.apply_entity_mapping(&spawned);
+        self.apply_reparent(&spawned);
+        self.apply_hook(&spawned);
+        self.apply_post_load();
+
+        Ok(spawned)
+    }
+}
+
+/// Returns the live entity of each [`SaveId`], or `None` if not using [`AppEntityRefs::SaveId`].
+fn save_ids(world: &mut World) -> Option<HashMap<SaveId, Entity>> {
+    let refs = world.get_resource::<AppEntityRefs>().copied().unwrap_or_default();
+
+    if refs != AppEntityRefs::SaveId {
+        return None;
+    }
+
+    let ids = world
+        .query::<(Entity, &SaveId)>()
+        .iter(world)
+        .map(|(entity, id)| (*id, entity))
+        .collect();
+
+    Some(ids)
+}
+
+/// Returns true if the type is applied under [`Applier::only_types`], always true if it is not set.
+fn applies(only: Option<&HashSet<String>>, type_name: &str) -> bool {
+    match only {
+        Some(only) => named(only, type_name),
+        None => true,
+    }
+}
+
+/// Returns true if the type is kept on `entity` with [`Applier::preserve`].
+fn preserves(
+    preserved: &HashMap<Entity, HashSet<String>>,
+    entity: Entity,
+    type_name: &str,
+) -> bool {
+    preserved
+        .get(&entity)
+        .is_some_and(|names| named(names, type_name))
+}
+
+/// Returns true if `names` contains the full or short name of the type.
+fn named(names: &HashSet<String>, type_name: &str) -> bool {
+    names.contains(type_name) || names.contains(&get_short_name(type_name))
+}
+
+/// Returns the registration and [`ReflectComponent`] of a saved component.
+fn component_data<'r>(
+    registry: &'r TypeRegistryInternal,
+    type_name: &str,
+) -> Result<(&'r TypeRegistration, &'r ReflectComponent), SaveableError> {
+    let reg = registry
+        .get_with_name(type_name)
+        .ok_or_else(|| SaveableError::UnregisteredType {
+            type_name: type_name.to_string(),
+        })?;
+
+    let data = reg
+        .data::<ReflectComponent>()
+        .ok_or_else(|| SaveableError::UnregisteredComponent {
+            type_name: type_name.to_string(),
+        })?;
+
+    Ok((reg, data))
+}
+
+/// Returns the registration and [`ReflectResource`] of a saved resource.
+fn resource_data<'r>(
+    registry: &'r TypeRegistryInternal,
+    type_name: &str,
+) -> Result<(&'r TypeRegistration, &'r ReflectResource), SaveableError> {
+    let reg = registry
+        .get_with_name(type_name)
+        .ok_or_else(|| SaveableError::UnregisteredType {
+            type_name: type_name.to_string(),
+        })?;
+
+    let data = reg
+        .data::<ReflectResource>()
+        .ok_or_else(|| SaveableError::UnregisteredResource {
+            type_name: type_name.to_string(),
+        })?;
+
+    Ok((reg, data))
+}
+
+/// Record a per-entity error if applying with [`Applier::apply_lenient`], or return it otherwise.
+fn recover(
+    failures: &mut Option<Vec<(Entity, SaveableError)>>,
+    entity: Entity,
+    err: SaveableError,
+) -> Result<(), SaveableError> {
+    match failures {
+        Some(failures) => {
+            failures.push((entity, err));
+            Ok(())
+        }
+        None => Err(err),
+    }
+}
+
+/// Returns the components with their [`RelativeTime`] fields restored, or `None` if none have relative time fields.
+fn restored(
+    registry: &TypeRegistryInternal,
+    components: &[Box<dyn Reflect>],
+    clock: Clock,
+) -> Option<Vec<Box<dyn Reflect>>> {
+    let time = |component: &dyn Reflect| {
+        registry
+            .get_with_name(component.type_name())
+            .and_then(|reg| reg.data::<RelativeTime>())
+    };
+
+    if !components.iter().any(|c| time(c.as_reflect()).is_some()) {
+        return None;
+    }
+
+    let components = components
+        .iter()
+        .map(|c| match time(c.as_reflect()) {
+            Some(time) => clock.restore(c.as_reflect(), time),
+            None => c.clone_value(),
+        })
+        .collect();
+
+    Some(components)
+}
+
+/// Returns a complete value for a type with a [`FieldFilter`], filling the excluded fields from its default.
+///
+/// Values saved with a [`FieldFilter`] cannot be inserted directly, as they are missing fields.
+fn filled(reg: &TypeRegistration, value: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+    reg.data::<FieldFilter>()?;
+
+    let mut filled = reg.data::<ReflectDefault>()?.default();
+    filled.apply(value);
+
+    Some(filled)
+}
+
+impl RawSnapshot {
+    /// Returns a best-effort estimate of the memory used by the snapshot, in bytes.
+    pub(crate) fn estimated_bytes(&self) -> usize {
+        let reflects = |reflects: &[Box<dyn Reflect>]| {
+            reflects
+                .iter()
+                .map(|r| std::mem::size_of::<Box<dyn Reflect>>() + reflect_bytes(r.as_reflect()))
+                .sum::<usize>()
+        };
+
+        let entities = self
+            .entities
+            .iter()
+            .map(|e| {
+                std::mem::size_of::<SaveableEntity>()
+                    + reflects(&e.components)
+                    + e.defaults.iter().map(String::capacity).sum::<usize>()
+            })
+            .sum::<usize>();
+
+        std::mem::size_of::<Self>() + reflects(&self.resources) + entities
+    }
+}
+
+/// Returns a best-effort estimate of the memory used by a reflected value, in bytes.
+///
+/// Snapshots store dynamic clones, so each value is counted along with the values it contains.
+fn reflect_bytes(value: &dyn Reflect) -> usize {
+    let children = match value.reflect_ref() {
+        ReflectRef::Struct(value) => value.iter_fields().map(reflect_bytes).sum(),
+        ReflectRef::TupleStruct(value) => value.iter_fields().map(reflect_bytes).sum(),
+        ReflectRef::Tuple(value) => value.iter_fields().map(reflect_bytes).sum(),
+        ReflectRef::List(value) => value.iter().map(reflect_bytes).sum(),
+        ReflectRef::Array(value) => value.iter().map(reflect_bytes).sum(),
+        ReflectRef::Map(value) => value
+            .iter()
+            .map(|(k, v)| reflect_bytes(k) + reflect_bytes(v))
+            .sum(),
+        ReflectRef::Enum(value) => value.iter_fields().map(|f| reflect_bytes(f.value())).sum(),
+        ReflectRef::Value(value) => value.downcast_ref::<String>().map_or(0, String::capacity),
+    };
+
+    std::mem::size_of_val(value) + children
 }
 
 impl CloneReflect for RawSnapshot {
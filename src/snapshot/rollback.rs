@@ -1,9 +1,20 @@
+use std::collections::HashMap;
+
 use bevy::{
+    ecs::{
+        entity::EntityMap,
+        query::ReadOnlyWorldQuery,
+    },
     prelude::*,
     reflect::TypeRegistration,
 };
 
 use crate::{
+    builder::{
+        canonicalize,
+        ordered_entities,
+    },
+    entity::SaveableEntity,
     prelude::*,
     snapshot::RawSnapshot,
 };
@@ -13,6 +24,8 @@ use crate::{
 /// [`Rollback`] excludes types that opt out of rollback.
 pub struct Rollback {
     pub(crate) snapshot: RawSnapshot,
+    pub(crate) scope: Option<fn() -> BoxedFilter>,
+    pub(crate) scoped: bool,
 }
 
 impl Rollback {
@@ -59,6 +72,96 @@ impl Rollback {
         Self::builder(world).filter(filter).extract_all().build()
     }
 
+    /// Returns a [`Rollback`] of only the entities matching the [`ReadOnlyWorldQuery`] `F`, along with all rollback resources.
+    ///
+    /// Applying it only affects entities matching `F`: by default, only entities matching `F` that are missing from the
+    /// [`Rollback`] are despawned, so entities outside of it, such as UI or cameras, keep their current state.
+    /// The query filter itself cannot be serialized, but [`Rollbacks`] keeps which of its checkpoints are scoped.
+    /// A loaded scoped rollback does not despawn any entities, instead of falling back to despawning every entity.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Component, Reflect, Default)]
+    /// #[reflect(Component)]
+    /// struct Sim;
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// app.register_saveable::<Sim>();
+    ///
+    /// let world = &mut app.world;
+    /// let body = world.spawn((Sim, Transform::from_xyz(1.0, 0.0, 0.0))).id();
+    /// let menu = world.spawn(Transform::from_xyz(2.0, 0.0, 0.0)).id();
+    ///
+    /// let rollback = Rollback::from_world_entities::<With<Sim>>(world);
+    /// world.resource_mut::<Rollbacks>().checkpoint(rollback);
+    ///
+    /// // Change both the simulation and the UI, and spawn a new simulation entity
+    /// world.get_mut::<Transform>(body).unwrap().translation.x = 10.0;
+    /// world.get_mut::<Transform>(menu).unwrap().translation.x = 20.0;
+    /// let debris = world.spawn((Sim, Transform::default())).id();
+    ///
+    /// world.rollback(0).unwrap();
+    ///
+    /// assert_eq!(world.get::<Transform>(body).unwrap().translation.x, 1.0);
+    /// assert_eq!(world.get::<Transform>(menu).unwrap().translation.x, 20.0);
+    /// assert!(world.get_entity(debris).is_none());
+    /// ```
+    ///
+    /// A loaded scoped checkpoint still leaves entities outside of it in place:
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Component, Reflect, Default)]
+    /// #[reflect(Component)]
+    /// struct Sim;
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// app.register_saveable::<Sim>();
+    ///
+    /// let world = &mut app.world;
+    /// world.spawn((Sim, Transform::from_xyz(1.0, 0.0, 0.0)));
+    /// world.spawn((Transform::from_xyz(2.0, 0.0, 0.0), Name::new("Menu")));
+    ///
+    /// let rollback = Rollback::from_world_entities::<With<Sim>>(world);
+    /// world.resource_mut::<Rollbacks>().checkpoint(rollback);
+    ///
+    /// let mut save = Vec::new();
+    /// world.serialize(&mut serde_json::Serializer::new(&mut save)).unwrap();
+    /// world.deserialize(&mut serde_json::Deserializer::from_slice(&save)).unwrap();
+    ///
+    /// let mut sim = world.query_filtered::<&mut Transform, With<Sim>>();
+    /// sim.single_mut(world).translation.x = 10.0;
+    ///
+    /// world.rollback(0).unwrap();
+    ///
+    /// assert_eq!(sim.single(world).translation.x, 1.0);
+    /// assert_eq!(world.query::<&Name>().iter(world).count(), 1);
+    /// ```
+    pub fn from_world_entities<F>(world: &mut World) -> Self
+    where
+        F: ReadOnlyWorldQuery + Send + Sync + 'static,
+    {
+        let entities = world
+            .query_filtered::<Entity, F>()
+            .iter(world)
+            .collect::<Vec<_>>();
+
+        let mut rollback = Self::builder(world)
+            .extract_entities(entities.into_iter())
+            .extract_all_resources()
+            .build();
+
+        rollback.scope = Some(<dyn Filter>::boxed::<F>);
+        rollback.scoped = true;
+        rollback
+    }
+
     /// Create a [`Builder`] from the [`World`], allowing you to create partial or filtered snapshots.
     /// 
     /// # Example
@@ -86,6 +189,67 @@ impl Rollback {
         Builder::new(world)
     }
 
+    /// Capture the current [`World`] state into this [`Rollback`], like [`Rollback::from_world`],
+    /// reusing the allocations of the previously captured entities.
+    ///
+    /// Components of an entity with the same index are overwritten in place where possible,
+    /// so capturing a mostly unchanged [`World`] every frame allocates little.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let world = &mut app.world;
+    /// let state = |world: &mut World| {
+    ///     let mut state = world
+    ///         .query::<(Entity, Option<&Transform>, Option<&Name>)>()
+    ///         .iter(world)
+    ///         .map(|(e, t, n)| (e, t.map(|t| t.translation), n.map(|n| n.to_string())))
+    ///         .collect::<Vec<_>>();
+    ///     state.sort_by_key(|(e, ..)| *e);
+    ///     state
+    /// };
+    ///
+    /// let player = world.spawn(Transform::default()).id();
+    /// let enemy = world.spawn((Transform::default(), Name::new("Enemy"))).id();
+    ///
+    /// let mut rollback = Rollback::from_world(world);
+    ///
+    /// for frame in 1..=3 {
+    ///     // Change the layout of the world between captures
+    ///     world.get_mut::<Transform>(player).unwrap().translation.x = frame as f32;
+    ///     world.entity_mut(enemy).remove::<Name>();
+    ///     world.spawn(Name::new(format!("Spawned on frame {frame}")));
+    ///
+    ///     rollback.capture_into(world);
+    ///     let captured = state(world);
+    ///
+    ///     world.get_mut::<Transform>(player).unwrap().translation.x = 100.0;
+    ///     for mut name in world.query::<&mut Name>().iter_mut(world) {
+    ///         name.set("Renamed");
+    ///     }
+    ///
+    ///     rollback.apply(world).unwrap();
+    ///
+    ///     assert_eq!(state(world), captured);
+    /// }
+    /// ```
+    pub fn capture_into(&mut self, world: &World) {
+        let mut builder = Self::builder(world);
+
+        builder.recycled = self
+            .snapshot
+            .entities
+            .drain(..)
+            .map(|entity| (entity.entity, entity))
+            .collect();
+
+        *self = builder.extract_all().build();
+    }
+
     /// Apply the [`Rollback`] to the [`World`].
     ///
     /// # Errors
@@ -94,6 +258,140 @@ impl Rollback {
         self.applier(world).apply()
     }
 
+    /// Apply only part of the [`Rollback`] to the [`World`], leaving all other live state intact.
+    ///
+    /// Only saved entities whose live counterpart (the entity with the same index) matches the [`ReadOnlyWorldQuery`] `F`
+    /// are applied, and only components and resources matching `type_filter` are restored.
+    /// Nothing is spawned or despawned.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    /// #[reflect(Component)]
+    /// struct Health(u32);
+    ///
+    /// #[derive(Component)]
+    /// struct Player;
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// app.register_saveable::<Health>();
+    ///
+    /// let world = &mut app.world;
+    /// let player = world.spawn((Player, Health(100), Transform::default())).id();
+    /// let enemy = world.spawn((Health(50), Transform::default())).id();
+    ///
+    /// let checkpoint = Rollback::from_world(world);
+    ///
+    /// for entity in [player, enemy] {
+    ///     world
+    ///         .entity_mut(entity)
+    ///         .insert((Health(1), Transform::from_xyz(5.0, 0.0, 0.0)));
+    /// }
+    ///
+    /// // Restore the player's health, keeping everything else current
+    /// checkpoint
+    ///     .apply_filtered::<With<Player>>(world, |reg| {
+    ///         reg.type_name() == std::any::type_name::<Health>()
+    ///     })
+    ///     .unwrap();
+    ///
+    /// assert_eq!(world.get::<Health>(player), Some(&Health(100)));
+    /// assert_eq!(world.get::<Health>(enemy), Some(&Health(1)));
+    ///
+    /// for entity in [player, enemy] {
+    ///     assert_eq!(world.get::<Transform>(entity).unwrap().translation.x, 5.0);
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// - See [`SaveableError`]
+    pub fn apply_filtered<F>(
+        &self,
+        world: &mut World,
+        type_filter: impl Fn(&&TypeRegistration) -> bool,
+    ) -> Result<(), SaveableError>
+    where
+        F: ReadOnlyWorldQuery,
+    {
+        let targets = world
+            .query_filtered::<Entity, F>()
+            .iter(world)
+            .map(|entity| (entity.index(), entity))
+            .collect::<HashMap<_, _>>();
+
+        let registry_arc = world.resource::<AppTypeRegistry>().clone();
+        let registry = registry_arc.read();
+
+        let matches = |value: &dyn Reflect| {
+            registry
+                .get_with_name(value.type_name())
+                .is_some_and(|reg| type_filter(&reg))
+        };
+
+        let mut map = EntityMap::default();
+
+        let entities = self
+            .snapshot
+            .entities
+            .iter()
+            .filter_map(|saved| {
+                let target = targets.get(&saved.entity)?;
+                map.insert(Entity::from_raw(saved.entity), *target);
+
+                Some(SaveableEntity {
+                    entity: saved.entity,
+                    components: saved
+                        .components
+                        .iter()
+                        .filter(|c| matches(c.as_reflect()))
+                        .map(|c| c.clone_value())
+                        .collect(),
+                    defaults: saved
+                        .defaults
+                        .iter()
+                        .filter(|name| {
+                            registry
+                                .get_with_name(name)
+                                .is_some_and(|reg| type_filter(&reg))
+                        })
+                        .cloned()
+                        .collect(),
+                    order: saved.order,
+                })
+            })
+            .collect();
+
+        let resources = self
+            .snapshot
+            .resources
+            .iter()
+            .filter(|r| matches(r.as_reflect()))
+            .map(|r| r.clone_value())
+            .collect();
+
+        drop(registry);
+
+        let partial = Self {
+            snapshot: RawSnapshot {
+                resources,
+                entities,
+            },
+            scope: None,
+            scoped: false,
+        };
+
+        partial
+            .applier(world)
+            .map(map)
+            .despawn(DespawnMode::None)
+            .mapping(MappingMode::Strict)
+            .apply()
+    }
+
     /// Create an [`Applier`] from the [`Rollback`] and the [`World`].
     ///
     /// # Example
@@ -115,12 +413,28 @@ impl Rollback {
     ///     .apply();
     /// ```
     pub fn applier<'a>(&'a self, world: &'a mut World) -> Applier<'a, &'a Self> {
-        Applier::new(world, self)
+        Self::scoped(Applier::new(world, self), self.scope, self.scoped)
     }
 
     /// Create an owning [`Applier`] from the [`Rollback`] and the [`World`].
     pub fn into_applier(self, world: &mut World) -> Applier<Self> {
-        Applier::new(world, self)
+        let (scope, scoped) = (self.scope, self.scoped);
+        Self::scoped(Applier::new(world, self), scope, scoped)
+    }
+
+    /// Only despawn entities in the scope of a [`Rollback::from_world_entities`] rollback.
+    ///
+    /// A loaded scoped rollback has lost its filter, so it despawns nothing.
+    fn scoped<S>(
+        applier: Applier<S>,
+        scope: Option<fn() -> BoxedFilter>,
+        scoped: bool,
+    ) -> Applier<S> {
+        match scope {
+            Some(scope) => applier.despawn(DespawnMode::MissingWith(scope())),
+            None if scoped => applier.despawn(DespawnMode::None),
+            None => applier,
+        }
     }
 }
 
@@ -137,9 +451,14 @@ where
             .filter(|reg: &&TypeRegistration| {
                 registry.can_rollback(reg.type_name()) && (self.filter)(reg)
             })
-            .extract_entities(entities);
+            .parallel(self.parallel);
+
+        builder.recycled = std::mem::take(&mut self.recycled);
+
+        let mut builder = builder.extract_entities(entities);
 
         self.entities.append(&mut builder.entities);
+        self.recycled = std::mem::take(&mut builder.recycled);
 
         self
     }
@@ -192,12 +511,16 @@ where
         self
     }
 
-    fn build(self) -> Self::Output {
+    fn build(mut self) -> Self::Output {
+        canonicalize(&mut self);
+
         Rollback {
             snapshot: RawSnapshot {
-                entities: self.entities.into_values().collect(),
+                entities: ordered_entities(self.world, self.entities, self.spawn_order),
                 resources: self.resources.into_values().collect(),
             },
+            scope: None,
+            scoped: false,
         }
     }
 }
@@ -210,16 +533,33 @@ macro_rules! impl_rollback_applier {
             /// # Errors
             /// - See [`SaveableError`]
             pub fn apply(self) -> Result<(), SaveableError> {
-                let applier = Applier {
-                    world: self.world,
-                    snapshot: &self.snapshot.snapshot,
-                    map: self.map,
-                    despawn: self.despawn,
-                    mapping: self.mapping,
-                    hook: self.hook,
-                };
-
-                applier.apply()
+                self.apply_and_return_map().map(|_| ())
+            }
+
+            /// Apply the [`Rollback`], returning the [`EntityMap`] from saved entity indices to the entities they were applied to.
+            ///
+            /// Saved indices are keyed as [`Entity::from_raw`], like the map passed to [`Applier::map`].
+            ///
+            /// # Errors
+            /// - See [`SaveableError`]
+            pub fn apply_and_return_map(mut self) -> Result<EntityMap, SaveableError> {
+                let applied = self.apply_raw()?;
+                Ok(self.resolved_map(&applied))
+            }
+
+            /// Compute what applying the [`Rollback`] would do, without modifying the [`World`].
+            ///
+            /// # Errors
+            /// - See [`SaveableError`]
+            pub fn dry_run(&mut self) -> Result<ApplyPlan, SaveableError> {
+                self.plan()
+            }
+
+            /// Returns the existing entities that the configured [`DespawnMode`] would despawn when applying the [`Rollback`].
+            ///
+            /// This is cheaper than [`Applier::dry_run`], as only the despawn phase is computed.
+            pub fn despawn_preview(&mut self) -> Vec<Entity> {
+                self.despawned()
             }
         }
     };
@@ -232,6 +572,8 @@ impl CloneReflect for Rollback {
     fn clone_value(&self) -> Self {
         Self {
             snapshot: self.snapshot.clone_value(),
+            scope: self.scope,
+            scoped: self.scoped,
         }
     }
 }
@@ -1,15 +1,62 @@
-use std::collections::HashSet;
+use std::{
+    cmp::Reverse,
+    collections::{
+        BTreeMap,
+        BTreeSet,
+        HashSet,
+    },
+    fmt::Write,
+    fs::File,
+    io::BufReader,
+    path::Path,
+};
 
 use bevy::{
+    ecs::{
+        archetype::Archetype,
+        entity::EntityMap,
+        query::ReadOnlyWorldQuery,
+    },
     prelude::*,
-    reflect::TypeRegistration,
+    reflect::{
+        std_traits::ReflectDefault,
+        TypeRegistration,
+        TypeRegistryInternal,
+    },
 };
 
+#[cfg(feature = "bevy_scene")]
+use bevy::scene::DynamicEntity;
+
+#[cfg(feature = "bevy_scene")]
+use crate::entity::SaveableEntity;
 use crate::{
+    builder::{
+        canonicalize,
+        ordered_entities,
+    },
+    compare::snapshot_mismatches,
     prelude::*,
+    save_id::visit_entities,
+    serde::ValueSerializer,
     snapshot::RawSnapshot,
 };
 
+/// The number of values of a type in a [`Snapshot`], and their total serialized size.
+///
+/// Returned by [`Snapshot::type_stats`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeStat {
+    /// The type name of the component or resource.
+    pub type_name: String,
+
+    /// The number of values of the type.
+    pub count: usize,
+
+    /// The total size of the serialized values in bytes, excluding type names and structure.
+    pub bytes: usize,
+}
+
 /// A complete snapshot of the game state.
 ///
 /// Can be serialized via [`SnapshotSerializer`] and deserialized via [`SnapshotDeserializer`].
@@ -18,21 +65,978 @@ pub struct Snapshot {
     pub(crate) rollbacks: Option<Rollbacks>,
 }
 
-impl Snapshot {
-    pub(crate) fn default() -> Self {
-        Self {
-            snapshot: RawSnapshot::default(),
-            rollbacks: None,
-        }
+impl Snapshot {
+    pub(crate) fn default() -> Self {
+        Self {
+            snapshot: RawSnapshot::default(),
+            rollbacks: None,
+        }
+    }
+}
+
+impl Snapshot {
+    /// Returns a complete [`Snapshot`] of the current [`World`] state.
+    ///
+    /// Contains all saveable entities and resources, including [`Rollbacks`].
+    ///
+    /// # Shortcut for
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let world = &mut app.world;
+    /// Snapshot::builder(world)
+    ///     .extract_all()
+    ///     .build();
+    /// ```
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// // Components in both table and sparse set storage are captured
+    /// #[derive(Component, Reflect, Default)]
+    /// #[component(storage = "SparseSet")]
+    /// #[reflect(Component)]
+    /// struct Burning(u32);
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// app.register_saveable::<Burning>();
+    ///
+    /// let world = &mut app.world;
+    /// world.spawn((Transform::default(), Burning(3)));
+    ///
+    /// let snapshot = Snapshot::from_world(world);
+    ///
+    /// world.clear_entities();
+    /// snapshot.apply(world).unwrap();
+    ///
+    /// let burning = world.query::<&Burning>().single(world);
+    /// assert_eq!(burning.0, 3);
+    /// ```
+    pub fn from_world(world: &World) -> Self {
+        Self::builder(world).extract_all().build()
+    }
+
+    /// Returns a [`Snapshot`] of the current [`World`] state filtered by `filter`.
+    ///
+    /// # Shortcut for
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let world = &mut app.world;
+    /// # let filter = |_: &&bevy::reflect::TypeRegistration| true;
+    /// Snapshot::builder(world)
+    ///     .filter(filter)
+    ///     .extract_all()
+    ///     .build();
+    /// ```
+    pub fn from_world_with_filter<F>(world: &World, filter: F) -> Self
+    where
+        F: Fn(&&TypeRegistration) -> bool,
+    {
+        Self::builder(world).filter(filter).extract_all().build()
+    }
+
+    /// Returns a [`Snapshot`] of the entities whose [`GlobalTransform`] translation lies inside `region`.
+    ///
+    /// Entities without a [`GlobalTransform`] are excluded, and no resources are captured,
+    /// so the snapshot can be saved when a region of the world is unloaded and applied when it is loaded again.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let world = &mut app.world;
+    /// let at = |x, y| GlobalTransform::from_xyz(x, y, 0.0);
+    ///
+    /// world.spawn(at(5.0, 5.0));
+    /// world.spawn(at(10.0, 0.0));
+    /// world.spawn(at(15.0, 5.0));
+    /// world.spawn(Name::new("Manager"));
+    ///
+    /// let region = Rect::new(0.0, 0.0, 10.0, 10.0);
+    /// let snapshot = Snapshot::from_world_in_region(world, &region);
+    ///
+    /// world.clear_entities();
+    /// snapshot.apply(world).unwrap();
+    ///
+    /// let mut captured = world
+    ///     .query::<&GlobalTransform>()
+    ///     .iter(world)
+    ///     .map(|transform| transform.translation().x)
+    ///     .collect::<Vec<_>>();
+    /// captured.sort_by(f32::total_cmp);
+    ///
+    /// // Only the entities inside the region, including its boundary, were captured
+    /// assert_eq!(captured, vec![5.0, 10.0]);
+    /// assert!(world.query::<&Name>().iter(world).next().is_none());
+    /// ```
+    pub fn from_world_in_region(world: &World, region: &impl Region) -> Self {
+        let entities = world
+            .iter_entities()
+            .filter(|entity| {
+                entity
+                    .get::<GlobalTransform>()
+                    .is_some_and(|transform| region.contains(transform.translation()))
+            })
+            .map(|entity| entity.id())
+            .collect::<Vec<_>>();
+
+        Self::builder(world).extract_entities(entities.into_iter()).build()
+    }
+
+    /// Returns a [`Snapshot`] of only the component `C` of every entity that has it.
+    ///
+    /// `C` must be registered as saveable. Types [coupled](SaveableRegistry::couple) with `C` are captured with it,
+    /// such as [`GlobalTransform`] with [`Transform`], but no other components and no resources,
+    /// so applying the snapshot only writes `C` and its partners back onto the entities it was captured from.
+    /// Apply it with [`DespawnMode::None`] to keep the entities without `C`.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let world = &mut app.world;
+    /// for i in 0..3 {
+    ///     world.spawn((
+    ///         TransformBundle::from_transform(Transform::from_xyz(i as f32, 0.0, 0.0)),
+    ///         Name::new("Unit"),
+    ///     ));
+    /// }
+    /// world.spawn(Name::new("Manager"));
+    ///
+    /// let snapshot = Snapshot::from_component::<Transform>(world);
+    ///
+    /// for (mut transform, mut name) in world.query::<(&mut Transform, &mut Name)>().iter_mut(world) {
+    ///     transform.translation.y = 10.0;
+    ///     name.set("Moved");
+    /// }
+    ///
+    /// snapshot
+    ///     .applier(world)
+    ///     .despawn(DespawnMode::None)
+    ///     .apply()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(world.entities().len(), 4);
+    ///
+    /// let mut units = world.query::<(&Transform, &Name)>();
+    /// assert_eq!(units.iter(world).count(), 3);
+    ///
+    /// for (transform, name) in units.iter(world) {
+    ///     assert_eq!(transform.translation.y, 0.0);
+    ///     assert_eq!(name.as_str(), "Moved");
+    /// }
+    /// ```
+    pub fn from_component<C: Component>(world: &World) -> Self {
+        let saveables = world.resource::<SaveableRegistry>();
+
+        // Capture the coupled partners of `C` too, or the builder would exclude the split pairs
+        let mut names = HashSet::from([std::any::type_name::<C>()]);
+
+        loop {
+            let len = names.len();
+
+            for (a, b) in saveables.coupled() {
+                if names.contains(a) || names.contains(b) {
+                    names.insert(a);
+                    names.insert(b);
+                }
+            }
+
+            if names.len() == len {
+                break;
+            }
+        }
+
+        let entities = world
+            .iter_entities()
+            .filter(|entity| entity.contains::<C>())
+            .map(|entity| entity.id())
+            .collect::<Vec<_>>();
+
+        Self::builder(world)
+            .filter(move |reg: &&TypeRegistration| names.contains(reg.type_name()))
+            .extract_entities(entities.into_iter())
+            .build()
+    }
+
+    /// Returns a [`Snapshot`] of every entity matching `F` and all of their descendants, without resources.
+    ///
+    /// Descendants are found by walking [`Children`], like [`Builder::extract_entities_recursive`].
+    /// The snapshot is self-contained: a root's [`Parent`] outside the captured hierarchies is left out,
+    /// and any other reference to an entity outside them is cleared to [`Entity::PLACEHOLDER`].
+    /// References within the hierarchies are remapped when applied, if the components reflect `MapEntities`.
+    /// Use [`Snapshot::from_roots_strict`] to return an error for outside references instead.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let mut other = App::new();
+    /// # other.add_plugins(MinimalPlugins);
+    /// # other.add_plugins(SavePlugins);
+    /// #[derive(Component)]
+    /// struct RoomRoot;
+    ///
+    /// #[derive(Component)]
+    /// struct CurrentRoom;
+    ///
+    /// for app in [&mut app, &mut other] {
+    ///     app.register_saveable::<Parent>()
+    ///         .register_saveable::<Children>();
+    /// }
+    ///
+    /// let world = &mut app.world;
+    /// let level = world.spawn(Name::new("Level")).id();
+    ///
+    /// for room in 0..3 {
+    ///     let root = world.spawn((Name::new(format!("Room {room}")), RoomRoot)).id();
+    ///
+    ///     world.entity_mut(root).with_children(|parent| {
+    ///         parent.spawn(Name::new(format!("Room {room} chest")));
+    ///         parent.spawn(Name::new(format!("Room {room} door")));
+    ///     });
+    ///
+    ///     world.entity_mut(level).add_child(root);
+    ///
+    ///     if room == 1 {
+    ///         world.entity_mut(root).insert(CurrentRoom);
+    ///     }
+    /// }
+    ///
+    /// let snapshot = Snapshot::from_roots::<(With<RoomRoot>, With<CurrentRoom>)>(world);
+    /// snapshot.apply(&mut other.world).unwrap();
+    ///
+    /// let world = &mut other.world;
+    ///
+    /// let mut names = world
+    ///     .query::<&Name>()
+    ///     .iter(world)
+    ///     .map(|name| name.as_str().to_owned())
+    ///     .collect::<Vec<_>>();
+    /// names.sort();
+    ///
+    /// assert_eq!(names, ["Room 1", "Room 1 chest", "Room 1 door"]);
+    ///
+    /// // The room is a root in the snapshot, with its own hierarchy intact
+    /// let (root, children) = world.query_filtered::<(Entity, &Children), Without<Parent>>().single(world);
+    /// assert_eq!(children.len(), 2);
+    /// assert_eq!(world.get::<Name>(root).unwrap().as_str(), "Room 1");
+    /// ```
+    pub fn from_roots<F: ReadOnlyWorldQuery>(world: &mut World) -> Self {
+        let mut snapshot = Self::roots_builder::<F>(world).build();
+
+        // Only fails in strict mode
+        snapshot.detach(false).ok();
+        snapshot
+    }
+
+    /// Returns a [`Snapshot`] of every entity matching `F` and all of their descendants, like [`Snapshot::from_roots`],
+    /// or an error if a captured component references an entity outside the captured hierarchies.
+    ///
+    /// A root's [`Parent`] outside the hierarchies is still left out, as it is not a reference within the snapshot.
+    ///
+    /// # Errors
+    /// - [`SaveableError::ExternalReference`] for the first component that references an outside entity
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// #[derive(Component)]
+    /// struct RoomRoot;
+    ///
+    /// #[derive(Component, Reflect)]
+    /// #[reflect(Component)]
+    /// struct Door {
+    ///     to: Entity,
+    /// }
+    ///
+    /// impl FromWorld for Door {
+    ///     fn from_world(_: &mut World) -> Self {
+    ///         Self { to: Entity::PLACEHOLDER }
+    ///     }
+    /// }
+    ///
+    /// app.register_saveable::<Door>();
+    ///
+    /// let world = &mut app.world;
+    ///
+    /// let hallway = world.spawn_empty().id();
+    /// world.spawn((RoomRoot, Door { to: hallway }));
+    ///
+    /// assert!(matches!(
+    ///     Snapshot::from_roots_strict::<With<RoomRoot>>(world),
+    ///     Err(SaveableError::ExternalReference { type_name, entity })
+    ///         if type_name.ends_with("Door") && entity == hallway
+    /// ));
+    ///
+    /// // Without strict mode, the reference is cleared
+    /// let snapshot = Snapshot::from_roots::<With<RoomRoot>>(world);
+    /// snapshot.applier(world).despawn(DespawnMode::None).apply().unwrap();
+    ///
+    /// let door = world.query::<&Door>().single(world);
+    /// assert_eq!(door.to, Entity::PLACEHOLDER);
+    /// ```
+    pub fn from_roots_strict<F: ReadOnlyWorldQuery>(
+        world: &mut World,
+    ) -> Result<Self, SaveableError> {
+        let mut snapshot = Self::roots_builder::<F>(world).build();
+        snapshot.detach(true)?;
+        Ok(snapshot)
+    }
+
+    /// Returns a [`Builder`] that extracts the entities matching `F` and all of their descendants.
+    fn roots_builder<F: ReadOnlyWorldQuery>(world: &mut World) -> Builder<'_, Self> {
+        let roots = world.query_filtered::<Entity, F>().iter(world).collect::<Vec<_>>();
+        Self::builder(world).extract_entities_recursive(roots.into_iter())
+    }
+
+    /// Removes every [`Parent`] outside the snapshot, and clears every other reference to an entity outside it.
+    ///
+    /// With `strict`, returns [`SaveableError::ExternalReference`] for the first cleared reference instead.
+    fn detach(&mut self, strict: bool) -> Result<(), SaveableError> {
+        let parent = std::any::type_name::<Parent>();
+
+        let captured = self
+            .snapshot
+            .entities
+            .iter()
+            .map(|e| e.entity)
+            .collect::<HashSet<_>>();
+
+        let outside = |entity: &Entity| {
+            *entity != Entity::PLACEHOLDER && !captured.contains(&entity.index())
+        };
+
+        for saved in &mut self.snapshot.entities {
+            saved.components.retain_mut(|component| {
+                if component.type_name() != parent {
+                    return true;
+                }
+
+                let mut detached = false;
+                visit_entities(&mut **component, &mut |entity| detached |= outside(entity));
+                !detached
+            });
+
+            for component in &mut saved.components {
+                let mut external = None;
+
+                visit_entities(&mut **component, &mut |entity| {
+                    if outside(entity) {
+                        external.get_or_insert(*entity);
+                        *entity = Entity::PLACEHOLDER;
+                    }
+                });
+
+                if let (true, Some(entity)) = (strict, external) {
+                    return Err(SaveableError::ExternalReference {
+                        type_name: component.type_name().to_string(),
+                        entity,
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns a [`Snapshot`] of the current [`World`] state, like [`Snapshot::from_world`], without the given entities.
+    ///
+    /// Useful for leaving out temporary entities, such as projectiles or effects.
+    ///
+    /// References to excluded entities are not cleared. A captured child of an excluded parent keeps its [`Parent`],
+    /// and a captured parent keeps excluded entities in its [`Children`], so exclude whole hierarchies
+    /// to avoid dangling references when the snapshot is applied.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let mut other = App::new();
+    /// # other.add_plugins(MinimalPlugins);
+    /// # other.add_plugins(SavePlugins);
+    /// let world = &mut app.world;
+    ///
+    /// world.spawn(Name::new("Player"));
+    /// world.spawn(Name::new("Enemy"));
+    /// let a = world.spawn(Name::new("Bullet A")).id();
+    /// let b = world.spawn(Name::new("Bullet B")).id();
+    ///
+    /// let snapshot = Snapshot::from_world_excluding_entities(world, &[a, b]);
+    ///
+    /// snapshot.apply(&mut other.world).unwrap();
+    ///
+    /// let world = &mut other.world;
+    ///
+    /// let mut names = world
+    ///     .query::<&Name>()
+    ///     .iter(world)
+    ///     .map(|name| name.as_str().to_owned())
+    ///     .collect::<Vec<_>>();
+    /// names.sort();
+    ///
+    /// assert_eq!(names, ["Enemy", "Player"]);
+    /// ```
+    pub fn from_world_excluding_entities(world: &World, excluded: &[Entity]) -> Self {
+        let excluded = excluded.iter().copied().collect::<HashSet<_>>();
+
+        let entities = world
+            .iter_entities()
+            .map(|entity| entity.id())
+            .filter(|entity| !excluded.contains(entity))
+            .collect::<Vec<_>>();
+
+        Self::builder(world)
+            .extract_entities(entities.into_iter())
+            .extract_all_resources()
+            .build()
+    }
+
+    /// Create a [`Builder`] from the [`World`], allowing you to create partial or filtered snapshots.
+    /// 
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let world = &mut app.world;
+    /// Snapshot::builder(world)
+    ///     // Exclude `Transform` from this `Snapshot`
+    ///     .filter(|reg| reg.type_name() != "bevy_transform::components::transform::Transform")
+    ///
+    ///     // Extract all matching entities and resources
+    ///     .extract_all()
+    ///
+    ///     // Clear all extracted entities without any components
+    ///     .clear_empty()
+    ///
+    ///     // Build the `Snapshot`
+    ///     .build();
+    /// ```
+    pub fn builder(world: &World) -> Builder<Self> {
+        Builder::new(world)
+    }
+
+    /// Apply the [`Snapshot`] to the [`World`], restoring it to the saved state.
+    ///
+    /// # Errors
+    /// - See [`SaveableError`]
+    pub fn apply(&self, world: &mut World) -> Result<(), SaveableError> {
+        self.applier(world).apply()
+    }
+
+    /// Apply only the resources of the [`Snapshot`] to the [`World`], leaving all entities untouched.
+    ///
+    /// [`Rollbacks`] are not restored.
+    ///
+    /// # Errors
+    /// - See [`SaveableError`]
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Resource, Reflect, Default)]
+    /// #[reflect(Resource)]
+    /// struct Volume(f32);
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// app.register_saveable::<Volume>()
+    ///     .insert_resource(Volume(0.5));
+    ///
+    /// let world = &mut app.world;
+    /// let entity = world.spawn(Transform::from_xyz(1.0, 0.0, 0.0)).id();
+    ///
+    /// let snapshot = Snapshot::from_world(world);
+    ///
+    /// world.insert_resource(Volume(1.0));
+    /// world.entity_mut(entity).insert(Transform::from_xyz(2.0, 0.0, 0.0));
+    /// let spawned = world.spawn(Transform::default()).id();
+    ///
+    /// snapshot.apply_resources_only(world).unwrap();
+    ///
+    /// assert_eq!(world.resource::<Volume>().0, 0.5);
+    /// assert_eq!(world.get::<Transform>(entity).unwrap().translation.x, 2.0);
+    /// assert!(world.get_entity(spawned).is_some());
+    /// ```
+    pub fn apply_resources_only(&self, world: &mut World) -> Result<(), SaveableError> {
+        self.applier(world).apply_resources()
+    }
+
+    /// Apply only the entities of the [`Snapshot`] to the [`World`], leaving all resources untouched.
+    ///
+    /// Entities are despawned and applied as [`Snapshot::apply`] would. [`Rollbacks`] are not restored.
+    ///
+    /// # Errors
+    /// - See [`SaveableError`]
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Resource, Reflect, Default)]
+    /// #[reflect(Resource)]
+    /// struct Volume(f32);
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// app.register_saveable::<Volume>()
+    ///     .insert_resource(Volume(0.5));
+    ///
+    /// let world = &mut app.world;
+    /// let entity = world.spawn(Transform::from_xyz(1.0, 0.0, 0.0)).id();
+    ///
+    /// let snapshot = Snapshot::from_world(world);
+    ///
+    /// world.insert_resource(Volume(1.0));
+    /// world.entity_mut(entity).insert(Transform::from_xyz(2.0, 0.0, 0.0));
+    /// let spawned = world.spawn(Transform::default()).id();
+    ///
+    /// snapshot.apply_entities_only(world).unwrap();
+    ///
+    /// assert_eq!(world.resource::<Volume>().0, 1.0);
+    /// assert_eq!(world.get::<Transform>(entity).unwrap().translation.x, 1.0);
+    /// assert!(world.get_entity(spawned).is_none());
+    /// ```
+    pub fn apply_entities_only(&self, world: &mut World) -> Result<(), SaveableError> {
+        self.applier(world).apply_entity_phases().map(|_| ())
+    }
+
+    /// Replace all saveable state of the [`World`] with the [`Snapshot`].
+    ///
+    /// Every entity with a saveable component is despawned and every saveable resource is removed,
+    /// then the [`Snapshot`] is applied with [`MappingMode::Strict`] and [`Applier::preserve_layout`].
+    /// Afterwards the [`World`] contains exactly the saveable content of the [`Snapshot`],
+    /// unlike [`DespawnMode::AllWith`], which leaves unrelated saveable entities and resources in place.
+    ///
+    /// # Errors
+    /// - See [`SaveableError`]
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Resource, Reflect, Default)]
+    /// #[reflect(Resource)]
+    /// struct Score(u32);
+    ///
+    /// #[derive(Resource, Reflect, Default)]
+    /// #[reflect(Resource)]
+    /// struct Bonus(u32);
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// app.register_saveable::<Score>()
+    ///     .register_saveable::<Bonus>()
+    ///     .insert_resource(Score(10));
+    ///
+    /// let world = &mut app.world;
+    /// let a = world.spawn(Transform::from_xyz(1.0, 0.0, 0.0)).id();
+    /// world.spawn(Transform::from_xyz(2.0, 0.0, 0.0));
+    ///
+    /// let snapshot = Snapshot::from_world(world);
+    ///
+    /// // Mutate everything
+    /// world.resource_mut::<Score>().0 = 99;
+    /// world.insert_resource(Bonus(5));
+    /// world.despawn(a);
+    /// for i in 0..5 {
+    ///     world.spawn((Transform::from_xyz(i as f32, 1.0, 0.0), Visibility::Hidden));
+    /// }
+    ///
+    /// snapshot.restore(world).unwrap();
+    ///
+    /// snapshot.assert_matches(&Snapshot::from_world(world));
+    ///
+    /// assert_eq!(world.resource::<Score>().0, 10);
+    /// assert!(!world.contains_resource::<Bonus>());
+    /// assert_eq!(world.query::<&Transform>().iter(world).count(), 2);
+    /// assert_eq!(world.get::<Transform>(a).unwrap().translation.x, 1.0);
+    /// ```
+    pub fn restore(&self, world: &mut World) -> Result<(), SaveableError> {
+        let registry_arc = world.resource::<AppTypeRegistry>().clone();
+        let registry = registry_arc.read();
+        let saveables = world.resource::<SaveableRegistry>();
+
+        let saveable = |archetype: &Archetype| {
+            archetype
+                .components()
+                .filter_map(|id| world.components().get_info(id))
+                .any(|info| saveables.contains(info.name()))
+        };
+
+        let entities = world
+            .iter_entities()
+            .filter(|entity| saveable(entity.archetype()))
+            .map(|entity| entity.id())
+            .collect::<Vec<_>>();
+
+        let resources = saveables
+            .types()
+            .filter_map(|name| registry.get_with_name(name))
+            .filter_map(|reg| reg.data::<ReflectResource>())
+            .cloned()
+            .collect::<Vec<_>>();
+
+        for entity in entities {
+            world.despawn(entity);
+        }
+
+        for data in resources {
+            data.remove(world);
+        }
+
+        drop(registry);
+
+        self.applier(world)
+            .despawn(DespawnMode::None)
+            .mapping(MappingMode::Strict)
+            .preserve_layout()
+            .apply()
+    }
+
+    /// Apply the [`Snapshot`] to the [`World`], leaving the [`World`] unchanged if applying fails.
+    ///
+    /// A [`Snapshot`] of the [`World`] is taken first, and if applying returns an error,
+    /// the [`World`] is put back into that state with [`Snapshot::restore`] before the error is returned.
+    /// This costs an extra [`Snapshot`] of the whole [`World`].
+    ///
+    /// Only saveable state is captured, so entities and components that are not saveable are not brought back
+    /// if the failed apply despawned them.
+    ///
+    /// # Errors
+    /// - See [`SaveableError`]
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # use serde::Serialize;
+    /// #[derive(Component, Reflect, Default)]
+    /// #[reflect(Component)]
+    /// struct Debug;
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let mut other = App::new();
+    /// # other.add_plugins(MinimalPlugins);
+    /// # other.add_plugins(SavePlugins);
+    /// app.register_saveable::<Debug>();
+    ///
+    /// app.world.spawn(Transform::from_xyz(1.0, 0.0, 0.0));
+    /// app.world.spawn((Transform::from_xyz(2.0, 0.0, 0.0), Debug));
+    ///
+    /// let snapshot = Snapshot::from_world(&app.world);
+    ///
+    /// // A build without `Debug`, which fails on the second entity
+    /// let world = &mut other.world;
+    /// world.spawn((Name::new("Player"), Transform::from_xyz(0.0, 5.0, 0.0)));
+    /// world.spawn(Transform::from_xyz(0.0, 6.0, 0.0));
+    /// world.spawn(Name::new("Camera"));
+    ///
+    /// let json = |world: &World| {
+    ///     let mut json = Vec::new();
+    ///     world.serialize(&mut serde_json::Serializer::new(&mut json)).unwrap();
+    ///     json
+    /// };
+    ///
+    /// let before = json(world);
+    ///
+    /// let err = snapshot.apply_transactional(world).unwrap_err();
+    /// assert!(matches!(err, SaveableError::UnregisteredType { .. }));
+    ///
+    /// assert_eq!(json(world), before);
+    /// ```
+    pub fn apply_transactional(&self, world: &mut World) -> Result<(), SaveableError> {
+        let before = Self::from_world(world);
+
+        self.apply(world).or_else(|err| {
+            before.restore(world)?;
+            Err(err)
+        })
+    }
+
+    /// Returns the number of values of each component and resource type in the [`Snapshot`],
+    /// and their total size when serialized with `saver`, largest first.
+    ///
+    /// Useful for finding which types make saves large. [`Rollbacks`] are not included.
+    ///
+    /// # Errors
+    /// - [`SaveableError::Other`] if a value fails to serialize
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let world = &mut app.world;
+    /// for i in 0..100 {
+    ///     world.spawn(Transform::from_xyz(i as f32, 0.0, 0.0));
+    /// }
+    ///
+    /// world.spawn(Name::new("Player"));
+    /// world.spawn(Name::new("Camera"));
+    ///
+    /// let snapshot = Snapshot::from_world(world);
+    /// let saver = AppSaver::new(JSONSaver::new());
+    ///
+    /// let stats = snapshot
+    ///     .type_stats(world.resource::<AppTypeRegistry>(), &saver)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(stats.len(), 2);
+    ///
+    /// assert_eq!(stats[0].type_name, std::any::type_name::<Transform>());
+    /// assert_eq!(stats[0].count, 100);
+    /// assert!(stats[0].bytes > stats[1].bytes);
+    ///
+    /// assert_eq!(stats[1].type_name, std::any::type_name::<Name>());
+    /// assert_eq!(stats[1].count, 2);
+    /// ```
+    pub fn type_stats(
+        &self,
+        registry: &AppTypeRegistry,
+        saver: &AppSaver,
+    ) -> Result<Vec<TypeStat>, SaveableError> {
+        let registry = registry.read();
+
+        let mut stats = BTreeMap::<&str, TypeStat>::new();
+
+        let components = self.snapshot.entities.iter().flat_map(|entity| &entity.components);
+
+        for value in self.snapshot.resources.iter().chain(components) {
+            let mut bytes = Vec::new();
+
+            saver
+                .serialize(&ValueSerializer::new(&**value, &registry), &mut bytes)
+                .map_err(SaveableError::other)?;
+
+            let stat = stats.entry(value.type_name()).or_insert_with(|| TypeStat {
+                type_name: value.type_name().to_owned(),
+                count: 0,
+                bytes: 0,
+            });
+
+            stat.count += 1;
+            stat.bytes += bytes.len();
+        }
+
+        let mut stats = stats.into_values().collect::<Vec<_>>();
+        stats.sort_by_key(|stat| Reverse(stat.bytes));
+
+        Ok(stats)
+    }
+
+    /// Returns the sorted names of the types registered in the [`SaveableRegistry`] without any value in the [`Snapshot`].
+    ///
+    /// Components removed by [`Snapshot::compact`] count as present. [`Rollbacks`] are not included.
+    /// A type that is never captured may have been registered by mistake, or its values may be filtered out.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Component, Reflect, Default)]
+    /// #[reflect(Component)]
+    /// struct Health(u32);
+    ///
+    /// #[derive(Component, Reflect, Default)]
+    /// #[reflect(Component)]
+    /// struct Mana(u32);
+    ///
+    /// #[derive(Resource, Reflect, Default)]
+    /// #[reflect(Resource)]
+    /// struct Score(u32);
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// app.register_saveable::<Health>()
+    ///     .register_saveable::<Mana>()
+    ///     .register_saveable::<Score>();
+    ///
+    /// app.world.spawn(Health(100));
+    /// app.insert_resource(Score(0));
+    ///
+    /// let snapshot = Snapshot::from_world(&app.world);
+    /// let unused = snapshot.unused_types(app.world.resource::<SaveableRegistry>());
+    ///
+    /// assert!(unused.contains(&std::any::type_name::<Mana>().to_owned()));
+    /// assert!(!unused.contains(&std::any::type_name::<Health>().to_owned()));
+    /// assert!(!unused.contains(&std::any::type_name::<Score>().to_owned()));
+    /// ```
+    pub fn unused_types(&self, registry: &SaveableRegistry) -> Vec<String> {
+        let components = self.snapshot.entities.iter().flat_map(|entity| {
+            let defaults = entity.defaults.iter().map(|type_name| type_name.as_str());
+            entity.components.iter().map(|value| value.type_name()).chain(defaults)
+        });
+
+        let used = self
+            .snapshot
+            .resources
+            .iter()
+            .map(|value| value.type_name())
+            .chain(components)
+            .collect::<HashSet<_>>();
+
+        let mut unused = registry
+            .types()
+            .filter(|type_name| !used.contains(type_name.as_str()))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        unused.sort();
+        unused
+    }
+
+    /// Returns a Graphviz DOT graph of the entities in the [`Snapshot`] and their hierarchy.
+    ///
+    /// Each entity is a node labeled with its saved index and the short names of its components.
+    /// Each parent-child relationship found in a captured [`Parent`] or [`Children`] component is an edge
+    /// from the parent to the child, so both must be registered as saveable to appear.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// app.register_saveable::<Parent>()
+    ///     .register_saveable::<Children>();
+    ///
+    /// let world = &mut app.world;
+    ///
+    /// let root = world.spawn(Name::new("Root")).id();
+    /// let a = world.spawn(Transform::default()).set_parent(root).id();
+    /// let b = world.spawn(Transform::default()).set_parent(root).id();
+    ///
+    /// let dot = Snapshot::from_world(world).to_dot();
+    ///
+    /// assert!(dot.starts_with("digraph snapshot {"));
+    /// assert_eq!(dot.matches("[label=").count(), 3);
+    /// assert!(dot.contains(&format!("e{} [label=\"{}\\nChildren, Name\"]", root.index(), root.index())));
+    ///
+    /// assert_eq!(dot.matches(" -> ").count(), 2);
+    /// assert!(dot.contains(&format!("e{} -> e{}", root.index(), a.index())));
+    /// assert!(dot.contains(&format!("e{} -> e{}", root.index(), b.index())));
+    /// ```
+    pub fn to_dot(&self) -> String {
+        let parent = std::any::type_name::<Parent>();
+        let children = std::any::type_name::<Children>();
+
+        let mut dot = String::from("digraph snapshot {\n");
+        let mut edges = BTreeSet::new();
+
+        for entity in &self.snapshot.entities {
+            let mut names = entity
+                .components
+                .iter()
+                .map(|component| component.type_name())
+                .chain(entity.defaults.iter().map(|type_name| type_name.as_str()))
+                .map(bevy::utils::get_short_name)
+                .collect::<Vec<_>>();
+
+            names.sort();
+
+            let label = format!("{}\\n{}", entity.entity, names.join(", ")).replace('"', "\\\"");
+            let _ = writeln!(dot, "    e{} [label=\"{label}\"];", entity.entity);
+
+            for component in &entity.components {
+                let type_name = component.type_name();
+
+                if type_name != parent && type_name != children {
+                    continue;
+                }
+
+                let mut value = component.clone_value();
+
+                visit_entities(&mut *value, &mut |related| {
+                    if type_name == parent {
+                        edges.insert((related.index(), entity.entity));
+                    } else {
+                        edges.insert((entity.entity, related.index()));
+                    }
+                });
+            }
+        }
+
+        for (parent, child) in edges {
+            let _ = writeln!(dot, "    e{parent} -> e{child};");
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Create an [`Applier`] from the [`Snapshot`] and the [`World`].
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::entity::EntityMap;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let world = &mut app.world;
+    /// let snapshot = Snapshot::from_world(world);
+    ///
+    /// snapshot
+    ///     .applier(world)
+    ///     .map(EntityMap::default())
+    ///     .despawn(DespawnMode::default())
+    ///     .mapping(MappingMode::default())
+    ///     .apply();
+    /// ```
+    pub fn applier<'a>(&'a self, world: &'a mut World) -> Applier<&Self> {
+        Applier::new(world, self)
+    }
+
+    /// Create an owning [`Applier`] from the [`Snapshot`] and the [`World`].
+    pub fn into_applier(self, world: &mut World) -> Applier<Self> {
+        Applier::new(world, self)
     }
-}
 
-impl Snapshot {
-    /// Returns a complete [`Snapshot`] of the current [`World`] state.
+    /// Returns the [`SnapshotDelta`] from this [`Snapshot`] to the `newer` one.
     ///
-    /// Contains all saveable entities and resources, including [`Rollbacks`].
+    /// [`Rollbacks`] are not compared.
     ///
-    /// # Shortcut for
+    /// # Example
     /// ```
     /// # use bevy::prelude::*;
     /// # use bevy_save::prelude::*;
@@ -40,38 +1044,117 @@ impl Snapshot {
     /// # app.add_plugins(MinimalPlugins);
     /// # app.add_plugins(SavePlugins);
     /// # let world = &mut app.world;
-    /// Snapshot::builder(world)
-    ///     .extract_all()
-    ///     .build();
-    pub fn from_world(world: &World) -> Self {
-        Self::builder(world).extract_all().build()
+    /// let entity = world.spawn(Transform::default()).id();
+    /// let before = Snapshot::from_world(world);
+    ///
+    /// world.entity_mut(entity).insert(Transform::from_xyz(1.0, 0.0, 0.0));
+    /// let after = Snapshot::from_world(world);
+    ///
+    /// let delta = before.diff(&after);
+    ///
+    /// assert_eq!(delta.components_changed, vec![(
+    ///     entity.index(),
+    ///     "bevy_transform::components::transform::Transform".to_string()
+    /// )]);
+    /// ```
+    pub fn diff(&self, newer: &Snapshot) -> SnapshotDelta {
+        SnapshotDelta::between(&self.snapshot, &newer.snapshot)
     }
 
-    /// Returns a [`Snapshot`] of the current [`World`] state filtered by `filter`.
+    /// Returns the [`SnapshotDelta`] between two save files on disk, without loading them into a [`World`].
     ///
-    /// # Shortcut for
+    /// Both files are read with their [`SaveHeader`] settings and deserialized against the given registry,
+    /// which must contain every saved type. Saves written without a header are read with the default
+    /// [`AppLoader`]. The save versions are not checked, and extension data is ignored.
+    ///
+    /// # Errors
+    /// - See [`SaveableError`]
+    ///
+    /// # Example
     /// ```
+    /// # use std::io::Read;
     /// # use bevy::prelude::*;
     /// # use bevy_save::prelude::*;
     /// # let mut app = App::new();
     /// # app.add_plugins(MinimalPlugins);
     /// # app.add_plugins(SavePlugins);
-    /// # let world = &mut app.world;
-    /// # let filter = |_: &&bevy::reflect::TypeRegistration| true;
-    /// Snapshot::builder(world)
-    ///     .filter(filter)
-    ///     .extract_all()
-    ///     .build();
+    /// app.insert_resource(AppBackend::new(MemoryBackend::new()));
+    /// app.insert_resource(AppCompression::Gzip);
+    ///
+    /// let world = &mut app.world;
+    /// let dir = std::env::temp_dir();
+    ///
+    /// let write = |world: &World, name: &str| {
+    ///     world.save(name).unwrap();
+    ///
+    ///     let mut bytes = Vec::new();
+    ///     let mut reader = world.resource::<AppBackend>().reader(name).unwrap();
+    ///     reader.read_to_end(&mut bytes).unwrap();
+    ///
+    ///     let path = dir.join(format!("bevy_save_diff_files_{name}"));
+    ///     std::fs::write(&path, bytes).unwrap();
+    ///     path
+    /// };
+    ///
+    /// let entity = world.spawn(Transform::default()).id();
+    /// let before = Snapshot::from_world(world);
+    /// let a = write(world, "before");
+    ///
+    /// world.entity_mut(entity).insert(Transform::from_xyz(1.0, 0.0, 0.0));
+    /// world.spawn(Name::new("Crate"));
+    /// let after = Snapshot::from_world(world);
+    /// let b = write(world, "after");
+    ///
+    /// let registry = world.resource::<AppTypeRegistry>().read();
+    /// let delta = Snapshot::diff_files(&a, &b, &registry).unwrap();
+    ///
+    /// assert_eq!(delta, before.diff(&after));
+    /// assert_eq!(delta.entities_added.len(), 1);
     /// ```
-    pub fn from_world_with_filter<F>(world: &World, filter: F) -> Self
+    pub fn diff_files<A, B>(
+        a: A,
+        b: B,
+        registry: &TypeRegistryInternal,
+    ) -> Result<SnapshotDelta, SaveableError>
     where
-        F: Fn(&&TypeRegistration) -> bool,
+        A: AsRef<Path>,
+        B: AsRef<Path>,
     {
-        Self::builder(world).filter(filter).extract_all().build()
+        let older = Self::read_file(a.as_ref(), registry)?;
+        let newer = Self::read_file(b.as_ref(), registry)?;
+
+        Ok(older.diff(&newer))
     }
 
-    /// Create a [`Builder`] from the [`World`], allowing you to create partial or filtered snapshots.
-    /// 
+    /// Deserializes the [`Snapshot`] of the save file at `path`.
+    fn read_file(path: &Path, registry: &TypeRegistryInternal) -> Result<Self, SaveableError> {
+        let file = File::open(path).map_err(SaveableError::other)?;
+        let (segment, reader) = SaveHeader::read(BufReader::new(file))?;
+
+        let mut reader = segment.compression.decompress(reader);
+        let loader = AppLoader::default();
+
+        let mut de = SnapshotDeserializer::new(registry);
+
+        if segment.type_names == AppTypeNames::Table {
+            de = de.type_table();
+        }
+
+        let mut deserializer = segment.format.deserializer(&loader, (&mut reader).into());
+        let snapshot = de.deserialize_checked(&mut deserializer)?;
+
+        Ok(snapshot)
+    }
+
+    /// Returns a [`Snapshot`] with only the entities and resources that were added or changed since `base`.
+    ///
+    /// Unchanged components and resources are left out, so saving the delta of a player save against
+    /// a shared base save is much smaller than saving the whole snapshot.
+    /// Entities are kept without their unchanged components, so removed entities are still despawned.
+    /// Removed components and resources are not recorded, and [`Rollbacks`] are not included.
+    ///
+    /// Load the delta with [`Snapshot::apply_with_base`].
+    ///
     /// # Example
     /// ```
     /// # use bevy::prelude::*;
@@ -79,58 +1162,397 @@ impl Snapshot {
     /// # let mut app = App::new();
     /// # app.add_plugins(MinimalPlugins);
     /// # app.add_plugins(SavePlugins);
-    /// # let world = &mut app.world;
-    /// Snapshot::builder(world)
-    ///     // Exclude `Transform` from this `Snapshot`
-    ///     .filter(|reg| reg.type_name() != "bevy_transform::components::transform::Transform")
+    /// # let mut other = App::new();
+    /// # other.add_plugins(MinimalPlugins);
+    /// # other.add_plugins(SavePlugins);
+    /// let world = &mut app.world;
     ///
-    ///     // Extract all matching entities and resources
-    ///     .extract_all()
+    /// let entities = (0..10)
+    ///     .map(|i| {
+    ///         let tree = (Name::new(format!("Tree {i}")), Transform::from_xyz(i as f32, 0.0, 0.0));
+    ///         world.spawn(tree).id()
+    ///     })
+    ///     .collect::<Vec<_>>();
     ///
-    ///     // Clear all extracted entities without any components
-    ///     .clear_empty()
+    /// let base = Snapshot::from_world(world);
     ///
-    ///     // Build the `Snapshot`
-    ///     .build();
+    /// // The player moves one tree, cuts down another and plants a new one
+    /// world.get_mut::<Transform>(entities[3]).unwrap().translation.y = 5.0;
+    /// world.despawn(entities[7]);
+    /// world.spawn((Name::new("Sapling"), Transform::from_xyz(20.0, 0.0, 0.0)));
+    ///
+    /// let modified = Snapshot::from_world(world);
+    /// let delta = modified.delta_from(&base);
+    ///
+    /// let registry = world.resource::<AppTypeRegistry>();
+    /// let len = |snapshot: &Snapshot| {
+    ///     serde_json::to_vec(&SnapshotSerializer::new(snapshot, registry)).unwrap().len()
+    /// };
+    ///
+    /// assert!(len(&delta) < len(&modified) / 2);
+    ///
+    /// delta.apply_with_base(&base, &mut other.world).unwrap();
+    ///
+    /// let state = |world: &mut World| {
+    ///     let mut state = world
+    ///         .query::<(&Name, &Transform)>()
+    ///         .iter(world)
+    ///         .map(|(name, transform)| (name.to_string(), transform.translation.to_array()))
+    ///         .collect::<Vec<_>>();
+    ///     state.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    ///     state
+    /// };
+    ///
+    /// assert_eq!(state(&mut other.world), state(&mut app.world));
     /// ```
-    pub fn builder(world: &World) -> Builder<Self> {
-        Builder::new(world)
+    pub fn delta_from(&self, base: &Snapshot) -> Snapshot {
+        Self {
+            snapshot: self.snapshot.delta_from(&base.snapshot),
+            rollbacks: None,
+        }
     }
 
-    /// Apply the [`Snapshot`] to the [`World`], restoring it to the saved state.
+    /// Apply `base`, then this delta created with [`Snapshot::delta_from`], to the [`World`].
+    ///
+    /// The delta is applied to the entities that `base` was applied to.
     ///
     /// # Errors
     /// - See [`SaveableError`]
-    pub fn apply(&self, world: &mut World) -> Result<(), SaveableError> {
-        self.applier(world).apply()
+    pub fn apply_with_base(&self, base: &Snapshot, world: &mut World) -> Result<(), SaveableError> {
+        let map = base.applier(world).apply_and_return_map()?;
+
+        self.applier(world).map(map).apply()
     }
 
-    /// Create an [`Applier`] from the [`Snapshot`] and the [`World`].
+    /// Returns a readable description of each difference between this [`Snapshot`] and the `other` one.
+    ///
+    /// Values are compared with [`reflect_values_eq`]. [`Rollbacks`] are not compared.
+    pub fn mismatches(&self, other: &Snapshot) -> Vec<String> {
+        snapshot_mismatches(&self.snapshot, &other.snapshot)
+    }
+
+    /// Assert that this [`Snapshot`] matches the `other` one, listing every difference if it does not.
+    ///
+    /// # Panics
+    /// - If the snapshots differ, see [`Snapshot::mismatches`]
+    ///
     /// # Example
     /// ```
+    /// # use std::panic::{catch_unwind, AssertUnwindSafe};
     /// # use bevy::prelude::*;
-    /// # use bevy::ecs::entity::EntityMap;
     /// # use bevy_save::prelude::*;
     /// # let mut app = App::new();
     /// # app.add_plugins(MinimalPlugins);
     /// # app.add_plugins(SavePlugins);
     /// # let world = &mut app.world;
+    /// let entity = world.spawn(Transform::default()).id();
+    /// let before = Snapshot::from_world(world);
+    ///
+    /// world.entity_mut(entity).insert(Transform::from_xyz(1.0, 0.0, 0.0));
+    /// let after = Snapshot::from_world(world);
+    ///
+    /// assert_eq!(before.mismatches(&after), vec![format!(
+    ///     "entity {}: component `bevy_transform::components::transform::Transform` `translation.x`: 0.0 != 1.0",
+    ///     entity.index(),
+    /// )]);
+    ///
+    /// after.assert_matches(&Snapshot::from_world(world));
+    ///
+    /// // Panics, listing the mismatch above
+    /// let result = catch_unwind(AssertUnwindSafe(|| before.assert_matches(&after)));
+    /// assert!(result.is_err());
+    /// ```
+    #[track_caller]
+    pub fn assert_matches(&self, other: &Snapshot) {
+        let mismatches = self.mismatches(other);
+
+        assert!(
+            mismatches.is_empty(),
+            "snapshots do not match:\n{}",
+            mismatches.join("\n")
+        );
+    }
+
+    /// Remove every saved component that equals the default value of its type, returning how many were removed.
+    ///
+    /// Only the names of the removed components are kept, and they are restored to their default value when applied.
+    /// Types without `#[reflect(Default)]` are never removed.
+    ///
+    /// [`Snapshot::diff`] reports removed components as removed, so compare snapshots before compacting them.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy::reflect::serde::TypedReflectSerializer;
+    /// # use bevy_save::prelude::*;
+    /// # use serde::de::DeserializeSeed;
+    /// #[derive(Component, Reflect, Default, PartialEq, Debug)]
+    /// #[reflect(Component, Default)]
+    /// struct Velocity(Vec3);
+    ///
+    /// #[derive(Component, Reflect, Default, PartialEq, Debug)]
+    /// #[reflect(Component)]
+    /// struct Health(u32);
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let mut other = App::new();
+    /// # other.add_plugins(MinimalPlugins);
+    /// # other.add_plugins(SavePlugins);
+    /// for app in [&mut app, &mut other] {
+    ///     app.register_saveable::<Velocity>()
+    ///         .register_saveable::<Health>();
+    /// }
+    ///
+    /// let world = &mut app.world;
+    ///
+    /// for _ in 0..3 {
+    ///     world.spawn((Velocity::default(), Health::default()));
+    /// }
+    /// world.spawn((Velocity(Vec3::X), Health(5)));
+    ///
+    /// let full = Snapshot::from_world(world);
+    /// let mut compact = Snapshot::from_world(world);
+    ///
+    /// // `Health` has no registered default, so only the default `Velocity` components are removed
+    /// assert_eq!(compact.compact(world.resource::<AppTypeRegistry>()), 3);
+    ///
+    /// let registry = world.resource::<AppTypeRegistry>().read();
+    ///
+    /// let json = |snapshot: &Snapshot| {
+    ///     serde_json::to_vec(&SnapshotSerializer::new(snapshot, &*registry)).unwrap()
+    /// };
+    ///
+    /// let save = json(&compact);
+    /// assert!(save.len() < json(&full).len());
+    ///
+    /// let loaded = SnapshotDeserializer::new(&registry)
+    ///     .deserialize(&mut serde_json::Deserializer::from_slice(&save))
+    ///     .unwrap();
+    ///
+    /// drop(registry);
+    ///
+    /// loaded.apply(&mut other.world).unwrap();
+    ///
+    /// let state = |world: &mut World| {
+    ///     let mut state = world
+    ///         .query::<(&Velocity, &Health)>()
+    ///         .iter(world)
+    ///         .map(|(v, h)| (v.0.x, h.0))
+    ///         .collect::<Vec<_>>();
+    ///     state.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    ///     state
+    /// };
+    ///
+    /// assert_eq!(state(&mut other.world), state(&mut app.world));
+    /// ```
+    pub fn compact(&mut self, registry: &AppTypeRegistry) -> usize {
+        let registry = registry.read();
+
+        let is_default = |component: &dyn Reflect| {
+            registry
+                .get_with_name(component.type_name())
+                .and_then(|reg| reg.data::<ReflectDefault>())
+                .and_then(|default| component.reflect_partial_eq(&*default.default()))
+                .unwrap_or(false)
+        };
+
+        let mut compacted = 0;
+
+        for saved in &mut self.snapshot.entities {
+            let (defaults, components) = std::mem::take(&mut saved.components)
+                .into_iter()
+                .partition::<Vec<_>, _>(|c| is_default(c.as_reflect()));
+
+            compacted += defaults.len();
+
+            saved.components = components;
+            saved
+                .defaults
+                .extend(defaults.iter().map(|c| c.type_name().to_string()));
+        }
+
+        compacted
+    }
+
+    /// Run `f` on every saved component and resource, including those in the [`Rollbacks`], to redact sensitive data.
+    ///
+    /// `f` receives the type name and the reflected value, which it may modify in place,
+    /// e.g. to replace account ids or tokens before sharing a save publicly.
+    /// Saved values are usually dynamic representations of their type, so modify them through reflection
+    /// rather than by downcasting.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy::reflect::ReflectMut;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// #[derive(Component, Reflect, Default)]
+    /// #[reflect(Component)]
+    /// struct Account {
+    ///     token: String,
+    ///     level: u32,
+    /// }
+    ///
+    /// app.register_saveable::<Account>();
+    ///
+    /// let world = &mut app.world;
+    ///
+    /// for i in 0..3 {
+    ///     world.spawn(Account {
+    ///         token: format!("secret-{i}"),
+    ///         level: i,
+    ///     });
+    /// }
+    ///
+    /// let mut snapshot = Snapshot::from_world(world);
+    ///
+    /// snapshot.redact(|type_name, reflect| {
+    ///     if type_name != std::any::type_name::<Account>() {
+    ///         return;
+    ///     }
+    ///
+    ///     if let ReflectMut::Struct(account) = reflect.reflect_mut() {
+    ///         account
+    ///             .field_mut("token")
+    ///             .unwrap()
+    ///             .apply(&String::from("REDACTED"));
+    ///     }
+    /// });
+    ///
+    /// let registry = world.resource::<AppTypeRegistry>();
+    /// let json = serde_json::to_string(&SnapshotSerializer::new(&snapshot, registry)).unwrap();
+    ///
+    /// assert!(!json.contains("secret"));
+    /// assert_eq!(json.matches("REDACTED").count(), 3);
+    /// ```
+    pub fn redact<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&str, &mut dyn Reflect),
+    {
+        let rollbacks = self
+            .rollbacks
+            .iter_mut()
+            .flat_map(|rollbacks| rollbacks.checkpoints.iter_mut())
+            .map(|rollback| &mut rollback.snapshot);
+
+        for snapshot in std::iter::once(&mut self.snapshot).chain(rollbacks) {
+            let entities = snapshot.entities.iter_mut().flat_map(|e| e.components.iter_mut());
+
+            for value in entities.chain(snapshot.resources.iter_mut()) {
+                let type_name = value.type_name().to_owned();
+                f(&type_name, &mut **value);
+            }
+        }
+    }
+
+    /// Convert the snapshot into a [`DynamicScene`], to hand its data to `bevy_scene` tooling such as scene editors.
+    ///
+    /// Entities keep their saved index, and compacted components are restored to their default value.
+    /// The [`Rollbacks`] are not included, as scenes cannot store them.
+    ///
+    /// # Errors
+    /// - [`SaveableError::UnregisteredType`] or [`SaveableError::MissingDefault`] if a compacted component cannot be restored
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::{prelude::*, scene::serde::{SceneDeserializer, SceneSerializer}};
+    /// # use bevy_save::prelude::*;
+    /// # use serde::de::DeserializeSeed;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// let world = &mut app.world;
+    ///
+    /// world.spawn(Transform::from_xyz(1.0, 2.0, 3.0));
+    /// world.spawn((Transform::default(), Name::new("Player")));
+    ///
     /// let snapshot = Snapshot::from_world(world);
+    /// let scene = snapshot.to_dynamic_scene(world).unwrap();
     ///
-    /// snapshot
-    ///     .applier(world)
-    ///     .map(EntityMap::default())
-    ///     .despawn(DespawnMode::default())
-    ///     .mapping(MappingMode::default())
-    ///     .apply();
+    /// let counts = |scene: &DynamicScene| {
+    ///     let components = scene.entities.iter().map(|e| e.components.len()).sum::<usize>();
+    ///     (scene.entities.len(), components, scene.resources.len())
+    /// };
+    ///
+    /// assert_eq!(counts(&scene).0, 2);
+    /// assert_eq!(counts(&scene).1, 3);
+    ///
+    /// // Round-trip through the `bevy_scene` format
+    /// let registry = world.resource::<AppTypeRegistry>();
+    ///
+    /// let json = serde_json::to_string(&SceneSerializer::new(&scene, registry)).unwrap();
+    ///
+    /// let deserialized = SceneDeserializer { type_registry: &registry.read() }
+    ///     .deserialize(&mut serde_json::Deserializer::from_str(&json))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(counts(&deserialized), counts(&scene));
+    ///
+    /// // And back into a snapshot
+    /// let restored = Snapshot::from_dynamic_scene(&deserialized);
+    ///
+    /// world.clear_entities();
+    /// restored.apply(world).unwrap();
+    ///
+    /// assert_eq!(world.query::<&Transform>().iter(world).count(), 2);
+    /// assert_eq!(world.query::<&Name>().single(world).as_str(), "Player");
     /// ```
-    pub fn applier<'a>(&'a self, world: &'a mut World) -> Applier<&Self> {
-        Applier::new(world, self)
+    #[cfg(feature = "bevy_scene")]
+    pub fn to_dynamic_scene(&self, world: &World) -> Result<DynamicScene, SaveableError> {
+        let registry = world.resource::<AppTypeRegistry>().read();
+
+        let entities = self
+            .snapshot
+            .entities
+            .iter()
+            .map(|saved| {
+                let components = match saved.expanded(&registry)? {
+                    Some(components) => components,
+                    None => saved.components.clone_value(),
+                };
+
+                Ok(DynamicEntity {
+                    entity: Entity::from_raw(saved.entity),
+                    components,
+                })
+            })
+            .collect::<Result<_, SaveableError>>()?;
+
+        Ok(DynamicScene {
+            resources: self.snapshot.resources.clone_value(),
+            entities,
+        })
     }
 
-    /// Create an owning [`Applier`] from the [`Snapshot`] and the [`World`].
-    pub fn into_applier(self, world: &mut World) -> Applier<Self> {
-        Applier::new(world, self)
+    /// Create a [`Snapshot`] from a [`DynamicScene`], such as one edited with `bevy_scene` tooling.
+    ///
+    /// Each scene entity is saved with the index of its [`Entity`], so it maps onto existing entities as usual.
+    /// The snapshot has no [`Rollbacks`].
+    #[cfg(feature = "bevy_scene")]
+    pub fn from_dynamic_scene(scene: &DynamicScene) -> Self {
+        let entities = scene
+            .entities
+            .iter()
+            .map(|entity| SaveableEntity {
+                entity: entity.entity.index(),
+                components: entity.components.clone_value(),
+                defaults: Vec::new(),
+                order: None,
+            })
+            .collect();
+
+        Self {
+            snapshot: RawSnapshot {
+                resources: scene.resources.clone_value(),
+                entities,
+            },
+            rollbacks: None,
+        }
     }
 }
 
@@ -143,6 +1565,7 @@ where
     fn extract_entities(mut self, entities: impl Iterator<Item = Entity>) -> Self {
         let mut builder = Builder::new::<RawSnapshot>(self.world)
             .filter(&self.filter)
+            .parallel(self.parallel)
             .extract_entities(entities);
 
         self.entities.append(&mut builder.entities);
@@ -215,11 +1638,13 @@ where
         self
     }
 
-    fn build(self) -> Self::Output {
+    fn build(mut self) -> Self::Output {
+        canonicalize(&mut self);
+
         let mut snapshot = self.snapshot.unwrap_or_else(Snapshot::default);
 
         snapshot.snapshot = RawSnapshot {
-            entities: self.entities.into_values().collect(),
+            entities: ordered_entities(self.world, self.entities, self.spawn_order),
             resources: self.resources.into_values().collect(),
         };
 
@@ -235,22 +1660,159 @@ macro_rules! impl_snapshot_applier {
             /// # Errors
             /// - See [`SaveableError`]
             pub fn apply(self) -> Result<(), SaveableError> {
-                let applier = Applier {
-                    world: self.world,
-                    snapshot: &self.snapshot.snapshot,
-                    map: self.map,
-                    despawn: self.despawn,
-                    mapping: self.mapping,
-                    hook: self.hook,
-                };
+                self.apply_and_return_map().map(|_| ())
+            }
+
+            /// Apply the [`Snapshot`], returning the [`EntityMap`] from saved entity indices to the entities they were applied to.
+            ///
+            /// Saved indices are keyed as [`Entity::from_raw`], like the map passed to [`Applier::map`].
+            ///
+            /// # Errors
+            /// - See [`SaveableError`]
+            ///
+            /// # Example
+            /// ```
+            /// # use bevy::prelude::*;
+            /// # use bevy_save::prelude::*;
+            /// # let mut app = App::new();
+            /// # app.add_plugins(MinimalPlugins);
+            /// # app.add_plugins(SavePlugins);
+            /// # let mut other = App::new();
+            /// # other.add_plugins(MinimalPlugins);
+            /// # other.add_plugins(SavePlugins);
+            /// # let world = &mut app.world;
+            /// let saved = (0..3)
+            ///     .map(|_| world.spawn(Transform::default()).id())
+            ///     .collect::<Vec<_>>();
+            ///
+            /// let snapshot = Snapshot::from_world(world);
+            ///
+            /// let world = &mut other.world;
+            /// world.spawn_empty();
+            ///
+            /// let map = snapshot.applier(world).apply_and_return_map().unwrap();
+            ///
+            /// for entity in saved {
+            ///     let live = map.get(Entity::from_raw(entity.index())).unwrap();
+            ///     assert!(world.entity(live).contains::<Transform>());
+            /// }
+            /// ```
+            pub fn apply_and_return_map(mut self) -> Result<EntityMap, SaveableError> {
+                let applied = self.apply_raw()?;
+
+                if let Some(rollbacks) = &self.snapshot.rollbacks {
+                    rollbacks.insert_into(self.world);
+                }
+
+                Ok(self.resolved_map(&applied))
+            }
 
-                applier.apply()?;
+            /// Apply the [`Snapshot`], skipping the components that fail to apply instead of aborting.
+            ///
+            /// Returns each skipped component's error along with the entity it was being applied to.
+            /// The other components of that entity, and every other entity, are still applied.
+            ///
+            /// # Errors
+            /// - Errors that are not specific to an entity, such as an unregistered resource. See [`SaveableError`]
+            ///
+            /// # Example
+            /// ```
+            /// # use bevy::prelude::*;
+            /// # use bevy_save::prelude::*;
+            /// #[derive(Component, Reflect, Default)]
+            /// #[reflect(Component)]
+            /// struct Loot;
+            ///
+            /// # let mut app = App::new();
+            /// # app.add_plugins(MinimalPlugins);
+            /// # app.add_plugins(SavePlugins);
+            /// app.register_saveable::<Loot>();
+            ///
+            /// app.world.spawn(Transform::from_xyz(1.0, 0.0, 0.0));
+            /// app.world.spawn((Transform::from_xyz(2.0, 0.0, 0.0), Loot));
+            ///
+            /// let snapshot = Snapshot::from_world(&app.world);
+            ///
+            /// // A build that does not know about `Loot`
+            /// # let mut app = App::new();
+            /// # app.add_plugins(MinimalPlugins);
+            /// # app.add_plugins(SavePlugins);
+            /// let world = &mut app.world;
+            ///
+            /// assert!(snapshot.applier(world).apply().is_err());
+            ///
+            /// world.clear_entities();
+            /// let failures = snapshot.applier(world).apply_lenient().unwrap();
+            ///
+            /// assert_eq!(failures.len(), 1);
+            /// assert!(matches!(failures[0].1, SaveableError::UnregisteredType { .. }));
+            ///
+            /// // Every entity was applied, including the one with the failed component
+            /// assert_eq!(world.query::<&Transform>().iter(world).count(), 2);
+            ///
+            /// let failed = world.get::<Transform>(failures[0].0).unwrap();
+            /// assert_eq!(failed.translation.x, 2.0);
+            /// ```
+            pub fn apply_lenient(mut self) -> Result<Vec<(Entity, SaveableError)>, SaveableError> {
+                self.failures = Some(Vec::new());
+                self.apply_raw()?;
 
                 if let Some(rollbacks) = &self.snapshot.rollbacks {
-                    self.world.insert_resource(rollbacks.clone_value());
+                    rollbacks.insert_into(self.world);
                 }
 
-                Ok(())
+                Ok(self.failures.take().unwrap_or_default())
+            }
+
+            /// Compute what applying the [`Snapshot`] would do, without modifying the [`World`].
+            ///
+            /// # Errors
+            /// - See [`SaveableError`]
+            pub fn dry_run(&mut self) -> Result<ApplyPlan, SaveableError> {
+                self.plan()
+            }
+
+            /// Returns the existing entities that the configured [`DespawnMode`] would despawn when applying the [`Snapshot`].
+            ///
+            /// This is cheaper than [`Applier::dry_run`], as only the despawn phase is computed.
+            ///
+            /// # Example
+            /// ```
+            /// # use bevy::prelude::*;
+            /// # use bevy_save::prelude::*;
+            /// # let mut app = App::new();
+            /// # app.add_plugins(MinimalPlugins);
+            /// # app.add_plugins(SavePlugins);
+            /// # let world = &mut app.world;
+            /// #[derive(Component)]
+            /// struct Enemy;
+            ///
+            /// world.spawn(Transform::default());
+            /// let snapshot = Snapshot::from_world(world);
+            ///
+            /// let enemy = world.spawn(Enemy).id();
+            /// let other = world.spawn_empty().id();
+            ///
+            /// let preview = snapshot
+            ///     .applier(world)
+            ///     .despawn(DespawnMode::missing_with::<With<Enemy>>())
+            ///     .despawn_preview();
+            ///
+            /// assert_eq!(preview, vec![enemy]);
+            ///
+            /// snapshot
+            ///     .applier(world)
+            ///     .despawn(DespawnMode::missing_with::<With<Enemy>>())
+            ///     .apply()
+            ///     .unwrap();
+            ///
+            /// for entity in preview {
+            ///     assert!(world.get_entity(entity).is_none());
+            /// }
+            /// assert!(world.get_entity(other).is_some());
+            /// ```
+            pub fn despawn_preview(&mut self) -> Vec<Entity> {
+                self.despawned()
             }
         }
     };
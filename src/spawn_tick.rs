@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+
+/// The tick an entity was spawned at, used by [`DespawnMode::MissingOlderThan`](crate::DespawnMode::MissingOlderThan).
+///
+/// Entities can be tagged manually, or automatically by adding [`tag_spawn_ticks`] to a schedule.
+#[derive(Component, Reflect, Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[reflect(Component)]
+pub struct SpawnTick(pub u32);
+
+/// Tags every entity without a [`SpawnTick`] with the current change tick of the [`World`].
+///
+/// This is not added by [`SavePlugins`](crate::SavePlugins).
+pub fn tag_spawn_ticks(world: &mut World) {
+    let tick = SpawnTick(world.change_tick().get());
+
+    let untagged = world
+        .query_filtered::<Entity, Without<SpawnTick>>()
+        .iter(world)
+        .collect::<Vec<_>>();
+
+    for entity in untagged {
+        world.entity_mut(entity).insert(tick);
+    }
+}
@@ -0,0 +1,41 @@
+use bevy::{
+    prelude::*,
+    reflect::TypePath,
+};
+
+/// The saveable copy of a [`State<S>`], registered by
+/// [`AppSaveableExt::register_saveable_state`](crate::AppSaveableExt::register_saveable_state).
+///
+/// [`State<S>`] does not implement [`Reflect`], so this resource mirrors it and is captured in its place.
+/// It is kept up to date by [`sync_saved_state`], and when a snapshot overwrites it with a different state,
+/// [`restore_saved_state`] queues that state in [`NextState<S>`] so the usual [`OnExit`] and [`OnEnter`] schedules run.
+#[derive(Resource, Reflect, Default, Debug, Clone, PartialEq, Eq)]
+#[reflect(Resource)]
+pub struct SavedState<S: States + Reflect + TypePath>(pub S);
+
+/// Copies the current [`State<S>`] into [`SavedState<S>`].
+///
+/// Added to [`StateTransition`] after the state is applied, and does not trigger change detection.
+#[allow(clippy::needless_pass_by_value)]
+pub fn sync_saved_state<S: States + Reflect + TypePath>(
+    state: Res<State<S>>,
+    mut saved: ResMut<SavedState<S>>,
+) {
+    if saved.0 != *state.get() {
+        saved.bypass_change_detection().0 = state.get().clone();
+    }
+}
+
+/// Queues the state in [`SavedState<S>`] as the [`NextState<S>`] if it was overwritten, such as by applying a snapshot.
+///
+/// Added to [`PreUpdate`], so the transition is applied in the following [`StateTransition`].
+#[allow(clippy::needless_pass_by_value)]
+pub fn restore_saved_state<S: States + Reflect + TypePath>(
+    state: Res<State<S>>,
+    saved: Res<SavedState<S>>,
+    mut next: ResMut<NextState<S>>,
+) {
+    if saved.is_changed() && !saved.is_added() && saved.0 != *state.get() {
+        next.set(saved.0.clone());
+    }
+}
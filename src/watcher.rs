@@ -0,0 +1,156 @@
+use std::collections::{
+    BTreeMap,
+    BTreeSet,
+};
+
+use bevy::prelude::*;
+
+use crate::{
+    reflect_values_eq,
+    SaveableRegistry,
+};
+
+/// Reports which resources changed between updates, without capturing any entities.
+///
+/// The watcher keeps a reflected copy of each watched resource, compares it to the live value on every
+/// [`ResourceWatcher::update`] and only clones the resources that changed, so it is much cheaper than a
+/// [`Snapshot`](crate::Snapshot) per frame, e.g. for a debug panel of the global state.
+///
+/// Watches every saveable resource, or only the ones added with [`ResourceWatcher::watch`].
+/// Watched resources must have a [`ReflectResource`] registration.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// #[derive(Resource, Reflect, Default)]
+/// #[reflect(Resource)]
+/// struct Gold(u32);
+///
+/// #[derive(Resource, Reflect, Default)]
+/// #[reflect(Resource)]
+/// struct Health(f32);
+///
+/// #[derive(Resource, Reflect, Default)]
+/// #[reflect(Resource)]
+/// struct Level(u8);
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// app.register_saveable::<Gold>()
+///     .register_saveable::<Health>()
+///     .register_saveable::<Level>()
+///     .init_resource::<Gold>()
+///     .init_resource::<Health>()
+///     .init_resource::<Level>()
+///     .insert_resource(ResourceWatcher::new().watch::<Gold>().watch::<Health>().watch::<Level>())
+///     .add_systems(Last, watch_resources);
+///
+/// // Every resource is reported as changed the first time it is seen
+/// app.update();
+/// assert_eq!(app.world.resource::<ResourceWatcher>().changed().count(), 3);
+///
+/// app.world.resource_mut::<Health>().0 = 50.0;
+/// app.update();
+///
+/// let watcher = app.world.resource::<ResourceWatcher>();
+/// let changed = watcher.changed().collect::<Vec<_>>();
+///
+/// assert_eq!(changed, [std::any::type_name::<Health>()]);
+/// assert_eq!(watcher.changes().len(), 3);
+/// ```
+#[derive(Resource, Default)]
+pub struct ResourceWatcher {
+    watched: Option<BTreeSet<String>>,
+    last: BTreeMap<String, Box<dyn Reflect>>,
+    changes: Vec<(String, bool)>,
+}
+
+impl ResourceWatcher {
+    /// Create a new [`ResourceWatcher`] watching every saveable resource.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Watch the resource `R`, and only the resources added with this method.
+    pub fn watch<R: Resource>(mut self) -> Self {
+        self.watched
+            .get_or_insert_with(BTreeSet::new)
+            .insert(std::any::type_name::<R>().to_string());
+        self
+    }
+
+    /// Compare the watched resources to their values at the last update, returning whether each changed.
+    ///
+    /// Resources are listed by type name. A resource that was added or removed since the last update
+    /// counts as changed, and resources missing from the [`World`] at both updates are not listed.
+    pub fn update(&mut self, world: &World) -> &[(String, bool)] {
+        let registry_arc = world.resource::<AppTypeRegistry>();
+        let registry = registry_arc.read();
+
+        let names = match &self.watched {
+            Some(watched) => watched.clone(),
+            None => world
+                .get_resource::<SaveableRegistry>()
+                .map(|saveables| saveables.types().cloned().collect())
+                .unwrap_or_default(),
+        };
+
+        self.changes.clear();
+
+        for name in names {
+            let live = registry
+                .get_with_name(&name)
+                .and_then(|reg| reg.data::<ReflectResource>())
+                .and_then(|res| res.reflect(world));
+
+            let changed = match live {
+                Some(live) => {
+                    let changed = match self.last.get(&name) {
+                        Some(last) => !reflect_values_eq(live, &**last),
+                        None => true,
+                    };
+
+                    if changed {
+                        self.last.insert(name.clone(), live.clone_value());
+                    }
+
+                    changed
+                }
+                None if self.last.remove(&name).is_some() => true,
+                None => continue,
+            };
+
+            self.changes.push((name, changed));
+        }
+
+        &self.changes
+    }
+
+    /// Returns whether each watched resource changed at the last [`ResourceWatcher::update`].
+    pub fn changes(&self) -> &[(String, bool)] {
+        &self.changes
+    }
+
+    /// Returns the type names of the resources that changed at the last [`ResourceWatcher::update`].
+    pub fn changed(&self) -> impl Iterator<Item = &str> {
+        self.changes
+            .iter()
+            .filter(|(_, changed)| *changed)
+            .map(|(name, _)| name.as_str())
+    }
+}
+
+/// Updates the [`ResourceWatcher`], if present.
+///
+/// This is not added by [`SavePlugins`](crate::SavePlugins).
+pub fn watch_resources(world: &mut World) {
+    if !world.contains_resource::<ResourceWatcher>() {
+        return;
+    }
+
+    world.resource_scope(|world, mut watcher: Mut<ResourceWatcher>| {
+        watcher.update(world);
+    });
+}
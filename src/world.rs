@@ -1,22 +1,47 @@
-use bevy::prelude::*;
+use std::{
+    collections::BTreeMap,
+    io::Write,
+};
+
+use bevy::{
+    prelude::*,
+    reflect::TypeRegistryInternal,
+};
 use serde::{
     de::{
         DeserializeSeed,
         Error,
     },
+    Deserialize,
     Serialize,
 };
 
 use crate::{
+    compare::lossy_type,
+    rollbacks::ChangeBaseline,
     AppBackend,
+    AppCompression,
+    AppDespawnMode,
+    AppEntityRefs,
     AppLoader,
+    AppMappingMode,
+    AppMaxDepth,
+    AppSaveExtensions,
+    AppSaveVersion,
     AppSaver,
+    AppSelfCheck,
+    AppTypeNames,
     Applier,
     CloneReflect,
+    LoadSession,
     Rollback,
     Rollbacks,
+    SaveHeader,
+    SaveMetadata,
     SaveableError,
+    SaveableRegistry,
     Snapshot,
+    SnapshotDelta,
     SnapshotDeserializer,
     SnapshotSerializer,
 };
@@ -29,6 +54,19 @@ pub trait WorldSaveableExt: Sized {
     /// Creates a checkpoint for rollback.
     fn checkpoint(&mut self);
 
+    /// Creates a checkpoint on a new branch, keeping any rollforward checkpoints.
+    ///
+    /// Returns the index of the new checkpoint. See [`Rollbacks::checkpoint_branch`].
+    fn checkpoint_branch(&mut self) -> usize;
+
+    /// Restores the [`World`] state to the checkpoint at the given index, which may be on another branch.
+    ///
+    /// Does nothing if there is no checkpoint at the index. See [`Rollbacks::checkpoint_branch`].
+    ///
+    /// # Errors
+    /// - See [`SaveableError`]
+    fn switch_branch(&mut self, index: usize) -> Result<(), SaveableError>;
+
     /// Rolls back / forward the [`World`] state.
     ///
     /// # Errors
@@ -78,6 +116,55 @@ pub trait WorldSaveableExt: Sized {
     /// - See [`serde::Serialize`]
     fn save(&self, name: &str) -> Result<(), SaveableError>;
 
+    /// Saves the game state to a named save, storing the given [`SaveHeader`] uncompressed at the start of the save.
+    ///
+    /// # Errors
+    /// - See [`SaveableError`]
+    /// - See [`serde::Serialize`]
+    fn save_with_header(&self, name: &str, header: &SaveHeader) -> Result<(), SaveableError>;
+
+    /// Loads only the [`SaveHeader`] from a named save, without reading the rest of the save.
+    ///
+    /// Saves written without a header return an empty [`SaveHeader`].
+    ///
+    /// # Errors
+    /// - See [`SaveableError`]
+    fn load_header(&self, name: &str) -> Result<SaveHeader, SaveableError>;
+
+    /// Returns true if a save with the given name exists in the [`AppBackend`].
+    fn save_exists(&self, name: &str) -> bool;
+
+    /// Returns the [`SaveMetadata`] of a named save, reading only its header segment.
+    ///
+    /// Returns `None` if the save does not exist or its header cannot be read.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # app.insert_resource(AppBackend::new(MemoryBackend::new()));
+    /// # let world = &mut app.world;
+    /// for (slot, title) in [("slot_0", "Chapter 1"), ("slot_1", "Chapter 2")] {
+    ///     let header = SaveHeader::new().with("title", title);
+    ///     world.save_with_header(slot, &header).unwrap();
+    /// }
+    ///
+    /// assert!(world.save_exists("slot_0"));
+    /// assert!(world.save_exists("slot_1"));
+    /// assert!(!world.save_exists("slot_2"));
+    ///
+    /// let metadata = world.save_metadata("slot_1").unwrap();
+    /// assert_eq!(metadata.header.get("title"), Some("Chapter 2"));
+    /// assert_eq!(metadata.version, world.resource::<AppSaveVersion>().0);
+    /// assert_eq!(metadata.compression, *world.resource::<AppCompression>());
+    ///
+    /// assert_eq!(world.save_metadata("slot_2"), None);
+    /// ```
+    fn save_metadata(&self, name: &str) -> Option<SaveMetadata>;
+
     /// Loads the game state from a named save.
     ///
     /// # Errors
@@ -93,6 +180,81 @@ pub trait WorldSaveableExt: Sized {
     /// - See [`SaveableError`]
     /// - See [`serde::Deserialize`]
     fn load_applier(&mut self, name: &str) -> Result<Applier<Snapshot>, SaveableError>;
+
+    /// Begins loading the game state from a named save, inserting a [`LoadSession`] that applies it over several frames.
+    ///
+    /// # Errors
+    /// - See [`SaveableError`]
+    /// - See [`serde::Deserialize`]
+    fn begin_load(&mut self, name: &str) -> Result<(), SaveableError>;
+
+    /// Runs `f` on the [`World`], returning the [`SnapshotDelta`] between snapshots taken before and after.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Component, Reflect, Default)]
+    /// #[reflect(Component)]
+    /// struct Velocity(Vec3);
+    ///
+    /// fn integrate(mut query: Query<(&mut Transform, &Velocity)>) {
+    ///     for (mut transform, velocity) in &mut query {
+    ///         if velocity.0 != Vec3::ZERO {
+    ///             transform.translation += velocity.0;
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// app.register_saveable::<Velocity>();
+    ///
+    /// let world = &mut app.world;
+    ///
+    /// let moving = world.spawn((Transform::default(), Velocity(Vec3::X))).id();
+    /// world.spawn((Transform::default(), Velocity(Vec3::ZERO)));
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.add_systems(integrate);
+    ///
+    /// let delta = world.record_changes(|world| schedule.run(world));
+    ///
+    /// assert_eq!(delta.components_changed, vec![(
+    ///     moving.index(),
+    ///     "bevy_transform::components::transform::Transform".to_string()
+    /// )]);
+    /// assert!(delta.entities_added.is_empty());
+    /// assert!(delta.resources_changed.is_empty());
+    /// ```
+    fn record_changes<F: FnOnce(&mut World)>(&mut self, f: F) -> SnapshotDelta;
+
+    /// Creates a new, empty [`World`] that snapshots of this [`World`] can be applied to.
+    ///
+    /// The new [`World`] shares this [`World`]'s [`AppTypeRegistry`] and copies its [`SaveableRegistry`],
+    /// along with the [`AppDespawnMode`], [`AppMappingMode`] and [`AppEntityRefs`] settings if present.
+    /// This is useful for validating or inspecting snapshots without touching the main [`World`].
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let world = &mut app.world;
+    /// world.spawn(Transform::from_xyz(1.0, 2.0, 3.0));
+    ///
+    /// let snapshot = world.snapshot();
+    ///
+    /// let mut validation = world.sub_world();
+    /// snapshot.apply(&mut validation).unwrap();
+    ///
+    /// let transform = validation.query::<&Transform>().single(&validation);
+    /// assert_eq!(transform.translation, Vec3::new(1.0, 2.0, 3.0));
+    /// ```
+    fn sub_world(&self) -> World;
 }
 
 impl WorldSaveableExt for World {
@@ -104,11 +266,46 @@ impl WorldSaveableExt for World {
         let rollback = Rollback::from_world(self);
         let mut state = self.resource_mut::<Rollbacks>();
         state.checkpoint(rollback);
+
+        let baseline = ChangeBaseline::capture(self);
+        self.resource_mut::<Rollbacks>().baseline = Some(baseline);
+    }
+
+    fn checkpoint_branch(&mut self) -> usize {
+        let rollback = Rollback::from_world(self);
+        let index = self.resource_mut::<Rollbacks>().checkpoint_branch(rollback);
+
+        let baseline = ChangeBaseline::capture(self);
+        self.resource_mut::<Rollbacks>().baseline = Some(baseline);
+
+        index
+    }
+
+    fn switch_branch(&mut self, index: usize) -> Result<(), SaveableError> {
+        let rollback = self
+            .resource_mut::<Rollbacks>()
+            .switch_branch(index)
+            .map(|r| r.clone_value());
+
+        if let Some(rollback) = rollback {
+            rollback.into_applier(self).apply()?;
+
+            let baseline = ChangeBaseline::capture(self);
+            self.resource_mut::<Rollbacks>().baseline = Some(baseline);
+        }
+
+        Ok(())
     }
 
     fn rollback(&mut self, checkpoints: isize) -> Result<(), SaveableError> {
         self.rollback_applier(checkpoints)
-            .map_or(Ok(()), |a| a.apply())
+            .map_or(Ok(()), |a| a.apply())?;
+
+        // The world now matches the active checkpoint
+        let baseline = ChangeBaseline::capture(self);
+        self.resource_mut::<Rollbacks>().baseline = Some(baseline);
+
+        Ok(())
     }
 
     fn rollback_applier(&mut self, checkpoints: isize) -> Option<Applier<Rollback>> {
@@ -121,12 +318,8 @@ impl WorldSaveableExt for World {
     }
 
     fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        let registry = self.resource::<AppTypeRegistry>();
         let snap = self.snapshot();
-
-        let ser = SnapshotSerializer::new(&snap, registry);
-
-        ser.serialize(serializer)
+        snapshot_serializer(self, &snap).serialize(serializer)
     }
 
     fn deserialize<'de, D: serde::Deserializer<'de>>(
@@ -145,25 +338,69 @@ impl WorldSaveableExt for World {
         let registry = self.resource::<AppTypeRegistry>().clone();
         let reg = registry.read();
 
-        let de = SnapshotDeserializer::new(&reg);
-
-        let snap = de.deserialize(deserializer)?;
+        let table = self.get_resource::<AppTypeNames>() == Some(&AppTypeNames::Table);
+        let snap = snapshot_deserializer(self, &reg, table).deserialize(deserializer)?;
 
         Ok(snap.into_applier(self))
     }
 
     fn save(&self, name: &str) -> Result<(), SaveableError> {
+        self.save_with_header(name, &SaveHeader::default())
+    }
+
+    fn save_with_header(&self, name: &str, header: &SaveHeader) -> Result<(), SaveableError> {
+        let saver = self.resource::<AppSaver>();
+
+        // Checked before opening the writer, so a lossy save leaves the slot untouched
+        let checked = match self.get_resource::<AppSelfCheck>() {
+            Some(AppSelfCheck::Enabled) => Some(self_checked(self, saver)?),
+            _ => None,
+        };
+
         let mut writer = self
             .resource::<AppBackend>()
             .writer(name)
             .map_err(SaveableError::other)?;
 
-        let saver = self.resource::<AppSaver>();
+        let version = *self.resource::<AppSaveVersion>();
+        let compression = *self.resource::<AppCompression>();
+        let type_names = *self.resource::<AppTypeNames>();
+
+        header.write(&mut writer, version, saver.format(), compression, type_names)?;
+
+        compression.compress(&mut writer, |mut writer| {
+            match &checked {
+                Some(bytes) => writer.write_all(bytes).map_err(SaveableError::other)?,
+                None => snapshot_serializer(self, &self.snapshot())
+                    .serialize_checked(&mut saver.serializer(&mut writer))?,
+            }
+
+            match self.get_resource::<AppSaveExtensions>() {
+                Some(extensions) if !extensions.is_empty() => extensions
+                    .serialize(self)
+                    .serialize(&mut saver.serializer(&mut writer))
+                    .map(|_| ())
+                    .map_err(SaveableError::other),
+                _ => Ok(()),
+            }
+        })?;
+
+        writer.flush().map_err(SaveableError::other)
+    }
 
-        self.serialize(&mut saver.serializer(&mut writer))
-            .map_err(SaveableError::other)?;
+    fn load_header(&self, name: &str) -> Result<SaveHeader, SaveableError> {
+        let reader = self.resource::<AppBackend>().reader(name)?;
+        let (segment, _) = SaveHeader::read(reader)?;
 
-        Ok(())
+        Ok(segment.header)
+    }
+
+    fn save_exists(&self, name: &str) -> bool {
+        self.resource::<AppBackend>().exists(name)
+    }
+
+    fn save_metadata(&self, name: &str) -> Option<SaveMetadata> {
+        self.resource::<AppBackend>().metadata(name)
     }
 
     fn load(&mut self, name: &str) -> Result<(), SaveableError> {
@@ -171,14 +408,144 @@ impl WorldSaveableExt for World {
     }
 
     fn load_applier(&mut self, name: &str) -> Result<Applier<Snapshot>, SaveableError> {
-        let mut reader = self.resource::<AppBackend>().reader(name)?;
+        let reader = self.resource::<AppBackend>().reader(name)?;
+        let (segment, reader) = SaveHeader::read(reader)?;
+
+        let supported = self.resource::<AppSaveVersion>().0;
+
+        if segment.version > supported {
+            return Err(SaveableError::UnsupportedVersion {
+                found: segment.version,
+                supported,
+            });
+        }
+
+        let mut reader = segment.compression.decompress(reader);
 
+        let registry = self.resource::<AppTypeRegistry>().clone();
         let loader = self.resource::<AppLoader>();
 
-        let applier = self
-            .deserialize_applier(&mut loader.deserializer(&mut reader))
-            .map_err(SaveableError::other)?;
+        let snapshot = {
+            let registry = registry.read();
+            let table = segment.type_names == AppTypeNames::Table;
+
+            snapshot_deserializer(self, &registry, table).deserialize_checked(
+                &mut segment.format.deserializer(loader, (&mut reader).into()),
+            )?
+        };
+
+        // Extension data follows the snapshot as a second value, absent without extensions
+        let extensions = self
+            .get_resource::<AppSaveExtensions>()
+            .filter(|extensions| !extensions.is_empty())
+            .and_then(|_| {
+                let mut deserializer = segment.format.deserializer(loader, (&mut reader).into());
+                BTreeMap::<String, Vec<u8>>::deserialize(&mut deserializer).ok()
+            });
+
+        if let Some(data) = extensions {
+            self.resource_scope(|world, extensions: Mut<AppSaveExtensions>| {
+                extensions.deserialize(world, &data);
+            });
+        }
+
+        Ok(snapshot.into_applier(self))
+    }
+
+    fn begin_load(&mut self, name: &str) -> Result<(), SaveableError> {
+        let snapshot = self.load_applier(name)?.snapshot;
+        self.insert_resource(LoadSession::new(snapshot));
+        Ok(())
+    }
+
+    fn record_changes<F: FnOnce(&mut World)>(&mut self, f: F) -> SnapshotDelta {
+        let before = Snapshot::from_world(self);
+        f(self);
+        let after = Snapshot::from_world(self);
 
-        Ok(applier)
+        before.diff(&after)
     }
+
+    fn sub_world(&self) -> World {
+        let mut world = World::new();
+
+        world.insert_resource(self.resource::<AppTypeRegistry>().clone());
+        world.insert_resource(self.resource::<SaveableRegistry>().clone());
+
+        if let Some(despawn) = self.get_resource::<AppDespawnMode>() {
+            world.insert_resource(despawn.clone());
+        }
+
+        if let Some(mapping) = self.get_resource::<AppMappingMode>() {
+            world.insert_resource(mapping.clone());
+        }
+
+        if let Some(refs) = self.get_resource::<AppEntityRefs>() {
+            world.insert_resource(*refs);
+        }
+
+        world
+    }
+}
+
+/// Serializes a snapshot of the world and deserializes it again, returning the serialized bytes
+/// if every component and resource came back equal.
+fn self_checked(world: &World, saver: &AppSaver) -> Result<Vec<u8>, SaveableError> {
+    let table = world.get_resource::<AppTypeNames>() == Some(&AppTypeNames::Table);
+    let snap = world.snapshot();
+
+    let mut bytes = Vec::new();
+    snapshot_serializer(world, &snap).serialize_checked(&mut saver.serializer(&mut bytes))?;
+
+    let loaded = {
+        let registry = world.resource::<AppTypeRegistry>().read();
+
+        let mut reader = &bytes[..];
+        let mut deserializer = saver
+            .format()
+            .deserializer(world.resource::<AppLoader>(), (&mut reader).into());
+
+        let snapshot =
+            snapshot_deserializer(world, &registry, table).deserialize_checked(&mut deserializer)?;
+        snapshot
+    };
+
+    match lossy_type(&snap.snapshot, &loaded.snapshot) {
+        Some(type_name) => Err(SaveableError::LossySerialization { type_name }),
+        None => Ok(bytes),
+    }
+}
+
+/// Returns a [`SnapshotSerializer`] using the [`AppTypeNames`] and [`AppMaxDepth`] of the world.
+fn snapshot_serializer<'a>(world: &'a World, snap: &'a Snapshot) -> SnapshotSerializer<'a> {
+    let mut ser = SnapshotSerializer::new(snap, world.resource::<AppTypeRegistry>());
+
+    if world.get_resource::<AppTypeNames>() == Some(&AppTypeNames::Table) {
+        ser = ser.type_table();
+    }
+
+    if let Some(AppMaxDepth(max_depth)) = world.get_resource::<AppMaxDepth>() {
+        ser = ser.max_depth(*max_depth);
+    }
+
+    ser
+}
+
+/// Returns a [`SnapshotDeserializer`] using the [`AppMaxDepth`] of the world.
+fn snapshot_deserializer<'a>(
+    world: &World,
+    registry: &'a TypeRegistryInternal,
+    table: bool,
+) -> SnapshotDeserializer<'a> {
+    let mut de = SnapshotDeserializer::new(registry);
+
+    if table {
+        de = de.type_table();
+    }
+
+    if let Some(AppMaxDepth(max_depth)) = world.get_resource::<AppMaxDepth>() {
+        de = de.max_depth(*max_depth);
+    }
+
+    de
 }